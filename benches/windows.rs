@@ -0,0 +1,44 @@
+#![feature(test)]
+
+extern crate test;
+
+use dyn_slice::standard::debug;
+use test::{black_box, Bencher};
+
+const LEN: usize = 1024;
+const WINDOW_SIZE: usize = 4;
+
+#[bench]
+fn dyn_slice_windows(b: &mut Bencher) {
+    let array = [0_u32; LEN];
+    let slice = debug::new(&array);
+
+    b.iter(|| {
+        for window in black_box(slice).windows(WINDOW_SIZE).unwrap() {
+            black_box(window);
+        }
+    });
+}
+
+#[bench]
+fn dyn_slice_windows_rev(b: &mut Bencher) {
+    let array = [0_u32; LEN];
+    let slice = debug::new(&array);
+
+    b.iter(|| {
+        for window in black_box(slice).windows(WINDOW_SIZE).unwrap().rev() {
+            black_box(window);
+        }
+    });
+}
+
+#[bench]
+fn slice_windows(b: &mut Bencher) {
+    let array = [0_u32; LEN];
+
+    b.iter(|| {
+        for window in black_box(&array[..]).windows(WINDOW_SIZE) {
+            black_box(window);
+        }
+    });
+}
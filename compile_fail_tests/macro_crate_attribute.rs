@@ -0,0 +1,12 @@
+// Make sure that an invalid `crate` attribute is reported on its value
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    #[crate = "dyn_slice"]
+    debug core::fmt::Debug
+);
+
+fn main() {}
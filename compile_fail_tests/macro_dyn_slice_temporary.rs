@@ -0,0 +1,11 @@
+// Make sure that dyn slices from the dyn_slice macro do not outlive the temporary array
+
+use std::fmt::Display;
+
+use dyn_slice::dyn_slice;
+
+fn main() {
+    let slice = dyn_slice![Display: String::from("a"), String::from("b")];
+
+    let _ = &slice;
+}
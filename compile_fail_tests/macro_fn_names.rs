@@ -0,0 +1,12 @@
+// Make sure that an unknown function in a `fn_names` attribute is reported
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    #[fn_names(new = make, new_ref = make_ref)]
+    debug core::fmt::Debug
+);
+
+fn main() {}
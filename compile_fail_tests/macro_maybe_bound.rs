@@ -0,0 +1,11 @@
+// Make sure that a `?` bound on the trait object is reported
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    debug core::fmt::Debug + ?Sized
+);
+
+fn main() {}
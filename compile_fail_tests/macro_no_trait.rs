@@ -0,0 +1,11 @@
+// Make sure that a trait object without a trait is reported
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    debug<'a> 'a
+);
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Make sure that a trait that is not object safe is reported
+// once, on the trait, with the method that breaks object safety
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+pub trait Convert {
+    fn convert<T: From<u8>>(&self) -> T;
+}
+
+declare_new_fns!(
+    convert Convert
+);
+
+fn main() {}
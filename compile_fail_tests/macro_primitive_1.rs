@@ -0,0 +1,12 @@
+// Make sure that a generic parameter that shadows a primitive
+// type is reported on the parameter
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    partial_eq<u8> core::cmp::PartialEq<u8>
+);
+
+fn main() {}
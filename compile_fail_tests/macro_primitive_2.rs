@@ -0,0 +1,12 @@
+// Make sure that a generic parameter that shadows a crate
+// is reported on the parameter
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    partial_eq<core> core::cmp::PartialEq<core>
+);
+
+fn main() {}
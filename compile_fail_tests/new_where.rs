@@ -0,0 +1,16 @@
+// Make sure that the bounds in a `new_where` attribute are
+// applied to the new functions
+
+#![feature(ptr_metadata)]
+
+use dyn_slice::declare_new_fns;
+
+declare_new_fns!(
+    #[new_where(Rhs: Copy)]
+    partial_eq_copy<Rhs> PartialEq<Rhs>
+);
+
+fn main() {
+    let array = [String::new()];
+    let _ = partial_eq_copy::new::<String, _>(&array);
+}
@@ -1,17 +1,22 @@
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
+    visit_mut::VisitMut,
     Attribute, Error, Expr, ExprPath, GenericArgument, GenericParam, Generics, Ident, Lifetime,
-    Meta, Path, PathSegment, Token, TypeParamBound, TypePath, Visibility, WhereClause,
+    Meta, MetaNameValue, Path, PathSegment, Token, TraitBoundModifier, TypeParamBound, TypePath,
+    Visibility, WhereClause, WherePredicate,
 };
 
-use crate::{
-    path_ext::{make_generics_inner_path, make_inner_path, RESERVED},
-    stringify_basic_path, type_param_bound_select_trait,
-};
+use crate::{stringify_basic_path, super_path::AnchorSuper};
+
+/// Names that should not be used for generic parameters
+const RESERVED: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize", "f32",
+    "char", "bool", "f64", "str", "core", "alloc", "std",
+];
 
 /// A definition for a set of new functions for `DynSlice`s
 pub struct DeclareNewFns {
@@ -90,31 +95,27 @@ impl TryFrom<DeclareNewFns> for TokenStream {
         } = value;
 
         // Get the dyn-slice crate path
-        let crate_ = match get_crate(&mut attrs) {
-            Ok(path) => path,
-            Err(err) => return Err(err),
-        };
+        let crate_ = get_crate(&mut attrs)?;
 
-        let mut generic_idents: Vec<String> =
-            RESERVED.iter().copied().map(ToOwned::to_owned).collect();
-        generic_idents.extend(generics.params.iter().filter_map(|param| match param {
-            GenericParam::Type(r#type) => Some(r#type.ident.to_string()),
-            GenericParam::Const(r#const) => Some(r#const.ident.to_string()),
-            GenericParam::Lifetime(_) => None,
-        }));
+        // Get the extra bounds for the new functions
+        let mut new_where = get_new_where(&mut attrs)?;
+        // Get the names of the new functions
+        let fn_names = get_fn_names(&mut attrs)?;
+
+        check_generics(&generics)?;
+        check_object_bounds(&object_bounds)?;
 
         // Create a clone before editing
         let outer_trait_object = object_bounds.clone();
 
-        // Make paths inner paths
-        for bound in &mut object_bounds
-            .iter_mut()
-            .filter_map(type_param_bound_select_trait)
-        {
-            make_inner_path(&mut bound.path, &generic_idents)?;
+        // Make `super` paths relative to the generated module
+        for bound in &mut object_bounds {
+            AnchorSuper.visit_type_param_bound_mut(bound);
+        }
+        AnchorSuper.visit_generics_mut(&mut generics);
+        for predicate in &mut new_where {
+            AnchorSuper.visit_where_predicate_mut(predicate);
         }
-
-        make_generics_inner_path(&mut generics, &generic_idents)?;
 
         // Get the path of the trait for documentation
         // This is done as a string rather than using `r#trait` in the quote
@@ -175,11 +176,21 @@ impl TryFrom<DeclareNewFns> for TokenStream {
             inner_path: inner_trait_paths.as_slice(),
         };
 
+        // Get the attributes that should also be applied to each item
+        let item_attrs = attrs
+            .iter()
+            .filter(|attr| is_item_attr(attr))
+            .map(ToTokens::into_token_stream)
+            .collect();
+
         let data = Data {
             attrs,
+            item_attrs,
             vis,
             ident,
             generics,
+            new_where,
+            fn_names,
             object_bounds,
         };
 
@@ -192,12 +203,77 @@ impl TryFrom<DeclareNewFns> for TokenStream {
     }
 }
 
+/// Checks that the generic parameters do not shadow primitive types or crates, which would
+/// make the bounds in the declaration confusing
+fn check_generics(generics: &Generics) -> syn::Result<()> {
+    for ident in generics.params.iter().filter_map(|param| match param {
+        GenericParam::Type(r#type) => Some(&r#type.ident),
+        GenericParam::Const(r#const) => Some(&r#const.ident),
+        GenericParam::Lifetime(_) => None,
+    }) {
+        let name = ident.to_string();
+        if !RESERVED.contains(&name.as_str()) {
+            continue;
+        }
+
+        let kind = if matches!(name.as_str(), "core" | "alloc" | "std") {
+            "crate"
+        } else {
+            "primitive type"
+        };
+
+        return Err(Error::new(
+            ident.span(),
+            format!("generic parameter `{name}` shadows a {kind}, rename the parameter"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the trait object bounds include a trait, and that every bound can be used in a
+/// trait object
+fn check_object_bounds(object_bounds: &Punctuated<TypeParamBound, Token![+]>) -> syn::Result<()> {
+    for bound in object_bounds {
+        match bound {
+            TypeParamBound::Trait(trait_bound) => {
+                if let TraitBoundModifier::Maybe(question) = trait_bound.modifier {
+                    return Err(Error::new(
+                        question.span(),
+                        "`?` bounds are not allowed on trait objects, remove this bound",
+                    ));
+                }
+            }
+
+            TypeParamBound::Verbatim(tokens) => {
+                return Err(Error::new(tokens.span(), "unsupported bound"));
+            }
+
+            _ => {}
+        }
+    }
+
+    if !object_bounds
+        .iter()
+        .any(|bound| matches!(bound, TypeParamBound::Trait(_)))
+    {
+        return Err(Error::new(
+            object_bounds.span(),
+            "at least one trait is required for a trait object",
+        ));
+    }
+
+    Ok(())
+}
+
 fn get_crate(attrs: &mut Vec<Attribute>) -> syn::Result<Path> {
-    // Make the crate name `dyn_slice` by default
+    // Make the crate name `::dyn_slice` by default, so that it cannot be shadowed by the
+    // imported scope
     let mut crate_ = Path::from(PathSegment::from(Ident::new(
         "dyn_slice",
         Span::mixed_site(),
     )));
+    crate_.leading_colon = Some(<Token![::]>::default());
 
     // Check for a `crate = <path>` attribute macro
     if let Some((i, value)) = attrs
@@ -220,8 +296,8 @@ fn get_crate(attrs: &mut Vec<Attribute>) -> syn::Result<Path> {
     {
         let Expr::Path(crate_new) = value else {
             return Err(Error::new(
-                Span::call_site(),
-                "'crate' attribute value must be the crate path",
+                value.span(),
+                "'crate' attribute value must be the crate path, for example `#[crate = other_crate::dyn_slice]`",
             ));
         };
         // Set the crate path
@@ -234,12 +310,107 @@ fn get_crate(attrs: &mut Vec<Attribute>) -> syn::Result<Path> {
     Ok(crate_)
 }
 
+fn get_new_where(attrs: &mut Vec<Attribute>) -> syn::Result<Punctuated<WherePredicate, Token![,]>> {
+    let mut predicates = Punctuated::new();
+
+    // Take every `new_where(<predicates>)` attribute macro
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("new_where") {
+            return true;
+        }
+
+        match attr.parse_args_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated) {
+            Ok(new) => predicates.extend(new),
+            Err(err) => result = Err(err),
+        }
+
+        false
+    });
+    result?;
+
+    if !predicates.empty_or_trailing() {
+        predicates.push_punct(<Token![,]>::default());
+    }
+
+    Ok(predicates)
+}
+
+fn get_fn_names(attrs: &mut Vec<Attribute>) -> syn::Result<FnNames> {
+    let mut fn_names = FnNames {
+        new: Ident::new("new", Span::call_site()),
+        new_mut: Ident::new("new_mut", Span::call_site()),
+    };
+
+    // Check for a `fn_names(new = <ident>, new_mut = <ident>)` attribute macro
+    let Some(i) = attrs
+        .iter()
+        .position(|attr| attr.path().is_ident("fn_names"))
+    else {
+        return Ok(fn_names);
+    };
+    let attr = attrs.remove(i);
+
+    let names = attr.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+    for name in names {
+        let Expr::Path(ExprPath { path, .. }) = &name.value else {
+            return Err(Error::new(
+                name.value.span(),
+                "function name must be an identifier",
+            ));
+        };
+        let Some(ident) = path.get_ident() else {
+            return Err(Error::new(
+                path.span(),
+                "function name must be an identifier",
+            ));
+        };
+
+        if name.path.is_ident("new") {
+            fn_names.new = ident.clone();
+        } else if name.path.is_ident("new_mut") {
+            fn_names.new_mut = ident.clone();
+        } else {
+            return Err(Error::new(name.path.span(), "expected `new` or `new_mut`"));
+        }
+    }
+
+    Ok(fn_names)
+}
+
+/// Returns `true` if the attribute should be applied to each generated item, as well as the module
+fn is_item_attr(attr: &Attribute) -> bool {
+    match &attr.meta {
+        Meta::Path(path) | Meta::NameValue(MetaNameValue { path, .. }) => {
+            path.is_ident("deprecated")
+        }
+        Meta::List(list) => {
+            list.path.is_ident("deprecated")
+                || list.path.is_ident("doc")
+                    && list
+                        .parse_args::<Ident>()
+                        .is_ok_and(|ident| ident == "hidden")
+        }
+    }
+}
+
+#[derive(Clone)]
+struct FnNames {
+    new: Ident,
+    new_mut: Ident,
+}
+
 #[derive(Clone)]
 struct Data {
     attrs: Vec<Attribute>,
+    /// Attributes that are applied to each generated item
+    item_attrs: TokenStream,
     vis: Visibility,
     ident: Ident,
     generics: Generics,
+    /// Extra bounds that only apply to the new functions
+    new_where: Punctuated<WherePredicate, Token![,]>,
+    fn_names: FnNames,
     object_bounds: Punctuated<TypeParamBound, Token![+]>,
 }
 
@@ -250,6 +421,8 @@ struct TraitDocs<T> {
     inner_path: T,
 }
 
+// Most of the function is the quoted module
+#[allow(clippy::too_many_lines)]
 fn declare_new_fns_quote(
     data: Data,
     crate_: &Path,
@@ -258,9 +431,12 @@ fn declare_new_fns_quote(
 ) -> TokenStream {
     let Data {
         attrs,
+        item_attrs,
         vis,
         ident,
         mut generics,
+        new_where,
+        fn_names: FnNames { new, new_mut },
         object_bounds,
     } = data;
 
@@ -299,35 +475,73 @@ fn declare_new_fns_quote(
     // Get arguments to Dyn
     let arguments = get_arguments(full_generics);
 
+    // Span the trait object and its metadata bound on the trait, so that if the trait is not object
+    // safe, the error is reported once, on the trait in the declaration
+    let object_span = object_bounds.span();
+    let trait_object = quote_spanned! {object_span=> dyn #object_bounds };
+    let dyn_pointee = quote_spanned! {object_span=>
+        Dyn<#arguments>: ::core::ptr::Pointee<Metadata = ::core::ptr::DynMetadata<Dyn<#arguments>>>
+    };
+
+    // The bounds on the `new`, `new_mut` and `metadata` functions
+    let fn_where = quote! {
+        where
+            #dyn_pointee,
+            #where_predicates
+            #new_where
+            DynSliceFromType: 'static + #object_bounds,
+    };
+
+    let metadata_fns = metadata_fns_quote(
+        &item_attrs,
+        &quote! { "`dyn `[`", #trait_name, "`](", #trait_inner_path, ")" #(, "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" )* },
+        full_generics,
+        &arguments,
+        &fn_where,
+        &dyn_pointee,
+        where_predicates.as_ref(),
+    );
+
+    let object_safety_probe =
+        object_safety_probe_quote(&trait_object, full_generics, where_predicates.as_ref());
+
     quote! {
         #[doc = concat!("New functions for `&(mut) dyn [`[`", #trait_name, "`](", #trait_outer_path, ")", #( "` + `[`", #auto_trait_names, "`](", #auto_trait_outer_paths, ")" ,)* "`]`.")]
         #( #attrs )*
         #vis mod #ident {
-            use core::{
-                mem::transmute,
-                ptr::{metadata, null, DynMetadata, Pointee},
-            };
+            // The items may be deprecated, but they still need to refer to each other
+            #![allow(deprecated)]
 
-            use #crate_ as dyn_slice;
-            use dyn_slice::{DynSlice, DynSliceMut};
+            // Import the caller's scope, so that paths in the declaration resolve the same way as
+            // at the call site
+            #[allow(unused_imports)]
+            use super::*;
+
+            // Other imports would shadow the caller's scope, so use a name that is unlikely to be used
+            use #crate_ as __dyn_slice;
 
             #[doc = concat!("An alias for `dyn `[`", #trait_name, "`](", #trait_inner_path, ")" #(, "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" )*, ".")]
-            pub type Dyn<#stripped_generics> = dyn #object_bounds;
+            #item_attrs
+            pub type Dyn<#stripped_generics> = #trait_object;
+
+            #[doc = concat!("An alias for `&dyn [`[`", #trait_name, "`](", #trait_inner_path, ")", #( "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" ,)* "`]` ([`DynSlice<Dyn>`](__dyn_slice::DynSlice)).")]
+            #item_attrs
+            pub type Slice<'__slice, #stripped_generics> = __dyn_slice::DynSlice<'__slice, Dyn<#arguments>>;
+
+            #[doc = concat!("An alias for `&mut dyn [`[`", #trait_name, "`](", #trait_inner_path, ")", #( "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" ,)* "`]` ([`DynSliceMut<Dyn>`](__dyn_slice::DynSliceMut)).")]
+            #item_attrs
+            pub type SliceMut<'__slice, #stripped_generics> = __dyn_slice::DynSliceMut<'__slice, Dyn<#arguments>>;
 
-            #[doc = concat!("An alias for `&dyn [`[`", #trait_name, "`](", #trait_inner_path, ")", #( "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" ,)* "`]` ([`DynSlice<Dyn>`]).")]
-            pub type Slice<'__slice, #stripped_generics> = DynSlice<'__slice, Dyn<#arguments>>;
+            #object_safety_probe
 
-            #[doc = concat!("An alias for `&mut dyn [`[`", #trait_name, "`](", #trait_inner_path, ")", #( "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" ,)* "`]` ([`DynSliceMut<Dyn>`]).")]
-            pub type SliceMut<'__slice, #stripped_generics> = DynSliceMut<'__slice, Dyn<#arguments>>;
+            #metadata_fns
 
             #[allow(unused)]
             #[must_use]
             #[doc = concat!("Create a dyn slice from a slice of a type that implements [`", #trait_name, "`](", #trait_inner_path, ")" #(, "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" )*, ".")]
-            pub fn new<#full_generics DynSliceFromType>(value: &[DynSliceFromType]) -> Slice<'_, #arguments>
-            where
-                Dyn<#arguments>: Pointee<Metadata = DynMetadata<Dyn<#arguments>>>,
-                #where_predicates
-                DynSliceFromType: 'static + #object_bounds,
+            #item_attrs
+            pub fn #new<#full_generics DynSliceFromType>(value: &[DynSliceFromType]) -> Slice<'_, #arguments>
+            #fn_where
             {
                 // SAFETY:
                 // DynMetadata contains a single pointer to the vtable, and the layout is the same as *const (),
@@ -336,24 +550,22 @@ fn declare_new_fns_quote(
                     // Get the dyn metadata from the first element of value
                     // If value is empty, the metadata should never be accessed, so set it to a null pointer
                     let vtable_ptr = value.get(0).map_or(
-                        null::<()>(),
+                        ::core::ptr::null::<()>(),
                         |example| {
-                            transmute(metadata(example as &Dyn<#arguments>))
+                            ::core::mem::transmute(::core::ptr::metadata(example as &Dyn<#arguments>))
                         }
                     );
 
-                    DynSlice::with_vtable_ptr(value, vtable_ptr)
+                    __dyn_slice::DynSlice::with_vtable_ptr(value, vtable_ptr)
                 }
             }
 
             #[allow(unused)]
             #[must_use]
             #[doc = concat!("Create a mutable dyn slice from a mutable slice of a type that implements [`", #trait_name, "`](", #trait_inner_path, ")" #(, "` + `[`", #auto_trait_names, "`](", #auto_trait_inner_paths, ")" )*, ".")]
-            pub fn new_mut<#full_generics DynSliceFromType>(value: &mut [DynSliceFromType]) -> SliceMut<'_, #arguments>
-            where
-                Dyn<#arguments>: Pointee<Metadata = DynMetadata<Dyn<#arguments>>>,
-                #where_predicates
-                DynSliceFromType: 'static + #object_bounds,
+            #item_attrs
+            pub fn #new_mut<#full_generics DynSliceFromType>(value: &mut [DynSliceFromType]) -> SliceMut<'_, #arguments>
+            #fn_where
             {
                 // SAFETY:
                 // DynMetadata contains a single pointer to the vtable, and the layout is the same as *const (),
@@ -362,19 +574,90 @@ fn declare_new_fns_quote(
                     // Get the dyn metadata from the first element of value
                     // If value is empty, the metadata should never be accessed, so set it to a null pointer
                     let vtable_ptr = value.get(0).map_or(
-                        null::<()>(),
+                        ::core::ptr::null::<()>(),
                         |example| {
-                            transmute(metadata(example as &Dyn<#arguments>))
+                            ::core::mem::transmute(::core::ptr::metadata(example as &Dyn<#arguments>))
                         }
                     );
 
-                    DynSliceMut::with_vtable_ptr(value, vtable_ptr)
+                    __dyn_slice::DynSliceMut::with_vtable_ptr(value, vtable_ptr)
                 }
             }
         }
     }
 }
 
+/// Quotes a function that takes the trait object, so that if the trait is not object safe, the
+/// error is reported on the trait, before any errors from the other items
+fn object_safety_probe_quote(
+    trait_object: &TokenStream,
+    full_generics: &Punctuated<GenericParam, Token![,]>,
+    where_predicates: Option<&Punctuated<WherePredicate, Token![,]>>,
+) -> TokenStream {
+    quote! {
+        // This is never called, it only checks that the trait object can be created
+        #[allow(unused)]
+        fn __object_safety_probe<#full_generics>(_: &(#trait_object))
+        where
+            #where_predicates
+        {}
+    }
+}
+
+/// Quotes the `metadata`, `empty` and `empty_untyped` functions
+///
+/// `dyn_doc` is the comma separated parts of the documentation for `Dyn`, for use in `concat!`
+fn metadata_fns_quote(
+    item_attrs: &TokenStream,
+    dyn_doc: &TokenStream,
+    full_generics: &Punctuated<GenericParam, Token![,]>,
+    arguments: &Punctuated<GenericArgument, Token![,]>,
+    fn_where: &TokenStream,
+    dyn_pointee: &TokenStream,
+    where_predicates: Option<&Punctuated<WherePredicate, Token![,]>>,
+) -> TokenStream {
+    quote! {
+        #[allow(unused)]
+        #[must_use]
+        #[doc = concat!("Returns the metadata for `DynSliceFromType` as a ", #dyn_doc, ".")]
+        ///
+        /// This can be stored in a `const` or `static`, for use with the `from_parts_with_metadata`
+        /// constructors, without an element to take the metadata from.
+        #item_attrs
+        pub const fn metadata<#full_generics DynSliceFromType>() -> ::core::ptr::DynMetadata<Dyn<#arguments>>
+        #fn_where
+        {
+            // Unsizing a pointer does not dereference it, so a null pointer can be used to get
+            // the metadata
+            ::core::ptr::metadata(::core::ptr::null::<DynSliceFromType>() as *const Dyn<#arguments>)
+        }
+
+        #[allow(unused)]
+        #[must_use]
+        #[doc = concat!("Create an empty dyn slice with the metadata for `DynSliceFromType` as a ", #dyn_doc, ".")]
+        #item_attrs
+        pub fn empty<'__slice, #full_generics DynSliceFromType>() -> Slice<'__slice, #arguments>
+        #fn_where
+        {
+            __dyn_slice::DynSlice::empty_with_metadata(
+                ::core::ptr::metadata(::core::ptr::null::<DynSliceFromType>() as *const Dyn<#arguments>)
+            )
+        }
+
+        #[allow(unused)]
+        #[must_use]
+        /// Create an empty dyn slice without metadata.
+        #item_attrs
+        pub const fn empty_untyped<'__slice, #full_generics>() -> Slice<'__slice, #arguments>
+        where
+            #dyn_pointee,
+            #where_predicates
+        {
+            __dyn_slice::DynSlice::empty()
+        }
+    }
+}
+
 fn remove_generic_bounds(
     generics: &Punctuated<GenericParam, Token![,]>,
 ) -> Punctuated<GenericParam, Token![,]> {
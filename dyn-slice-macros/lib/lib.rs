@@ -19,9 +19,9 @@
 
 mod declare_new_fns;
 use declare_new_fns::DeclareNewFns;
-mod path_ext;
+mod super_path;
 use proc_macro2::TokenStream;
-use syn::{spanned::Spanned, Path, TraitBound, TypeParamBound};
+use syn::{spanned::Spanned, Path};
 
 #[proc_macro]
 pub fn declare_new_fns(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -42,11 +42,3 @@ fn stringify_basic_path(path: &Path) -> syn::Result<String> {
         })
         .ok_or_else(|| syn::Error::new(path.span(), "empty path"))
 }
-
-fn type_param_bound_select_trait(bound: &mut TypeParamBound) -> Option<&mut TraitBound> {
-    if let TypeParamBound::Trait(trait_bound) = bound {
-        Some(trait_bound)
-    } else {
-        None
-    }
-}
@@ -0,0 +1,30 @@
+//! The generated module imports the caller's scope with `use super::*`, so
+//! paths in a declaration resolve the same way inside the module as they do
+//! at the call site.
+//! The only exception is paths that start with `super`, which need to go up
+//! one more module.
+
+use proc_macro2::Span;
+use syn::{
+    visit_mut::{self, VisitMut},
+    Ident, Path,
+};
+
+/// Adds a `super::` before any paths that start with `super`
+pub struct AnchorSuper;
+
+impl VisitMut for AnchorSuper {
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        if path.leading_colon.is_none()
+            && path
+                .segments
+                .first()
+                .is_some_and(|segment| segment.ident == "super")
+        {
+            path.segments
+                .insert(0, Ident::new("super", Span::call_site()).into());
+        }
+
+        visit_mut::visit_path_mut(self, path);
+    }
+}
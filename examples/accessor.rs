@@ -0,0 +1,71 @@
+// A dyn slice can be passed to code that does not share Rust's layouts, like another WebAssembly
+// module, with a DynSliceAccessor. This requires the `capi` feature.
+//
+// Here, the host and guest are modules in the same program, and the guest only uses the
+// `#[repr(C)]` accessor and element types. In WebAssembly, the function pointers in the accessor
+// would be indices into a function table that is shared between the modules.
+
+use dyn_slice::capi::DynSliceAccessor;
+
+// The element type has a `#[repr(C)]` layout, so the guest can read it directly
+#[repr(C)]
+#[derive(Debug)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+mod host {
+    use dyn_slice::standard::debug;
+
+    use super::{guest, Point};
+
+    pub fn run() {
+        let points = [
+            Point { x: 1, y: 2 },
+            Point { x: 3, y: 4 },
+            Point { x: 5, y: 6 },
+        ];
+
+        // Create a dyn slice, and an accessor for it
+        let slice = debug::new(&points);
+        let accessor = slice.accessor();
+
+        // Pass the accessor to the guest, while `points` is still borrowed
+        let sum = guest::sum_points(accessor);
+        println!("sum of {slice:?} = {sum:?}");
+        assert_eq!((sum.x, sum.y), (9, 12));
+    }
+}
+
+mod guest {
+    use super::{DynSliceAccessor, Point};
+
+    // The guest's entry point, which only takes `#[repr(C)]` types
+    pub extern "C" fn sum_points(accessor: DynSliceAccessor) -> Point {
+        let mut sum = Point { x: 0, y: 0 };
+
+        // SAFETY: the host passes an accessor of a slice of `Point`s, which is still borrowed
+        unsafe {
+            assert_eq!(accessor.element_size(), core::mem::size_of::<Point>());
+
+            for index in 0..accessor.len() {
+                let point = &*accessor.index_ptr(index).cast::<Point>();
+                sum.x += point.x;
+                sum.y += point.y;
+            }
+        }
+
+        sum
+    }
+}
+
+fn main() {
+    host::run();
+}
+
+// Test the example (this can be ignored)
+#[test]
+fn test() {
+    main()
+}
@@ -0,0 +1,143 @@
+use core::{
+    marker::Unsize,
+    ptr::{DynMetadata, Pointee},
+};
+
+use any_vec::{mem::MemBuilder, traits::Trait, AnyVec};
+
+use crate::{standard::AnyLike, utils::metadata_of, DynSlice, DynSliceMut};
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "any_vec")))]
+    /// Creates a dyn slice of the elements of an [`AnyVec`], or returns `None` if they are not of
+    /// type `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use any_vec::AnyVec;
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let mut vec: AnyVec = AnyVec::new::<u8>();
+    /// vec.downcast_mut::<u8>().unwrap().push(1);
+    /// vec.downcast_mut::<u8>().unwrap().push(2);
+    ///
+    /// let slice = debug::Slice::from_any_vec::<u8, _, _>(&vec).unwrap();
+    /// # assert_eq!(format!("{slice:?}"), "[1, 2]");
+    /// println!("{slice:?}"); // [1, 2]
+    /// assert!(debug::Slice::from_any_vec::<u16, _, _>(&vec).is_none());
+    /// ```
+    pub fn from_any_vec<T, Traits, M>(vec: &'a AnyVec<Traits, M>) -> Option<Self>
+    where
+        T: Unsize<Dyn> + 'static,
+        Traits: ?Sized + Trait,
+        M: MemBuilder,
+    {
+        let elements = vec.downcast_ref::<T>()?.as_slice();
+        let metadata = metadata_of::<T, Dyn>();
+
+        // SAFETY:
+        // The metadata is for `T` as `Dyn`, which is the type of the elements.
+        Some(unsafe { Self::with_metadata(elements, metadata) })
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "any_vec")))]
+    /// Creates a mutable dyn slice of the elements of an [`AnyVec`], or returns `None` if they
+    /// are not of type `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use any_vec::AnyVec;
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut vec: AnyVec = AnyVec::new::<u8>();
+    /// vec.downcast_mut::<u8>().unwrap().push(1);
+    ///
+    /// let mut slice = add_assign::SliceMut::<u8>::from_any_vec_mut::<u8, _, _>(&mut vec).unwrap();
+    /// slice[0] += 10;
+    /// assert_eq!(vec.downcast_ref::<u8>().unwrap().as_slice(), [11]);
+    /// ```
+    pub fn from_any_vec_mut<T, Traits, M>(vec: &'a mut AnyVec<Traits, M>) -> Option<Self>
+    where
+        T: Unsize<Dyn> + 'static,
+        Traits: ?Sized + Trait,
+        M: MemBuilder,
+    {
+        let elements = vec.downcast_mut::<T>()?.as_mut_slice();
+        let metadata = metadata_of::<T, Dyn>();
+
+        // SAFETY:
+        // The metadata is for `T` as `Dyn`, which is the type of the elements.
+        Some(unsafe { Self::with_metadata(elements, metadata) })
+    }
+}
+
+impl<'a, Dyn: AnyLike + ?Sized> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "any_vec")))]
+    /// Clones the elements into a new [`AnyVec`], or returns `None` if the underlying slice is not
+    /// of type `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let slice = any::new(&[1_u8, 2, 3]);
+    ///
+    /// let vec = slice.to_any_vec::<u8>().unwrap();
+    /// assert_eq!(vec.downcast_ref::<u8>().unwrap().as_slice(), [1, 2, 3]);
+    /// assert!(slice.to_any_vec::<u16>().is_none());
+    /// ```
+    pub fn to_any_vec<T: Clone + 'static>(&self) -> Option<AnyVec> {
+        let elements = self.downcast::<T>()?;
+
+        let mut vec = AnyVec::with_capacity::<T>(elements.len());
+        let mut typed = vec.downcast_mut::<T>()?;
+        for element in elements {
+            typed.push(element.clone());
+        }
+
+        Some(vec)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use any_vec::AnyVec;
+
+    use crate::standard::{add_assign, any, debug, partial_eq};
+
+    #[test]
+    fn views() {
+        let mut vec: AnyVec = AnyVec::new::<u8>();
+        assert!(partial_eq::Slice::<u8>::from_any_vec::<u8, _, _>(&vec)
+            .unwrap()
+            .is_empty());
+
+        vec.downcast_mut::<u8>().unwrap().push(1);
+        vec.downcast_mut::<u8>().unwrap().push(2);
+
+        let slice = partial_eq::Slice::<u8>::from_any_vec::<u8, _, _>(&vec).unwrap();
+        assert!(slice == [1, 2][..]);
+        assert!(debug::Slice::from_any_vec::<i8, _, _>(&vec).is_none());
+
+        let mut slice = add_assign::SliceMut::<u8>::from_any_vec_mut::<u8, _, _>(&mut vec).unwrap();
+        slice[1] += 10;
+        assert_eq!(vec.downcast_ref::<u8>().unwrap().as_slice(), [1, 12]);
+        assert!(debug::SliceMut::from_any_vec_mut::<i8, _, _>(&mut vec).is_none());
+    }
+
+    #[test]
+    fn to_any_vec() {
+        let array = [String::from("a"), String::from("b")];
+        let slice = any::new(&array);
+
+        let vec = slice.to_any_vec::<String>().unwrap();
+        assert_eq!(vec.downcast_ref::<String>().unwrap().as_slice(), array);
+        assert!(slice.to_any_vec::<u8>().is_none());
+        assert!(any::new::<u8>(&[]).to_any_vec::<u8>().unwrap().is_empty());
+    }
+}
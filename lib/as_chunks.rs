@@ -0,0 +1,308 @@
+use core::{
+    array,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{
+    iter::{ArrayChunks, ArrayChunksMut},
+    utils::extend_lifetime,
+    DynSlice, DynSliceMut,
+};
+
+/// A view of a [`DynSlice`] as a sequence of arrays of `N` references, like `&[[T; N]]`.
+///
+/// This is created by [`DynSlice::as_chunks`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::debug;
+///
+/// let array = [1, 2, 3, 4, 5];
+/// let slice = debug::new(&array);
+///
+/// let (chunks, remainder) = slice.as_chunks::<2>();
+/// # assert_eq!(chunks.len(), 2);
+/// let [a, b] = chunks.get(1).unwrap();
+/// # assert_eq!(format!("{a:?} {b:?}"), "3 4");
+/// println!("{a:?} {b:?}"); // 3 4
+/// # assert_eq!(format!("{remainder:?}"), "[5]");
+/// println!("{remainder:?}"); // [5]
+/// ```
+pub struct ChunksExactView<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize> {
+    /// The elements of the full chunks, so its length is a multiple of `N`
+    slice: DynSlice<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize>
+    ChunksExactView<'a, Dyn, N>
+{
+    #[inline]
+    #[must_use]
+    /// Returns the number of chunks.
+    pub const fn len(&self) -> usize {
+        self.slice.len / N
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if there are no chunks.
+    pub const fn is_empty(&self) -> bool {
+        self.slice.len == 0
+    }
+
+    #[must_use]
+    /// Returns the chunk at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<[&'a Dyn; N]> {
+        if index >= self.len() {
+            return None;
+        }
+
+        // SAFETY:
+        // `index < self.len()`, so the chunk is within the slice.
+        // The data lives for 'a, and the copy of the slice is discarded, so the lifetime can be
+        // extended.
+        let chunk = unsafe { extend_lifetime(self.slice.slice_unchecked(index * N, N)) };
+        let mut iter = chunk.into_iter();
+
+        Some(array::from_fn(|_| {
+            // SAFETY:
+            // The chunk has exactly `N` elements.
+            unsafe { iter.next_unchecked() }
+        }))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements of all of the chunks as one dyn slice.
+    pub const fn as_dyn_slice(&self) -> DynSlice<'a, Dyn> {
+        self.slice
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over the chunks.
+    pub fn iter(&self) -> ArrayChunks<'a, Dyn, N> {
+        self.slice.into_iter().array_chunks()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize> Clone
+    for ChunksExactView<'a, Dyn, N>
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize> Copy
+    for ChunksExactView<'a, Dyn, N>
+{
+}
+
+/// A view of a [`DynSliceMut`] as a sequence of arrays of `N` mutable references, like
+/// `&mut [[T; N]]`.
+///
+/// This is created by [`DynSliceMut::as_chunks_mut`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::add_assign;
+///
+/// let mut array = [1, 2, 3, 4, 5];
+/// let mut slice = add_assign::new_mut(&mut array);
+///
+/// let (mut chunks, mut remainder) = slice.as_chunks_mut::<2>();
+/// let [a, b] = chunks.get_mut(1).unwrap();
+/// *a += 10;
+/// *b += 20;
+/// *remainder.first_mut().unwrap() += 30;
+/// assert_eq!(array, [1, 2, 13, 24, 35]);
+/// ```
+pub struct ChunksExactViewMut<
+    'a,
+    Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+    const N: usize,
+> {
+    /// The elements of the full chunks, so its length is a multiple of `N`
+    slice: DynSliceMut<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize>
+    ChunksExactViewMut<'a, Dyn, N>
+{
+    #[inline]
+    #[must_use]
+    /// Returns the number of chunks.
+    pub const fn len(&self) -> usize {
+        self.slice.0.len / N
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if there are no chunks.
+    pub const fn is_empty(&self) -> bool {
+        self.slice.0.len == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an immutable view of the chunks.
+    pub const fn as_view(&self) -> ChunksExactView<'_, Dyn, N> {
+        ChunksExactView {
+            slice: self.slice.0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the chunk at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<[&Dyn; N]> {
+        self.as_view().get(index)
+    }
+
+    #[must_use]
+    /// Returns the chunk at `index` as mutable references, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<[&mut Dyn; N]> {
+        if index >= self.len() {
+            return None;
+        }
+
+        // SAFETY:
+        // `index < self.len()`, so the chunk is within the slice.
+        let chunk = unsafe { self.slice.slice_unchecked_mut(index * N, N) };
+        let mut iter = chunk.into_iter();
+
+        Some(array::from_fn(|_| {
+            // SAFETY:
+            // The chunk has exactly `N` elements.
+            unsafe { iter.next().unwrap_unchecked() }
+        }))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements of all of the chunks as one mutable dyn slice.
+    pub const fn into_dyn_slice(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over the chunks.
+    pub fn iter(&self) -> ArrayChunks<'_, Dyn, N> {
+        self.as_view().iter()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over the chunks, as mutable references.
+    pub fn iter_mut(&mut self) -> ArrayChunksMut<'_, Dyn, N> {
+        self.slice.chunks_exact_mut_arrays()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    /// Splits the slice into chunks of `N` elements, and the remainder that does not fit into a
+    /// full chunk, like [`slice::as_chunks`].
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    pub fn as_chunks<const N: usize>(&self) -> (ChunksExactView<'_, Dyn, N>, DynSlice<'_, Dyn>) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let len = self.len - self.len % N;
+        // SAFETY:
+        // `len <= self.len`, so is a valid split point.
+        let (chunks, remainder) = unsafe { self.split_at_unchecked(len) };
+
+        (ChunksExactView { slice: chunks }, remainder)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    /// Splits the slice into mutable chunks of `N` elements, and the remainder that does not fit
+    /// into a full chunk, like [`slice::as_chunks_mut`].
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    pub fn as_chunks_mut<const N: usize>(
+        &mut self,
+    ) -> (ChunksExactViewMut<'_, Dyn, N>, DynSliceMut<'_, Dyn>) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let len = self.len() - self.len() % N;
+        // SAFETY:
+        // `len <= self.len()`, so is a valid split point.
+        let (chunks, remainder) = unsafe { self.split_at_unchecked_mut(len) };
+
+        (ChunksExactViewMut { slice: chunks }, remainder)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::{add_assign, partial_eq};
+
+    #[test]
+    fn as_chunks() {
+        let array = [1, 2, 3, 4, 5, 6, 7];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        let (chunks, remainder) = slice.as_chunks::<3>();
+        assert_eq!(chunks.len(), 2);
+        assert!(!chunks.is_empty());
+        assert!(remainder == [7][..]);
+        assert!(chunks.as_dyn_slice() == array[..6]);
+
+        let [a, b, c] = chunks.get(1).unwrap();
+        assert!(a == &4 && b == &5 && c == &6);
+        assert!(chunks.get(2).is_none());
+
+        let firsts: Vec<_> = chunks.iter().map(|[a, _, _]| a).collect();
+        assert!(firsts[0] == &1 && firsts[1] == &4);
+    }
+
+    #[test]
+    fn as_chunks_short() {
+        let array = [1, 2];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        let (chunks, remainder) = slice.as_chunks::<3>();
+        assert!(chunks.is_empty());
+        assert!(chunks.get(0).is_none());
+        assert!(remainder == array[..]);
+    }
+
+    #[test]
+    fn as_chunks_mut() {
+        let mut array = [1, 2, 3, 4, 5];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+
+        let (mut chunks, mut remainder) = slice.as_chunks_mut::<2>();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.get(2).is_none());
+        assert!(chunks.get_mut(2).is_none());
+
+        let [a, b] = chunks.get_mut(0).unwrap();
+        *a += 10;
+        *b += 20;
+        for [a, _] in chunks.iter_mut() {
+            *a += 100;
+        }
+        assert_eq!(chunks.iter().count(), 2);
+        assert_eq!(chunks.into_dyn_slice().len(), 4);
+        remainder.iter_mut().for_each(|x| *x += 30);
+
+        assert_eq!(array, [111, 22, 103, 4, 35]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero() {
+        let array = [1, 2];
+        let slice = partial_eq::new::<u8, u8>(&array);
+        let _ = slice.as_chunks::<0>();
+    }
+}
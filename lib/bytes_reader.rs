@@ -0,0 +1,151 @@
+use core::{
+    fmt::{self, Debug},
+    ptr::{DynMetadata, Pointee},
+};
+use std::io::{self, BufRead, Read};
+
+use crate::{DynSlice, Iter};
+
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+/// A reader over the bytes of each element of a dyn slice of byte buffers, in order.
+///
+/// This implements [`BufRead`], which returns the bytes of each element without copying them.
+///
+/// This is created by [`DynSlice::bytes_reader`] or [`BytesReader::new`].
+///
+/// # Example
+/// ```
+/// use std::io::Read;
+///
+/// use dyn_slice::standard::as_ref;
+///
+/// let buffers = [vec![1_u8, 2], vec![], vec![3, 4, 5]];
+/// let slice = as_ref::new::<[u8], _>(&buffers);
+///
+/// let mut bytes = Vec::new();
+/// slice.bytes_reader().read_to_end(&mut bytes).unwrap();
+/// assert_eq!(bytes, [1, 2, 3, 4, 5]);
+/// ```
+pub struct BytesReader<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> {
+    /// The elements after the current one
+    iter: Iter<'a, Dyn>,
+    /// The bytes of the current element that have not been read
+    current: &'a [u8],
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> BytesReader<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a reader over the bytes of the elements of `slice`.
+    pub fn new(slice: DynSlice<'a, Dyn>) -> Self {
+        Self {
+            iter: slice.into_iter(),
+            current: &[],
+        }
+    }
+
+    #[must_use]
+    /// Returns the number of bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.current.len() + self.iter.as_slice().total_len()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> Clone
+    for BytesReader<'a, Dyn>
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            current: self.current,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> Read
+    for BytesReader<'a, Dyn>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+
+        Ok(len)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> BufRead
+    for BytesReader<'a, Dyn>
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        // Skip to the next element with bytes left, if the current one has been read
+        while self.current.is_empty() {
+            let Some(element) = self.iter.next() else {
+                break;
+            };
+            self.current = element.as_ref();
+        }
+
+        Ok(self.current)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.current = &self.current[amt.min(self.current.len())..];
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> Debug
+    for BytesReader<'a, Dyn>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BytesReader")
+            .field("remaining", &self.remaining())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "std")))]
+    /// Returns a reader over the bytes of each element, in order.
+    ///
+    /// See [`BytesReader`].
+    pub fn bytes_reader(&self) -> BytesReader<'_, Dyn> {
+        BytesReader::new(*self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, Read};
+
+    use crate::standard::as_ref;
+
+    #[test]
+    fn read() {
+        let buffers = [&b"ab"[..], b"", b"cde", b"", b"f"];
+        let slice = as_ref::new::<[u8], _>(&buffers);
+        let mut reader = slice.bytes_reader();
+        assert_eq!(reader.remaining(), 6);
+
+        let mut buf = [0; 4];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"ab");
+        assert_eq!(reader.read(&mut buf[..2]).unwrap(), 2);
+        assert_eq!(&buf[..2], b"cd");
+        assert_eq!(reader.remaining(), 2);
+
+        let mut rest = Vec::new();
+        reader.clone().read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"ef");
+
+        assert_eq!(reader.fill_buf().unwrap(), b"e");
+        reader.consume(1);
+        assert_eq!(reader.fill_buf().unwrap(), b"f");
+        reader.consume(1);
+        assert!(reader.fill_buf().unwrap().is_empty());
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+}
@@ -0,0 +1,138 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    any::Any,
+    panic::AssertUnwindSafe,
+    ptr::{DynMetadata, Pointee},
+};
+use std::panic;
+
+use crate::DynSlice;
+
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+/// A callable type that can be called with a tuple of arguments, `Args`.
+///
+/// This is implemented for all types that implement [`Fn`] with up to 6 arguments, including
+/// `dyn Fn(...)` trait objects, so that dyn slices of callbacks can be called generically over
+/// their arguments.
+pub trait CallWith<Args> {
+    /// The return type of the call.
+    type Output;
+
+    /// Calls `self` with `args`.
+    fn call_with(&self, args: Args) -> Self::Output;
+}
+
+macro_rules! impl_call_with {
+    ( $( ( $( $arg:ident ),* ) ),* $(,)? ) => {
+        $(
+            impl<F: ?Sized + Fn($( $arg ),*) -> R, R, $( $arg ),*> CallWith<($( $arg, )*)> for F {
+                type Output = R;
+
+                #[inline]
+                #[allow(non_snake_case)]
+                fn call_with(&self, ($( $arg, )*): ($( $arg, )*)) -> Self::Output {
+                    self($( $arg ),*)
+                }
+            }
+        )*
+    };
+}
+impl_call_with!(
+    (),
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, G),
+);
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[cfg_attr(doc, doc(cfg(feature = "std")))]
+    /// Calls each element with a clone of `args`, catching any panics, and returns the results in
+    /// order.
+    ///
+    /// A panicking callback does not stop the rest from being called. Each call is treated as
+    /// [unwind safe](core::panic::UnwindSafe), so a callback that panics may leave its own state,
+    /// or the arguments, inconsistent.
+    ///
+    /// Panics are still reported by the panic hook.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(ptr_metadata)]
+    /// use dyn_slice::declare_new_fns;
+    ///
+    /// declare_new_fns!(callback Fn(u8) -> u8);
+    ///
+    /// let callbacks: [&dyn Fn(u8) -> u8; 3] = [&|x| x + 1, &|_| panic!("oops"), &|x| x * 2];
+    /// let slice = callback::new(&callbacks);
+    ///
+    /// let results = slice.call_each_catching((5,));
+    /// assert_eq!(*results[0].as_ref().unwrap(), 6);
+    /// assert!(results[1].is_err());
+    /// assert_eq!(*results[2].as_ref().unwrap(), 10);
+    /// ```
+    pub fn call_each_catching<Args: Clone>(
+        &self,
+        args: Args,
+    ) -> Vec<Result<Dyn::Output, Box<dyn Any + Send>>>
+    where
+        Dyn: CallWith<Args>,
+    {
+        self.iter()
+            .map(|callback| {
+                let args = args.clone();
+                panic::catch_unwind(AssertUnwindSafe(|| callback.call_with(args)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::CallWith;
+    use crate::declare_new_fns;
+
+    declare_new_fns!(
+        #[crate = crate]
+        callback<A, B> Fn(A, B) -> u32
+    );
+
+    #[test]
+    fn call_with() {
+        let add = |a: u32, b: u32| a + b;
+        assert_eq!(add.call_with((1, 2)), 3);
+        assert_eq!((|| 7).call_with(()), 7);
+    }
+
+    #[test]
+    fn call_each_catching() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        let counted = |a: u32, b: u32| {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            a * b
+        };
+
+        let array = [counted, counted];
+        let results = callback::new(&array).call_each_catching((3, 4));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 2);
+        assert!(results.iter().all(|result| *result.as_ref().unwrap() == 12));
+
+        let panicking = |_: u32, b: u32| -> u32 {
+            assert!(b != 0, "divide by zero");
+            100 / b
+        };
+        let array = [panicking, panicking];
+        let slice = callback::new(&array);
+        assert!(slice.call_each_catching((0, 0)).iter().all(Result::is_err));
+        let results = slice.call_each_catching((0, 5));
+        assert_eq!(*results[1].as_ref().unwrap(), 20);
+
+        assert!(callback::new::<u32, u32, fn(u32, u32) -> u32>(&[])
+            .call_each_catching((0, 0))
+            .is_empty());
+    }
+}
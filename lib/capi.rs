@@ -0,0 +1,315 @@
+//! `extern "C"` functions for traversing a dyn slice from C, C++ or other languages.
+//!
+//! A dyn slice is handed across the boundary as a [`RawDynSlice`], which has a stable
+//! `#[repr(C)]` layout:
+//!
+//! ```c
+//! typedef struct RawDynSlice {
+//!     const void *data;
+//!     uintptr_t len;
+//!     const void *vtable;
+//! } RawDynSlice;
+//! ```
+//!
+//! The layout of the vtable is not stable, so foreign code must not read it. Instead, it can call
+//! the functions in this module, which are compiled into the Rust library that created the slice,
+//! to get the length, the size of the elements and pointers to the elements. The elements can then
+//! be passed back to Rust, or read directly if their type has a `#[repr(C)]` layout.
+//!
+//! The exported functions do not know the trait of the slice, so they read the size of the
+//! elements as if the vtable were for `dyn Any`. This relies on rustc storing the size at the same
+//! place in every vtable, which is not guaranteed. A [`DynSliceAccessor`] reads the vtable as the
+//! correct trait, so it should be preferred where possible.
+//!
+//! The functions are exported with `#[no_mangle]`, so the crate that links them must be built as
+//! a `staticlib` or `cdylib`. A C header can be generated with
+//! [cbindgen](https://github.com/mozilla/cbindgen), using the `cbindgen.toml` in the repository:
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output dyn_slice.h
+//! ```
+//!
+//! # Example
+//! ```
+//! use dyn_slice::{
+//!     capi::{dyn_slice_element_size, dyn_slice_index_ptr, dyn_slice_len},
+//!     standard::debug,
+//! };
+//!
+//! let array = [1_u32, 2, 3];
+//! let raw = debug::new(&array).into_raw();
+//!
+//! // SAFETY: `raw` was created from a dyn slice of `array`, which is still borrowed
+//! unsafe {
+//!     assert_eq!(dyn_slice_len(raw), 3);
+//!     assert_eq!(dyn_slice_element_size(raw), 4);
+//!     assert_eq!(dyn_slice_index_ptr(raw, 1), (&array[1] as *const u32).cast());
+//!     assert!(dyn_slice_index_ptr(raw, 3).is_null());
+//! }
+//! ```
+
+use core::{
+    any::Any,
+    mem::transmute,
+    ptr::{self, DynMetadata, Pointee},
+};
+
+use crate::{DynSlice, RawDynSlice};
+
+/// Returns the size of the elements of `raw`, or 0 if it has no vtable.
+///
+/// # Safety
+/// `raw` must be the raw parts of a valid dyn slice, and the size must be stored at the same place
+/// in its vtable as in a `dyn Any` vtable.
+unsafe fn element_size(raw: RawDynSlice) -> usize {
+    if raw.vtable.is_null() {
+        return 0;
+    }
+
+    // The caller guarantees that the size is stored in the same place as in a `dyn Any` vtable,
+    // so the metadata for `dyn Any` can be used to read it
+    transmute::<*const (), DynMetadata<dyn Any>>(raw.vtable).size_of()
+}
+
+#[no_mangle]
+#[must_use]
+#[cfg_attr(doc, doc(cfg(feature = "capi")))]
+/// Returns the number of elements in the dyn slice.
+///
+/// # Safety
+/// `raw` must be the raw parts of a valid dyn slice.
+pub const unsafe extern "C" fn dyn_slice_len(raw: RawDynSlice) -> usize {
+    raw.len
+}
+
+#[no_mangle]
+#[must_use]
+#[cfg_attr(doc, doc(cfg(feature = "capi")))]
+/// Returns the size of the underlying type of the dyn slice, in bytes, or 0 if the slice is empty
+/// and has no vtable.
+///
+/// # Safety
+/// `raw` must be the raw parts of a valid dyn slice.
+///
+/// The size of the elements is read from the vtable as if it were for `dyn Any`, which relies on
+/// rustc storing the size at the same place in every vtable. This is not guaranteed by the
+/// language, so [`DynSliceAccessor`] should be preferred where possible.
+pub unsafe extern "C" fn dyn_slice_element_size(raw: RawDynSlice) -> usize {
+    element_size(raw)
+}
+
+#[no_mangle]
+#[must_use]
+#[cfg_attr(doc, doc(cfg(feature = "capi")))]
+/// Returns a pointer to the element at `index` in the dyn slice, or null if `index` is out of
+/// bounds.
+///
+/// # Safety
+/// `raw` must be the raw parts of a valid dyn slice.
+///
+/// The element is found using the size read by [`dyn_slice_element_size`], so it relies on the
+/// same vtable layout, which rustc does not guarantee.
+pub unsafe extern "C" fn dyn_slice_index_ptr(raw: RawDynSlice, index: usize) -> *const () {
+    if index >= raw.len {
+        return ptr::null();
+    }
+
+    raw.data.byte_add(index * element_size(raw))
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(doc, doc(cfg(feature = "capi")))]
+/// A dyn slice, with function pointers for accessing it.
+///
+/// This is for passing a dyn slice to code that does not share Rust's layouts or the exported
+/// functions, for example another WebAssembly module, where the function pointers are indices
+/// into a shared function table. The functions are generated for each trait, so they read the
+/// vtable as the correct type.
+///
+/// Like [`RawDynSlice`], the accessor does not keep the slice borrowed, so it must not be used
+/// after the slice that it was created from.
+///
+/// This is created by [`DynSlice::accessor`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::{capi::DynSliceAccessor, standard::debug};
+///
+/// extern "C" fn guest_sum(accessor: DynSliceAccessor) -> u64 {
+///     // SAFETY: the host passes an accessor of a slice of `u32`s, which is still borrowed
+///     unsafe {
+///         assert_eq!(accessor.element_size(), 4);
+///         (0..accessor.len())
+///             .map(|index| u64::from(*accessor.index_ptr(index).cast::<u32>()))
+///             .sum()
+///     }
+/// }
+///
+/// let array = [1_u32, 2, 3];
+/// assert_eq!(guest_sum(debug::new(&array).accessor()), 6);
+/// ```
+pub struct DynSliceAccessor {
+    /// The raw parts of the slice, which should only be passed to the functions.
+    pub raw: RawDynSlice,
+    /// Returns the number of elements in the slice.
+    pub len: unsafe extern "C" fn(raw: RawDynSlice) -> usize,
+    /// Returns the size of the underlying type, or 0 if the slice is empty and has no vtable.
+    pub element_size: unsafe extern "C" fn(raw: RawDynSlice) -> usize,
+    /// Returns a pointer to the element at `index`, or null if it is out of bounds.
+    pub index_ptr: unsafe extern "C" fn(raw: RawDynSlice, index: usize) -> *const (),
+}
+
+/// The [`DynSliceAccessor::len`] function.
+const unsafe extern "C" fn accessor_len(raw: RawDynSlice) -> usize {
+    raw.len
+}
+
+/// The [`DynSliceAccessor::element_size`] function for `Dyn`.
+///
+/// # Safety
+/// `raw` must be the raw parts of a valid dyn slice of `Dyn`.
+unsafe extern "C" fn accessor_element_size<Dyn>(raw: RawDynSlice) -> usize
+where
+    Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+{
+    DynSlice::<Dyn>::from_raw(raw).element_size()
+}
+
+/// The [`DynSliceAccessor::index_ptr`] function for `Dyn`.
+///
+/// # Safety
+/// `raw` must be the raw parts of a valid dyn slice of `Dyn`.
+unsafe extern "C" fn accessor_index_ptr<Dyn>(raw: RawDynSlice, index: usize) -> *const ()
+where
+    Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+{
+    let slice = DynSlice::<Dyn>::from_raw(raw);
+    if index >= slice.len {
+        return ptr::null();
+    }
+
+    slice.get_ptr_unchecked(index)
+}
+
+impl DynSliceAccessor {
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in the slice, by calling [`DynSliceAccessor::len`].
+    ///
+    /// # Safety
+    /// The slice that the accessor was created from must still be borrowed.
+    pub unsafe fn len(&self) -> usize {
+        (self.len)(self.raw)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the slice has a length of 0.
+    ///
+    /// # Safety
+    /// The slice that the accessor was created from must still be borrowed.
+    pub unsafe fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the size of the underlying type, by calling [`DynSliceAccessor::element_size`].
+    ///
+    /// # Safety
+    /// The slice that the accessor was created from must still be borrowed.
+    pub unsafe fn element_size(&self) -> usize {
+        (self.element_size)(self.raw)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a pointer to the element at `index`, or null if it is out of bounds, by calling
+    /// [`DynSliceAccessor::index_ptr`].
+    ///
+    /// # Safety
+    /// The slice that the accessor was created from must still be borrowed.
+    pub unsafe fn index_ptr(&self, index: usize) -> *const () {
+        (self.index_ptr)(self.raw, index)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "capi")))]
+    /// Returns a [`DynSliceAccessor`] for the slice.
+    pub fn accessor(&self) -> DynSliceAccessor {
+        DynSliceAccessor {
+            raw: self.into_raw(),
+            len: accessor_len,
+            element_size: accessor_element_size::<Dyn>,
+            index_ptr: accessor_index_ptr::<Dyn>,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dyn_slice_element_size, dyn_slice_index_ptr, dyn_slice_len};
+    use crate::{
+        standard::{debug, partial_eq},
+        DynSlice,
+    };
+
+    #[test]
+    fn traverse() {
+        let array = [(1_u8, 2_u16), (3, 4), (5, 6)];
+        let raw = debug::new(&array).into_raw();
+
+        // SAFETY:
+        // `raw` is the raw parts of a dyn slice of `array`, which is still borrowed.
+        unsafe {
+            assert_eq!(dyn_slice_len(raw), 3);
+            assert_eq!(dyn_slice_element_size(raw), 4);
+            for (index, element) in array.iter().enumerate() {
+                assert_eq!(
+                    dyn_slice_index_ptr(raw, index),
+                    (element as *const (u8, u16)).cast()
+                );
+            }
+            assert!(dyn_slice_index_ptr(raw, 3).is_null());
+        }
+
+        let raw = DynSlice::<debug::Dyn>::empty().into_raw();
+        // SAFETY:
+        // `raw` is the raw parts of an empty dyn slice.
+        unsafe {
+            assert_eq!(dyn_slice_len(raw), 0);
+            assert_eq!(dyn_slice_element_size(raw), 0);
+            assert!(dyn_slice_index_ptr(raw, 0).is_null());
+        }
+    }
+
+    #[test]
+    fn accessor() {
+        let array = [10_u64, 20, 30];
+        let slice = partial_eq::new::<u64, _>(&array);
+        let accessor = slice.slice(1..).unwrap().accessor();
+
+        // SAFETY:
+        // `slice` is still borrowed.
+        unsafe {
+            assert_eq!(accessor.len(), 2);
+            assert!(!accessor.is_empty());
+            assert_eq!(accessor.element_size(), 8);
+            assert_eq!(*accessor.index_ptr(1).cast::<u64>(), 30);
+            assert!(accessor.index_ptr(2).is_null());
+        }
+
+        let accessor = DynSlice::<debug::Dyn>::empty().accessor();
+        // SAFETY:
+        // The slice is empty, so it does not borrow anything.
+        unsafe {
+            assert!(accessor.is_empty());
+            assert_eq!(accessor.element_size(), 0);
+            assert!(accessor.index_ptr(0).is_null());
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{
+    any::{Any, TypeId},
+    fmt::{self, Debug},
+    marker::Unsize,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::metadata_of, DynSlice, DynSliceMut};
+
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(doc, doc(cfg(feature = "cast")))]
+/// A trait for getting the [`TypeId`] of the underlying type of a trait object.
+///
+/// This is implemented for every `'static` type. Add it as a supertrait of a trait to make dyn
+/// slices of that trait castable with a [`CastRegistry`].
+///
+/// [`Any::type_id`] cannot be used for this in generic code, as `dyn Trait` itself implements
+/// [`Any`], so calling it on a generic `Dyn` returns the type id of the trait object type.
+pub trait CastFrom {
+    /// Returns the [`TypeId`] of the underlying type.
+    fn underlying_type_id(&self) -> TypeId;
+}
+
+impl<T: Any> CastFrom for T {
+    #[inline]
+    fn underlying_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+}
+
+#[derive(Default)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(doc, doc(cfg(feature = "cast")))]
+/// A registry of the traits that types can be cast to, for converting dyn slices between traits
+/// at runtime.
+///
+/// Each registration records the metadata for a type `T` as a trait object `NewDyn`. A dyn slice
+/// of any trait that has [`CastFrom`] as a supertrait can then be cast to `NewDyn`, if its
+/// underlying type has been registered.
+///
+/// # Example
+/// ```
+/// #![feature(ptr_metadata)]
+/// use std::fmt::{Debug, Display};
+///
+/// use dyn_slice::{declare_new_fns, CastFrom, CastRegistry};
+///
+/// pub trait Shape: CastFrom {
+///     fn area(&self) -> f64;
+/// }
+///
+/// #[derive(Debug)]
+/// struct Square(f64);
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// struct Circle(f64);
+/// impl Shape for Circle {
+///     fn area(&self) -> f64 {
+///         std::f64::consts::PI * self.0 * self.0
+///     }
+/// }
+///
+/// declare_new_fns!(shape Shape);
+///
+/// # fn main() {
+/// let mut registry = CastRegistry::new();
+/// registry.register::<Square, dyn Debug>();
+///
+/// let squares = [Square(1.0), Square(2.0)];
+/// let slice = shape::new(&squares);
+/// let debug = slice.try_cast::<dyn Debug>(&registry).unwrap();
+/// # assert_eq!(format!("{debug:?}"), "[Square(1.0), Square(2.0)]");
+/// println!("{debug:?}"); // [Square(1.0), Square(2.0)]
+///
+/// // `Circle` is not registered as `dyn Debug`
+/// let circles = [Circle(1.0)];
+/// assert!(shape::new(&circles).try_cast::<dyn Debug>(&registry).is_none());
+/// // `Square` is not registered as `dyn Display`
+/// assert!(slice.try_cast::<dyn Display>(&registry).is_none());
+/// # }
+/// ```
+pub struct CastRegistry {
+    /// The metadata for each registered type and trait object, keyed by the type id of the type,
+    /// then the type id of the trait object
+    casts: BTreeMap<(TypeId, TypeId), Box<dyn Any + Send + Sync>>,
+}
+
+impl CastRegistry {
+    #[inline]
+    #[must_use]
+    /// Create an empty registry.
+    pub const fn new() -> Self {
+        Self {
+            casts: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `T` as castable to `NewDyn`.
+    ///
+    /// Registering the same pair again has no effect.
+    pub fn register<T, NewDyn>(&mut self) -> &mut Self
+    where
+        T: Unsize<NewDyn> + 'static,
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>> + 'static,
+    {
+        let metadata = metadata_of::<T, NewDyn>();
+        self.casts.insert(
+            (TypeId::of::<T>(), TypeId::of::<NewDyn>()),
+            Box::new(metadata),
+        );
+        self
+    }
+
+    #[must_use]
+    /// Returns `true` if `T` has been registered as castable to `NewDyn`.
+    pub fn is_registered<T: 'static, NewDyn: ?Sized + 'static>(&self) -> bool {
+        self.casts
+            .contains_key(&(TypeId::of::<T>(), TypeId::of::<NewDyn>()))
+    }
+
+    #[must_use]
+    /// Returns the number of registered casts.
+    pub fn len(&self) -> usize {
+        self.casts.len()
+    }
+
+    #[must_use]
+    /// Returns `true` if no casts have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.casts.is_empty()
+    }
+
+    /// Returns the metadata for the underlying type of `element` as `NewDyn`, or `None` if the
+    /// underlying type is not registered as castable to `NewDyn`.
+    fn metadata_for<Dyn, NewDyn>(&self, element: &Dyn) -> Option<DynMetadata<NewDyn>>
+    where
+        Dyn: ?Sized + CastFrom,
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>> + 'static,
+    {
+        // `Dyn` has `CastFrom` as a supertrait, so this is called through the vtable, and returns
+        // the type id of the underlying type
+        let key = (element.underlying_type_id(), TypeId::of::<NewDyn>());
+        self.casts
+            .get(&key)?
+            .downcast_ref::<DynMetadata<NewDyn>>()
+            .copied()
+    }
+}
+
+impl Debug for CastRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CastRegistry")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + CastFrom> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "cast")))]
+    /// Returns the slice as a dyn slice of another trait, `NewDyn`, or `None` if the underlying
+    /// type has not been registered as castable to `NewDyn` in `registry`.
+    ///
+    /// An empty slice can always be cast, and the result has no metadata.
+    ///
+    /// See [`CastRegistry`].
+    pub fn try_cast<NewDyn>(&self, registry: &CastRegistry) -> Option<DynSlice<'a, NewDyn>>
+    where
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>> + 'static,
+    {
+        let Some(first) = self.first() else {
+            return Some(DynSlice::empty());
+        };
+        let metadata = registry.metadata_for::<Dyn, NewDyn>(first)?;
+
+        // SAFETY:
+        // The metadata was registered for the underlying type as `NewDyn`, so the new slice is
+        // valid for the same data.
+        Some(unsafe { DynSlice::from_parts_with_metadata(metadata, self.len(), self.as_ptr()) })
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + CastFrom> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "cast")))]
+    /// Returns the slice as a mutable dyn slice of another trait, `NewDyn`, or `None` if the
+    /// underlying type has not been registered as castable to `NewDyn` in `registry`.
+    ///
+    /// See [`DynSlice::try_cast`].
+    pub fn try_cast_mut<NewDyn>(
+        &mut self,
+        registry: &CastRegistry,
+    ) -> Option<DynSliceMut<'_, NewDyn>>
+    where
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>> + 'static,
+    {
+        let Some(first) = self.first() else {
+            return Some(DynSliceMut::empty());
+        };
+        let metadata = registry.metadata_for::<Dyn, NewDyn>(first)?;
+
+        // SAFETY:
+        // The metadata was registered for the underlying type as `NewDyn`, so the new slice is
+        // valid for the same data.
+        // The new slice borrows `self` mutably, so it is the only way to access the data.
+        Some(unsafe {
+            DynSliceMut::from_parts_with_metadata(metadata, self.len(), self.as_mut_ptr())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{fmt::Debug, ops::AddAssign};
+
+    use super::{CastFrom, CastRegistry};
+    use crate::declare_new_fns;
+
+    pub trait Value: CastFrom {
+        fn value(&self) -> u64;
+    }
+
+    impl Value for u8 {
+        fn value(&self) -> u64 {
+            u64::from(*self)
+        }
+    }
+
+    impl Value for u32 {
+        fn value(&self) -> u64 {
+            u64::from(*self)
+        }
+    }
+
+    declare_new_fns!(
+        #[crate = crate]
+        value Value
+    );
+
+    #[test]
+    fn try_cast() {
+        let mut registry = CastRegistry::new();
+        assert!(registry.is_empty());
+        registry
+            .register::<u8, dyn Debug>()
+            .register::<u8, dyn AddAssign<u8>>()
+            .register::<u8, dyn Debug>();
+        assert_eq!(registry.len(), 2);
+        assert!(registry.is_registered::<u8, dyn Debug>());
+        assert!(!registry.is_registered::<u32, dyn Debug>());
+
+        let array = [1_u8, 2, 3];
+        let slice = value::new(&array);
+        let debug = slice.try_cast::<dyn Debug>(&registry).unwrap();
+        assert_eq!(format!("{debug:?}"), "[1, 2, 3]");
+
+        let array = [1_u32, 2];
+        assert!(value::new(&array)
+            .try_cast::<dyn Debug>(&registry)
+            .is_none());
+
+        let empty = value::new::<u32>(&[])
+            .try_cast::<dyn Debug>(&registry)
+            .unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.metadata().is_none());
+    }
+
+    #[test]
+    fn try_cast_mut() {
+        let mut registry = CastRegistry::new();
+        registry.register::<u8, dyn AddAssign<u8>>();
+
+        let mut array = [1_u8, 2, 3];
+        let mut slice = value::new_mut(&mut array);
+        assert!(slice.try_cast_mut::<dyn Debug>(&registry).is_none());
+
+        let mut add_assign = slice.try_cast_mut::<dyn AddAssign<u8>>(&registry).unwrap();
+        for element in &mut add_assign {
+            *element += 10;
+        }
+        assert_eq!(array, [11, 12, 13]);
+    }
+}
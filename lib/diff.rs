@@ -0,0 +1,346 @@
+use alloc::{vec, vec::Vec};
+use core::ptr::{DynMetadata, Pointee};
+
+use crate::DynSlice;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A run of elements in a diff between an old and a new slice.
+///
+/// A list of these is returned by [`DynSlice::diff`] and [`DynSlice::diff_by`]. The runs are in
+/// order, so applying them from the start turns the old slice into the new slice.
+#[allow(clippy::module_name_repetitions)]
+pub enum DiffOp {
+    /// `len` elements from `old_index` in the old slice are equal to `len` elements from
+    /// `new_index` in the new slice.
+    Equal {
+        old_index: usize,
+        new_index: usize,
+        len: usize,
+    },
+    /// `len` elements from `old_index` in the old slice are not in the new slice.
+    Delete { old_index: usize, len: usize },
+    /// `len` elements from `new_index` in the new slice are not in the old slice.
+    Insert { new_index: usize, len: usize },
+}
+
+impl DiffOp {
+    /// Extends this run with `next` if they are the same kind, and `next` follows on from it.
+    fn merge(&mut self, next: Self) -> bool {
+        match (self, next) {
+            (
+                Self::Equal {
+                    old_index,
+                    new_index,
+                    len,
+                },
+                Self::Equal {
+                    old_index: next_old,
+                    new_index: next_new,
+                    len: next_len,
+                },
+            ) if *old_index + *len == next_old && *new_index + *len == next_new => {
+                *len += next_len;
+                true
+            }
+            (
+                Self::Delete { old_index, len },
+                Self::Delete {
+                    old_index: next_old,
+                    len: next_len,
+                },
+            ) if *old_index + *len == next_old => {
+                *len += next_len;
+                true
+            }
+            (
+                Self::Insert { new_index, len },
+                Self::Insert {
+                    new_index: next_new,
+                    len: next_len,
+                },
+            ) if *new_index + *len == next_new => {
+                *len += next_len;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Computes a shortest edit script between an old slice of length `old_len` and a new slice of
+/// length `new_len`, using Myers' algorithm.
+///
+/// `eq` is only called with `old < old_len` and `new < new_len`.
+fn myers<F: FnMut(usize, usize) -> bool>(old_len: usize, new_len: usize, mut eq: F) -> Vec<DiffOp> {
+    let max = old_len + new_len;
+    // Diagonal `k` (`x - y`) is stored at `k + offset`, leaving room for `k - 1` and `k + 1`
+    let offset = max + 1;
+    let mut furthest = vec![0; 2 * max + 3];
+    // The furthest reaching x on each diagonal, before each number of edits
+    let mut trace = Vec::new();
+
+    'search: for edits in 0..=max {
+        trace.push(furthest.clone());
+
+        for k in (offset - edits..=offset + edits).step_by(2) {
+            let mut x = if k == offset - edits
+                || (k != offset + edits && furthest[k - 1] < furthest[k + 1])
+            {
+                // Move down from diagonal k + 1
+                furthest[k + 1]
+            } else {
+                // Move right from diagonal k - 1
+                furthest[k - 1] + 1
+            };
+            let mut y = x + offset - k;
+
+            while x < old_len && y < new_len && eq(x, y) {
+                x += 1;
+                y += 1;
+            }
+            furthest[k] = x;
+
+            if x >= old_len && y >= new_len {
+                break 'search;
+            }
+        }
+    }
+
+    // Walk back from the end, recording the edits in reverse
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (old_len, new_len);
+    for (edits, furthest) in trace.iter().enumerate().rev() {
+        let (prev_x, prev_y) = if edits == 0 {
+            // The path starts with a snake from the origin
+            (0, 0)
+        } else {
+            let k = x + offset - y;
+            let prev_k = if k == offset - edits
+                || (k != offset + edits && furthest[k - 1] < furthest[k + 1])
+            {
+                k + 1
+            } else {
+                k - 1
+            };
+            let prev_x = furthest[prev_k];
+            (prev_x, prev_x + offset - prev_k)
+        };
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(DiffOp::Equal {
+                old_index: x,
+                new_index: y,
+                len: 1,
+            });
+        }
+
+        if edits > 0 {
+            if x == prev_x {
+                ops.push(DiffOp::Insert {
+                    new_index: prev_y,
+                    len: 1,
+                });
+            } else {
+                ops.push(DiffOp::Delete {
+                    old_index: prev_x,
+                    len: 1,
+                });
+            }
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    let mut runs: Vec<DiffOp> = Vec::new();
+    for op in ops.into_iter().rev() {
+        if !runs.last_mut().is_some_and(|last| last.merge(op)) {
+            runs.push(op);
+        }
+    }
+    runs
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Returns the differences between the slice and `new`, using `eq` to compare elements.
+    ///
+    /// The result is a shortest edit script, as runs of equal, deleted and inserted elements,
+    /// computed with Myers' algorithm. This takes `O((n + m) * d)` time, where `d` is the number of
+    /// differences.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{diff::DiffOp, standard::debug};
+    ///
+    /// let old = debug::new(&["a", "b", "c"]);
+    /// let new = debug::new(&["a", "c", "d"]);
+    ///
+    /// let ops = old.diff_by(&new, |a, b| format!("{a:?}") == format!("{b:?}"));
+    /// assert_eq!(
+    ///     ops,
+    ///     [
+    ///         DiffOp::Equal { old_index: 0, new_index: 0, len: 1 },
+    ///         DiffOp::Delete { old_index: 1, len: 1 },
+    ///         DiffOp::Equal { old_index: 2, new_index: 1, len: 1 },
+    ///         DiffOp::Insert { new_index: 2, len: 1 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff_by<Dyn2, F>(&self, new: &DynSlice<Dyn2>, mut eq: F) -> Vec<DiffOp>
+    where
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>>,
+        F: FnMut(&Dyn, &Dyn2) -> bool,
+    {
+        myers(self.len, new.len, |x, y| {
+            // SAFETY:
+            // `myers` only compares indices that are in bounds.
+            unsafe { eq(self.get_unchecked(x), new.get_unchecked(y)) }
+        })
+    }
+
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Returns the differences between the slice and `new`.
+    ///
+    /// See [`DynSlice::diff_by`] for details.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{diff::DiffOp, standard::partial_eq};
+    ///
+    /// let old = partial_eq::new(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(
+    ///     old.diff(&[0, 1, 2, 4]),
+    ///     [
+    ///         DiffOp::Insert { new_index: 0, len: 1 },
+    ///         DiffOp::Equal { old_index: 0, new_index: 1, len: 2 },
+    ///         DiffOp::Delete { old_index: 2, len: 1 },
+    ///         DiffOp::Equal { old_index: 3, new_index: 3, len: 1 },
+    ///     ]
+    /// );
+    /// ```
+    pub fn diff<Rhs>(&self, new: &[Rhs]) -> Vec<DiffOp>
+    where
+        Dyn: PartialEq<Rhs>,
+    {
+        myers(self.len, new.len(), |x, y| {
+            // SAFETY:
+            // `myers` only compares indices that are in bounds.
+            unsafe { self.get_unchecked(x) == new.get_unchecked(y) }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::{myers, DiffOp};
+    use crate::standard::partial_eq;
+
+    /// Returns the length of the longest common subsequence of `a` and `b`
+    fn lcs(a: &[u8], b: &[u8]) -> usize {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                table[i + 1][j + 1] = if x == y {
+                    table[i][j] + 1
+                } else {
+                    table[i][j + 1].max(table[i + 1][j])
+                };
+            }
+        }
+        table[a.len()][b.len()]
+    }
+
+    /// Checks that `ops` turns `old` into `new`, and returns the number of edits
+    fn apply(old: &[u8], new: &[u8], ops: &[DiffOp]) -> usize {
+        let (mut old_pos, mut new_pos, mut edits) = (0, 0, 0);
+        for &op in ops {
+            match op {
+                DiffOp::Equal {
+                    old_index,
+                    new_index,
+                    len,
+                } => {
+                    assert_eq!((old_index, new_index), (old_pos, new_pos));
+                    assert_eq!(old[old_pos..old_pos + len], new[new_pos..new_pos + len]);
+                    old_pos += len;
+                    new_pos += len;
+                }
+                DiffOp::Delete { old_index, len } => {
+                    assert_eq!(old_index, old_pos);
+                    old_pos += len;
+                    edits += len;
+                }
+                DiffOp::Insert { new_index, len } => {
+                    assert_eq!(new_index, new_pos);
+                    new_pos += len;
+                    edits += len;
+                }
+            }
+            assert_ne!(op_len(op), 0);
+        }
+        assert_eq!((old_pos, new_pos), (old.len(), new.len()));
+        edits
+    }
+
+    const fn op_len(op: DiffOp) -> usize {
+        match op {
+            DiffOp::Equal { len, .. } | DiffOp::Delete { len, .. } | DiffOp::Insert { len, .. } => {
+                len
+            }
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        let empty: [u8; 0] = [];
+        assert!(partial_eq::new(&empty).diff(&empty).is_empty());
+        assert_eq!(
+            partial_eq::new(&empty).diff(&[1, 2]),
+            [DiffOp::Insert {
+                new_index: 0,
+                len: 2
+            }]
+        );
+        assert_eq!(
+            partial_eq::new(&[1_u8, 2]).diff(&empty),
+            [DiffOp::Delete {
+                old_index: 0,
+                len: 2
+            }]
+        );
+        assert_eq!(
+            partial_eq::new(&[1_u8, 2]).diff(&[1, 2]),
+            [DiffOp::Equal {
+                old_index: 0,
+                new_index: 0,
+                len: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn shortest() {
+        let mut rng = SmallRng::seed_from_u64(0x00d1_ff00);
+        for _ in 0..500 {
+            let old: Vec<u8> = (0..rng.gen_range(0..12))
+                .map(|_| rng.gen_range(0..4))
+                .collect();
+            let new: Vec<u8> = (0..rng.gen_range(0..12))
+                .map(|_| rng.gen_range(0..4))
+                .collect();
+
+            let ops = myers(old.len(), new.len(), |x, y| old[x] == new[y]);
+            let edits = apply(&old, &new, &ops);
+            assert_eq!(edits, old.len() + new.len() - 2 * lcs(&old, &new));
+            assert_eq!(ops, partial_eq::new(&old).diff(&new));
+        }
+    }
+}
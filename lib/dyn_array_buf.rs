@@ -0,0 +1,1042 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    marker::{PhantomData, Unsize},
+    mem::{align_of, size_of, transmute, MaybeUninit},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+    ptr::{self, DynMetadata, Pointee},
+    slice,
+};
+
+#[cfg(feature = "stats")]
+use crate::Stats;
+use crate::{utils::metadata_of, DynSlice, DynSliceMut, DynSliceUninit, IterMut};
+
+/// `N` bytes of storage, aligned to [`DynArrayBuf::MAX_ALIGN`].
+#[repr(C, align(16))]
+struct AlignedBytes<const N: usize>([MaybeUninit<u8>; N]);
+
+/// A fixed capacity, stack allocated buffer of elements of one concrete type, that implement a
+/// trait, like an `ArrayVec<dyn Trait>`.
+///
+/// The buffer has `N_BYTES` bytes of storage, so the number of elements that fit depends on the
+/// size of the underlying type, which is set by the first element pushed. Elements are dropped
+/// when the buffer is dropped.
+///
+/// This does not need the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::debug, DynArrayBuf};
+///
+/// let mut buf = DynArrayBuf::<debug::Dyn, 8>::new();
+/// assert!(buf.try_push(1_u16).is_ok());
+/// assert!(buf.try_push(2_u16).is_ok());
+/// // Only elements of the first type can be pushed
+/// assert_eq!(buf.try_push(3_u8), Err(3));
+///
+/// let slice = buf.as_dyn_slice();
+/// # assert_eq!(format!("{slice:?}"), "[1, 2]");
+/// println!("{slice:?}"); // [1, 2]
+/// ```
+pub struct DynArrayBuf<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N_BYTES: usize> {
+    storage: AlignedBytes<N_BYTES>,
+    /// The vtable of the underlying type, or null if no elements have been pushed since the
+    /// buffer was created or cleared
+    vtable_ptr: *const (),
+    len: usize,
+    #[cfg(feature = "stats")]
+    stats: Stats,
+    phantom: PhantomData<Dyn>,
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N_BYTES: usize>
+    DynArrayBuf<Dyn, N_BYTES>
+{
+    /// The maximum alignment of the underlying type.
+    pub const MAX_ALIGN: usize = align_of::<AlignedBytes<0>>();
+
+    #[inline]
+    #[must_use]
+    /// Create a new, empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            storage: AlignedBytes([MaybeUninit::uninit(); N_BYTES]),
+            vtable_ptr: ptr::null(),
+            len: 0,
+            #[cfg(feature = "stats")]
+            stats: Stats::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in the buffer.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the buffer has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the vtable pointer for `T` if elements of type `T` can be stored in the buffer, or
+    /// `None` if:
+    /// - the buffer is not empty and `T` is not the underlying type of its elements, or
+    /// - the alignment of `T` is greater than [`DynArrayBuf::MAX_ALIGN`].
+    fn vtable_ptr_for<T: Unsize<Dyn>>(&self) -> Option<*const ()> {
+        let metadata = metadata_of::<T, Dyn>();
+        // SAFETY:
+        // DynMetadata only contains a single pointer, and has the same layout as *const ().
+        let vtable_ptr = unsafe { transmute::<DynMetadata<Dyn>, *const ()>(metadata) };
+
+        if self.len != 0 && self.vtable_ptr != vtable_ptr {
+            return None;
+        }
+        if align_of::<T>() > Self::MAX_ALIGN {
+            return None;
+        }
+
+        Some(vtable_ptr)
+    }
+
+    /// Returns the vtable pointer for `T` if another element of type `T` can be added to the
+    /// buffer, or `None` if [`DynArrayBuf::vtable_ptr_for`] returns `None`, or if there is not
+    /// enough space left for another `T`.
+    fn vtable_ptr_for_new<T: Unsize<Dyn>>(&self) -> Option<*const ()> {
+        let vtable_ptr = self.vtable_ptr_for::<T>()?;
+        if (self.len + 1) * size_of::<T>() > N_BYTES {
+            return None;
+        }
+
+        Some(vtable_ptr)
+    }
+
+    /// Appends `value` to the end of the buffer.
+    ///
+    /// # Errors
+    /// Returns `value` back if:
+    /// - the buffer is not empty and `T` is not the underlying type of its elements,
+    /// - the alignment of `T` is greater than [`DynArrayBuf::MAX_ALIGN`], or
+    /// - there is not enough space left for `value`.
+    pub fn try_push<T: Unsize<Dyn>>(&mut self, value: T) -> Result<(), T> {
+        let Some(vtable_ptr) = self.vtable_ptr_for_new::<T>() else {
+            return Err(value);
+        };
+        let offset = self.len * size_of::<T>();
+
+        // SAFETY:
+        // The storage is aligned to `MAX_ALIGN`, and the offset is a multiple of the size of `T`,
+        // so the pointer is aligned for `T`. The above check ensures that the element fits in the
+        // storage.
+        unsafe {
+            self.storage
+                .0
+                .as_mut_ptr()
+                .add(offset)
+                .cast::<T>()
+                .write(value);
+        }
+        self.vtable_ptr = vtable_ptr;
+        self.len += 1;
+        #[cfg(feature = "stats")]
+        self.stats.record_allocations(1, self.len, size_of::<T>());
+
+        Ok(())
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it to the right.
+    ///
+    /// # Errors
+    /// Returns `value` back if `index > self.len()`, or in the same cases as
+    /// [`DynArrayBuf::try_push`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::partial_eq, DynArrayBuf};
+    ///
+    /// let mut buf = DynArrayBuf::<partial_eq::Dyn<u8>, 4>::new();
+    /// buf.try_push(1_u8).unwrap();
+    /// buf.try_push(3_u8).unwrap();
+    ///
+    /// assert_eq!(buf.try_insert(1, 2_u8), Ok(()));
+    /// assert_eq!(buf.try_insert(0, 0_u8), Ok(()));
+    /// assert_eq!(buf.try_insert(0, 9_u8), Err(9));
+    /// assert!(buf.as_dyn_slice() == [0, 1, 2, 3]);
+    /// ```
+    pub fn try_insert<T: Unsize<Dyn>>(&mut self, index: usize, value: T) -> Result<(), T> {
+        if index > self.len {
+            return Err(value);
+        }
+        let Some(vtable_ptr) = self.vtable_ptr_for_new::<T>() else {
+            return Err(value);
+        };
+
+        // SAFETY:
+        // `index <= self.len`, and the vtable pointer is from `vtable_ptr_for_new`.
+        unsafe { self.insert_unchecked(index, value, vtable_ptr) };
+        Ok(())
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it to the right.
+    ///
+    /// # Safety
+    /// The caller must ensure that `index <= self.len()`, and that `vtable_ptr` was returned by
+    /// [`DynArrayBuf::vtable_ptr_for_new`] for `T`, without the buffer changing since.
+    unsafe fn insert_unchecked<T: Unsize<Dyn>>(
+        &mut self,
+        index: usize,
+        value: T,
+        vtable_ptr: *const (),
+    ) {
+        debug_assert!(
+            index <= self.len,
+            "[dyn-slice] index is greater than length!"
+        );
+
+        // The storage is aligned to `MAX_ALIGN`, and offsets are multiples of the size of `T`, so
+        // the pointers are aligned for `T`. There is space for one more element, so the elements
+        // from `index` can be shifted right by one, which leaves space for `value` at `index`.
+        let element = self.storage.0.as_mut_ptr().cast::<T>().add(index);
+        ptr::copy(element, element.add(1), self.len - index);
+        element.write(value);
+
+        self.vtable_ptr = vtable_ptr;
+        self.len += 1;
+        #[cfg(feature = "stats")]
+        self.stats.record_allocations(1, self.len, size_of::<T>());
+    }
+
+    /// Inserts `value` into a buffer sorted by `compare`, keeping it sorted, and returns the index
+    /// it was inserted at.
+    ///
+    /// `compare` is called with an element of the buffer, then `value`. `value` is inserted after
+    /// any elements that compare equal to it, so insertion is stable.
+    ///
+    /// # Errors
+    /// Returns `value` back in the same cases as [`DynArrayBuf::try_push`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::to, DynArrayBuf};
+    ///
+    /// let mut buf = DynArrayBuf::<to::Dyn<u32>, 16>::new();
+    /// for x in [5_u8, 1, 4, 1] {
+    ///     buf.insert_sorted_by(x, |a, b| a.to().cmp(&b.to())).unwrap();
+    /// }
+    ///
+    /// let values: Vec<u32> = buf.as_dyn_slice().iter().map(|x| x.to()).collect();
+    /// assert_eq!(values, [1, 1, 4, 5]);
+    /// assert_eq!(buf.binary_search_by(|x| x.to().cmp(&4)), Ok(2));
+    /// ```
+    pub fn insert_sorted_by<T, F>(&mut self, value: T, mut compare: F) -> Result<usize, T>
+    where
+        T: Unsize<Dyn>,
+        F: FnMut(&Dyn, &Dyn) -> Ordering,
+    {
+        let Some(vtable_ptr) = self.vtable_ptr_for_new::<T>() else {
+            return Err(value);
+        };
+
+        // Never report equality, so that the search finds the index after any equal elements
+        let (Ok(index) | Err(index)) = self
+            .as_dyn_slice()
+            .binary_search_by(|element| compare(element, &value).then(Ordering::Less));
+
+        // SAFETY:
+        // The search returns an index of at most `self.len()`, and the vtable pointer is from
+        // `vtable_ptr_for_new`.
+        unsafe { self.insert_unchecked(index, value, vtable_ptr) };
+        Ok(index)
+    }
+
+    /// Binary searches the buffer with a comparator function.
+    ///
+    /// See [`DynSlice::binary_search_by`].
+    ///
+    /// # Errors
+    /// Returns `Err` with the insertion index if no element matches.
+    pub fn binary_search_by<F: FnMut(&Dyn) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_dyn_slice().binary_search_by(f)
+    }
+
+    /// Returns the remaining storage of the buffer as uninitialised elements of type `T`, so that
+    /// they can be written in place.
+    ///
+    /// If the buffer is empty, this sets the underlying type to `T`. The elements only become part
+    /// of the buffer once [`DynArrayBuf::set_len`] is called.
+    ///
+    /// Returns `None` if the buffer is not empty and `T` is not the underlying type of its
+    /// elements, or if the alignment of `T` is greater than [`DynArrayBuf::MAX_ALIGN`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::partial_eq, DynArrayBuf};
+    ///
+    /// let mut buf = DynArrayBuf::<partial_eq::Dyn<u16>, 8>::new();
+    /// buf.try_push(1_u16).unwrap();
+    ///
+    /// let mut spare = buf.spare_capacity_mut::<u16>().unwrap();
+    /// assert_eq!(spare.len(), 3);
+    /// // SAFETY: the underlying type is `u16`
+    /// unsafe {
+    ///     spare.init_with(0, 2_u16);
+    ///     spare.init_with(1, 3_u16);
+    /// }
+    ///
+    /// // SAFETY: the first two spare elements have been initialised
+    /// unsafe { buf.set_len(3) };
+    /// assert!(buf.as_dyn_slice() == [1, 2, 3]);
+    /// ```
+    pub fn spare_capacity_mut<'a, T: Unsize<Dyn> + 'a>(
+        &'a mut self,
+    ) -> Option<DynSliceUninit<'a, Dyn>> {
+        self.vtable_ptr = self.vtable_ptr_for::<T>()?;
+
+        let spare_len = if size_of::<T>() == 0 {
+            usize::MAX - self.len
+        } else {
+            N_BYTES / size_of::<T>() - self.len
+        };
+        // SAFETY:
+        // The storage is aligned to `MAX_ALIGN`, and the offset is a multiple of the size of `T`,
+        // so the pointer is aligned for `T`. `spare_len` elements after the first `len` fit in
+        // the storage, and they are uninitialised. `MaybeUninit<T>` has the same layout as `T`.
+        let spare = unsafe {
+            slice::from_raw_parts_mut(
+                self.storage
+                    .0
+                    .as_mut_ptr()
+                    .add(self.len * size_of::<T>())
+                    .cast::<MaybeUninit<T>>(),
+                spare_len,
+            )
+        };
+
+        Some(DynSliceUninit::new(spare))
+    }
+
+    /// Sets the length of the buffer, without dropping or initialising any elements.
+    ///
+    /// This is used to add elements written with [`DynArrayBuf::spare_capacity_mut`].
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - if `new_len > 0`, the underlying type has been set, by pushing an element or calling
+    ///   [`DynArrayBuf::spare_capacity_mut`],
+    /// - `new_len` elements of the underlying type fit in the buffer, and
+    /// - the elements at `self.len()..new_len` have been initialised.
+    ///
+    /// If `new_len < self.len()`, the elements after `new_len` are leaked.
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(
+            new_len == 0 || !self.vtable_ptr.is_null(),
+            "[dyn-slice] underlying type is not set!"
+        );
+
+        #[cfg(feature = "stats")]
+        if new_len > self.len {
+            let size = transmute::<*const (), DynMetadata<Dyn>>(self.vtable_ptr).size_of();
+            self.stats
+                .record_allocations(new_len - self.len, new_len, size);
+        }
+
+        self.len = new_len;
+    }
+
+    /// Shortens the buffer to `len` elements, dropping the rest.
+    ///
+    /// If `len >= self.len()`, this does nothing.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail_len = self.len - len;
+        // SAFETY:
+        // `len < self.len`, so the index is in bounds.
+        let tail_ptr = unsafe { self.as_dyn_slice_mut().get_ptr_unchecked(len) }.cast_mut();
+        // Set the length first, so that the elements are leaked rather than dropped twice if
+        // dropping one panics
+        self.len = len;
+
+        // SAFETY:
+        // The elements are initialised, and are no longer part of the buffer, so are not
+        // accessed again.
+        unsafe {
+            let tail = DynSliceMut::<Dyn>::from_parts(self.vtable_ptr, tail_len, tail_ptr);
+            for element in tail {
+                ptr::drop_in_place(element);
+            }
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, dropping the rest, and keeping the
+    /// order of the kept elements.
+    ///
+    /// If `f` or a destructor panics, the elements that have been kept, and those that `f` has not
+    /// been called on yet, stay in the buffer. The element whose destructor panicked is leaked.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::partial_eq, DynArrayBuf};
+    ///
+    /// let mut buf = DynArrayBuf::<partial_eq::Dyn<u8>, 8>::new();
+    /// for x in [5_u8, 1, 7, 3] {
+    ///     buf.try_push(x).unwrap();
+    /// }
+    ///
+    /// buf.retain(|x| *x != 1);
+    /// assert!(buf.as_dyn_slice() == [5, 7, 3]);
+    /// ```
+    pub fn retain<F: FnMut(&Dyn) -> bool>(&mut self, mut f: F) {
+        /// Shifts the elements that have not been processed to after the kept elements, and sets
+        /// the length of the buffer to include both, when dropped, so that the buffer is valid even
+        /// if processing an element panics.
+        struct Guard<'b> {
+            len: &'b mut usize,
+            data: *mut MaybeUninit<u8>,
+            size: usize,
+            original_len: usize,
+            processed: usize,
+            kept: usize,
+        }
+
+        impl<'b> Drop for Guard<'b> {
+            fn drop(&mut self) {
+                let remaining = self.original_len - self.processed;
+                if remaining != 0 && self.kept != self.processed {
+                    // SAFETY:
+                    // The elements from `processed` are initialised, and `kept <= processed`, so
+                    // they are moved left, to after the kept elements, within the storage.
+                    unsafe {
+                        ptr::copy(
+                            self.data.add(self.processed * self.size),
+                            self.data.add(self.kept * self.size),
+                            remaining * self.size,
+                        );
+                    }
+                }
+                *self.len = self.kept + remaining;
+            }
+        }
+
+        let len = self.len;
+        if len == 0 {
+            return;
+        }
+
+        // SAFETY:
+        // The buffer is not empty, so the vtable pointer is valid metadata.
+        let metadata = unsafe { transmute::<*const (), DynMetadata<Dyn>>(self.vtable_ptr) };
+        let mut guard = Guard {
+            data: self.storage.0.as_mut_ptr(),
+            size: metadata.size_of(),
+            original_len: len,
+            processed: 0,
+            kept: 0,
+            len: &mut self.len,
+        };
+
+        while guard.processed < len {
+            // SAFETY:
+            // `processed < len`, so the element is within the storage.
+            let element = unsafe { guard.data.add(guard.processed * guard.size) };
+            let element = ptr::from_raw_parts_mut::<Dyn>(element.cast(), metadata);
+
+            // SAFETY:
+            // The element is initialised, as it has not been moved or dropped yet, and no other
+            // references to it exist.
+            if f(unsafe { &*element }) {
+                if guard.kept != guard.processed {
+                    // SAFETY:
+                    // `kept < processed`, so the elements do not overlap, and the element at
+                    // `kept` has already been moved or dropped, so can be overwritten.
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            element.cast::<MaybeUninit<u8>>(),
+                            guard.data.add(guard.kept * guard.size),
+                            guard.size,
+                        );
+                    }
+                }
+                guard.kept += 1;
+                guard.processed += 1;
+            } else {
+                // Mark the element as processed first, so that it is leaked rather than kept if
+                // its destructor panics
+                guard.processed += 1;
+                // SAFETY:
+                // The element is initialised, and is not accessed again.
+                unsafe { ptr::drop_in_place(element) };
+            }
+        }
+    }
+
+    /// Removes the elements in `range` from the buffer, returning them as an iterator of owned
+    /// elements.
+    ///
+    /// The elements after the range are shifted left straight away. Elements that are not yielded
+    /// by the iterator are dropped when it is dropped, and each yielded element is dropped when
+    /// its [`Drained`] handle is dropped. If the iterator is leaked, the removed elements are
+    /// leaked, but the buffer is still valid.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than its end, or if its end is greater than
+    /// `self.len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::debug, DynArrayBuf};
+    ///
+    /// let mut buf = DynArrayBuf::<debug::Dyn, 96>::new();
+    /// for s in ["a", "b", "c", "d"] {
+    ///     buf.try_push(s.to_owned()).unwrap();
+    /// }
+    ///
+    /// let drained: Vec<String> = buf.drain(1..3).map(|s| format!("{:?}", &*s)).collect();
+    /// assert_eq!(drained, [r#""b""#, r#""c""#]);
+    /// # assert_eq!(format!("{:?}", buf.as_dyn_slice()), r#"["a", "d"]"#);
+    /// println!("{:?}", buf.as_dyn_slice()); // ["a", "d"]
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, Dyn> {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1).expect("drain range is out of bounds"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1).expect("drain range is out of bounds"),
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end && end <= self.len,
+            "drain range is out of bounds"
+        );
+
+        let len = self.len;
+        let drained_len = end - start;
+        if drained_len == 0 {
+            return Drain {
+                iter: DynSliceMut::empty().into_iter(),
+            };
+        }
+
+        // SAFETY:
+        // The range is not empty, so the buffer is not empty, and the vtable pointer is valid
+        // metadata.
+        let size = unsafe { transmute::<*const (), DynMetadata<Dyn>>(self.vtable_ptr) }.size_of();
+        let data = self.storage.0.as_mut_ptr();
+
+        // SAFETY:
+        // The elements from `start` to `len` are initialised and within the storage.
+        // Rotating them moves the drained elements to the end, after the elements that follow
+        // them, which are shifted left to `start`. The elements only change place, so every
+        // element is still aligned and initialised.
+        unsafe {
+            slice::from_raw_parts_mut(data.add(start * size), (len - start) * size)
+                .rotate_left(drained_len * size);
+        }
+        // The drained elements are no longer part of the buffer, so are not dropped by it, even if
+        // the iterator is leaked
+        self.len = len - drained_len;
+
+        // SAFETY:
+        // The drained elements are initialised, and are now the last `drained_len` elements
+        // before the old length. The buffer is borrowed mutably for the lifetime of the
+        // iterator, so they are not accessed or overwritten by it.
+        let drained = unsafe {
+            DynSliceMut::from_parts(
+                self.vtable_ptr,
+                drained_len,
+                data.add(self.len * size).cast(),
+            )
+        };
+
+        Drain {
+            iter: drained.into_iter(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "stats")]
+    #[cfg_attr(doc, doc(cfg(feature = "stats")))]
+    /// Returns the allocation statistics of the buffer.
+    ///
+    /// The statistics are kept when the buffer is cleared, so the high-water marks cover its whole
+    /// life.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::debug, DynArrayBuf};
+    ///
+    /// let mut buf = DynArrayBuf::<debug::Dyn, 16>::new();
+    /// for x in [1_u32, 2, 3] {
+    ///     buf.try_push(x).unwrap();
+    /// }
+    /// buf.truncate(1);
+    ///
+    /// let stats = buf.stats();
+    /// assert_eq!((stats.allocations, stats.len, stats.high_water_len), (3, 1, 3));
+    /// assert_eq!((stats.bytes, stats.high_water_bytes, stats.capacity_bytes), (4, 12, 16));
+    /// ```
+    pub fn stats(&self) -> Stats {
+        self.stats
+            .with_usage(self.len, self.as_dyn_slice().element_size(), N_BYTES)
+    }
+
+    #[inline]
+    /// Drops all of the elements, leaving the buffer empty.
+    ///
+    /// After this, elements of any type can be pushed.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.vtable_ptr = ptr::null();
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements as a dyn slice.
+    pub const fn as_dyn_slice(&self) -> DynSlice<'_, Dyn> {
+        // SAFETY:
+        // The first `len` elements of the storage are initialised elements of the underlying
+        // type, and the vtable pointer is only null if `len == 0`.
+        unsafe { DynSlice::from_parts(self.vtable_ptr, self.len, self.storage.0.as_ptr().cast()) }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements as a mutable dyn slice.
+    pub fn as_dyn_slice_mut(&mut self) -> DynSliceMut<'_, Dyn> {
+        // SAFETY:
+        // The first `len` elements of the storage are initialised elements of the underlying
+        // type, and the vtable pointer is only null if `len == 0`. The mutable borrow ensures
+        // that the slice is the only way to access the elements.
+        unsafe {
+            DynSliceMut::from_parts(
+                self.vtable_ptr,
+                self.len,
+                self.storage.0.as_mut_ptr().cast(),
+            )
+        }
+    }
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N_BYTES: usize> Default
+    for DynArrayBuf<Dyn, N_BYTES>
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N_BYTES: usize> Drop
+    for DynArrayBuf<Dyn, N_BYTES>
+{
+    fn drop(&mut self) {
+        self.truncate(0);
+    }
+}
+
+/// An iterator over the elements removed from a [`DynArrayBuf`], which drops the elements that
+/// are not yielded.
+///
+/// This is created by [`DynArrayBuf::drain`].
+pub struct Drain<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    iter: IterMut<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Drain<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been yielded yet.
+    pub const fn as_slice(&self) -> DynSlice<'_, Dyn> {
+        self.iter.as_slice()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for Drain<'a, Dyn> {
+    type Item = Drained<'a, Dyn>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|element| Drained { element })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIterator
+    for Drain<'a, Dyn>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|element| Drained { element })
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> ExactSizeIterator
+    for Drain<'a, Dyn>
+{
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> FusedIterator for Drain<'a, Dyn> {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Drop for Drain<'a, Dyn> {
+    fn drop(&mut self) {
+        for element in &mut self.iter {
+            // SAFETY:
+            // The element has been removed from the buffer and was not yielded, so this is the
+            // only place that drops it.
+            unsafe { ptr::drop_in_place(element) };
+        }
+    }
+}
+
+/// An element removed from a [`DynArrayBuf`] by [`DynArrayBuf::drain`], which is dropped when the
+/// handle is dropped.
+pub struct Drained<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    element: &'a mut Dyn,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Deref for Drained<'a, Dyn> {
+    type Target = Dyn;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.element
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DerefMut for Drained<'a, Dyn> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.element
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Drop for Drained<'a, Dyn> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // The element has been removed from the buffer, and this handle is its only owner.
+        unsafe { ptr::drop_in_place(self.element) };
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug for Drained<'a, Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drained").field(&&*self.element).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::rc::Rc;
+    use core::fmt::Debug;
+
+    use super::DynArrayBuf;
+    use crate::standard::{add_assign, debug};
+
+    #[test]
+    fn push() {
+        let mut buf = DynArrayBuf::<add_assign::Dyn<u16>, 6>::new();
+        assert!(buf.is_empty());
+        assert!(buf.as_dyn_slice().is_empty());
+
+        assert!(buf.try_push(1_u16).is_ok());
+        assert!(buf.try_push(2_u16).is_ok());
+        assert!(buf.try_push(3_u16).is_ok());
+        assert_eq!(buf.try_push(4_u16), Err(4));
+        assert_eq!(buf.len(), 3);
+
+        buf.as_dyn_slice_mut().iter_mut().for_each(|x| *x += 10);
+        let slice = buf.as_dyn_slice();
+        // SAFETY:
+        // The underlying type is `u16`.
+        let values = unsafe { slice.downcast_unchecked::<u16>() };
+        assert_eq!(values, [11, 12, 13]);
+    }
+
+    #[test]
+    fn types() {
+        #[derive(Debug)]
+        #[repr(align(32))]
+        struct Aligned;
+
+        let mut buf = DynArrayBuf::<debug::Dyn, 16>::new();
+        assert!(buf.try_push(Aligned).is_err());
+        assert!(buf.try_push(1_u8).is_ok());
+        assert!(buf.try_push(2_u16).is_err());
+
+        buf.clear();
+        assert!(buf.try_push(2_u16).is_ok());
+        assert!(buf.try_push(3_u16).is_ok());
+        assert_eq!(format!("{:?}", buf.as_dyn_slice()), "[2, 3]");
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut buf = DynArrayBuf::<debug::Dyn, 0>::default();
+        assert!(buf.try_push(1_u8).is_err());
+        for _ in 0..100 {
+            assert!(buf.try_push(()).is_ok());
+        }
+        assert_eq!(buf.len(), 100);
+    }
+
+    #[test]
+    fn retain() {
+        let a = Rc::new(1);
+        let b = Rc::new(2);
+        let mut buf = DynArrayBuf::<debug::Dyn, 64>::new();
+        for rc in [&a, &b, &a, &b, &b] {
+            assert!(buf.try_push(Rc::clone(rc)).is_ok());
+        }
+
+        let mut seen = 0;
+        buf.retain(|_| {
+            seen += 1;
+            seen % 2 == 1
+        });
+        assert_eq!(buf.len(), 3);
+        assert_eq!(format!("{:?}", buf.as_dyn_slice()), "[1, 1, 2]");
+        assert_eq!((Rc::strong_count(&a), Rc::strong_count(&b)), (3, 2));
+
+        buf.retain(|_| false);
+        assert!(buf.is_empty());
+        assert_eq!((Rc::strong_count(&a), Rc::strong_count(&b)), (1, 1));
+        buf.retain(|_| unreachable!());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn retain_panic() {
+        use core::panic::AssertUnwindSafe;
+        use std::panic::catch_unwind;
+
+        let a = Rc::new(());
+        let mut buf = DynArrayBuf::<debug::Dyn, 128>::new();
+        for i in 0_u8..6 {
+            assert!(buf.try_push((Rc::clone(&a), i)).is_ok());
+        }
+
+        let mut seen = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            buf.retain(|_| {
+                seen += 1;
+                assert!(seen != 4, "predicate panicked");
+                seen % 2 == 1
+            });
+        }));
+        assert!(result.is_err());
+
+        // The first and third are kept, the second is dropped, and the last three were not checked
+        assert_eq!(
+            format!("{:?}", buf.as_dyn_slice()),
+            "[((), 0), ((), 2), ((), 3), ((), 4), ((), 5)]"
+        );
+        assert_eq!(Rc::strong_count(&a), 6);
+        drop(buf);
+        assert_eq!(Rc::strong_count(&a), 1);
+    }
+
+    #[test]
+    fn insert() {
+        let mut buf = DynArrayBuf::<add_assign::Dyn<u16>, 8>::new();
+        assert_eq!(buf.try_insert(0, 2_u16), Ok(()));
+        assert_eq!(buf.try_insert(0, 1_u16), Ok(()));
+        assert_eq!(buf.try_insert(2, 4_u16), Ok(()));
+        assert_eq!(buf.try_insert(2, 3_u16), Ok(()));
+        assert_eq!(buf.try_insert(2, 5_u16), Err(5));
+
+        let slice = buf.as_dyn_slice();
+        // SAFETY:
+        // The underlying type is `u16`.
+        let values = unsafe { slice.downcast_unchecked::<u16>() };
+        assert_eq!(values, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn insert_out_of_bounds() {
+        let mut buf = DynArrayBuf::<debug::Dyn, 8>::new();
+        assert_eq!(buf.try_insert(1, 1_u8), Err(1));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut buf = DynArrayBuf::<debug::Dyn, 128>::new();
+        let key = |x: &dyn Debug| format!("{x:?}").len();
+        for (i, s) in ["ccc", "a", "bb", "d", "eee", ""].into_iter().enumerate() {
+            let index = buf.insert_sorted_by(s, |a, b| key(a).cmp(&key(b)));
+            assert_eq!(index, Ok([0, 0, 1, 1, 4, 0][i]));
+        }
+        assert_eq!(
+            format!("{:?}", buf.as_dyn_slice()),
+            r#"["", "a", "d", "bb", "ccc", "eee"]"#
+        );
+
+        assert_eq!(buf.binary_search_by(|x| key(x).cmp(&4)), Ok(3));
+        assert_eq!(buf.binary_search_by(|x| key(x).cmp(&6)), Err(6));
+        assert!(buf.insert_sorted_by(1_u8, |_, _| unreachable!()).is_err());
+    }
+
+    #[test]
+    fn drain() {
+        let a = Rc::new(());
+        let b = Rc::new(());
+        let mut buf = DynArrayBuf::<debug::Dyn, 64>::new();
+        for rc in [&a, &b, &b, &b, &a] {
+            assert!(buf.try_push(Rc::clone(rc)).is_ok());
+        }
+
+        let mut drain = buf.drain(1..=3);
+        assert_eq!(drain.len(), 3);
+        let first = drain.next().unwrap();
+        assert_eq!(Rc::strong_count(&b), 4);
+        drop(first);
+        assert_eq!(Rc::strong_count(&b), 3);
+        drop(drain);
+        assert_eq!(Rc::strong_count(&b), 1);
+        assert_eq!(buf.len(), 2);
+        assert_eq!(Rc::strong_count(&a), 3);
+
+        assert_eq!(buf.drain(1..1).len(), 0);
+        core::mem::forget(buf.drain(..1));
+        assert_eq!(buf.len(), 1);
+        assert_eq!(Rc::strong_count(&a), 3);
+
+        let drained: Vec<_> = buf.drain(..).collect();
+        assert_eq!(drained.len(), 1);
+        assert_eq!(Rc::strong_count(&a), 3);
+        drop(drained);
+        assert!(buf.is_empty());
+        assert_eq!(Rc::strong_count(&a), 2);
+
+        // SAFETY:
+        // Forgetting the drain leaked one clone of `a`, which is never used again.
+        unsafe { Rc::decrement_strong_count(Rc::as_ptr(&a)) };
+    }
+
+    #[test]
+    fn drain_order() {
+        let mut buf = DynArrayBuf::<debug::Dyn, 16>::new();
+        for x in 0_u16..8 {
+            assert!(buf.try_push(x).is_ok());
+        }
+
+        let mut drain = buf.drain(2..6);
+        assert_eq!(format!("{:?}", drain.as_slice()), "[2, 3, 4, 5]");
+        assert_eq!(format!("{:?}", drain.next_back()), "Some(Drained(5))");
+        assert_eq!(format!("{:?}", drain.next()), "Some(Drained(2))");
+        drop(drain);
+        assert_eq!(format!("{:?}", buf.as_dyn_slice()), "[0, 1, 6, 7]");
+
+        // The space of the drained elements can be reused
+        for x in 8_u16..12 {
+            assert!(buf.try_push(x).is_ok());
+        }
+        assert_eq!(
+            format!("{:?}", buf.as_dyn_slice()),
+            "[0, 1, 6, 7, 8, 9, 10, 11]"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "drain range is out of bounds")]
+    fn drain_out_of_bounds() {
+        let mut buf = DynArrayBuf::<debug::Dyn, 8>::new();
+        assert!(buf.try_push(1_u8).is_ok());
+        let _ = buf.drain(..2);
+    }
+
+    #[test]
+    fn drops_elements() {
+        let a = Rc::new(());
+        let mut buf = DynArrayBuf::<debug::Dyn, 64>::new();
+        for _ in 0..4 {
+            assert!(buf.try_push(Rc::clone(&a)).is_ok());
+        }
+        assert_eq!(Rc::strong_count(&a), 5);
+
+        buf.truncate(5);
+        assert_eq!(Rc::strong_count(&a), 5);
+        buf.truncate(1);
+        assert_eq!(buf.len(), 1);
+        assert_eq!(Rc::strong_count(&a), 2);
+
+        drop(buf);
+        assert_eq!(Rc::strong_count(&a), 1);
+    }
+
+    #[test]
+    fn spare_capacity() {
+        let a = Rc::new(());
+        let mut buf = DynArrayBuf::<debug::Dyn, 64>::new();
+
+        let mut spare = buf.spare_capacity_mut::<Rc<()>>().unwrap();
+        let capacity = spare.len();
+        assert_eq!(capacity, 64 / core::mem::size_of::<Rc<()>>());
+        // SAFETY:
+        // The underlying type is `Rc<()>`.
+        unsafe {
+            spare.init_with(0, Rc::clone(&a));
+            spare.init_with(1, Rc::clone(&a));
+        }
+        // SAFETY:
+        // The first two elements have been initialised.
+        unsafe { buf.set_len(2) };
+        assert_eq!(format!("{:?}", buf.as_dyn_slice()), "[(), ()]");
+
+        assert!(buf.spare_capacity_mut::<u8>().is_none());
+        assert_eq!(
+            buf.spare_capacity_mut::<Rc<()>>().map(|spare| spare.len()),
+            Some(capacity - 2)
+        );
+        #[cfg(feature = "stats")]
+        assert_eq!(buf.stats().allocations, 2);
+
+        drop(buf);
+        assert_eq!(Rc::strong_count(&a), 1);
+
+        let mut buf = DynArrayBuf::<debug::Dyn, 4>::new();
+        assert_eq!(
+            buf.spare_capacity_mut::<()>().map(|spare| spare.len()),
+            Some(usize::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn stats() {
+        let mut buf = DynArrayBuf::<debug::Dyn, 16>::new();
+        assert_eq!(buf.stats().capacity_bytes, 16);
+        assert_eq!(buf.stats().bytes, 0);
+
+        for x in [1_u32, 2, 3] {
+            buf.try_push(x).unwrap();
+        }
+        buf.clear();
+        // Failed pushes are not allocations
+        assert_eq!(buf.try_push(4_u8), Ok(()));
+        assert_eq!(buf.try_push(5_u16), Err(5));
+
+        let stats = buf.stats();
+        assert_eq!(
+            (stats.allocations, stats.len, stats.high_water_len),
+            (4, 1, 3)
+        );
+        assert_eq!((stats.bytes, stats.high_water_bytes), (1, 12));
+    }
+}
@@ -0,0 +1,410 @@
+use core::{
+    fmt::{self, Debug},
+    mem,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime, DynSlice, DynSliceMut};
+
+/// A cursor over a dyn slice, that consumes elements from the front.
+///
+/// The consumed prefix can be returned to with a mark, set with [`DynCursor::mark`], and
+/// [`DynCursor::reset`]. This is useful for parsers, that take elements as they go, then backtrack
+/// if something does not match.
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::partial_eq, DynCursor};
+///
+/// let array = [1_u8, 2, 3, 4, 5];
+/// let mut cursor = DynCursor::new(partial_eq::new(&array));
+///
+/// assert!(cursor.take(2).unwrap() == [1, 2]);
+/// cursor.mark();
+/// assert!(cursor.advance(2));
+/// assert!(*cursor.peek().unwrap() == 5);
+///
+/// cursor.reset();
+/// assert_eq!(cursor.position(), 2);
+/// assert!(cursor.remaining() == [3, 4, 5]);
+/// ```
+pub struct DynCursor<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSlice<'a, Dyn>,
+    /// The index of the next element
+    position: usize,
+    /// The position to return to on [`DynCursor::reset`]
+    mark: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DynCursor<'a, Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for DynCursor<'a, Dyn> {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynCursor<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a cursor at the start of `slice`, with the mark at the start.
+    pub const fn new(slice: DynSlice<'a, Dyn>) -> Self {
+        Self {
+            slice,
+            position: 0,
+            mark: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements that have been consumed.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements that have not been consumed.
+    pub const fn len(&self) -> usize {
+        self.slice.len - self.position
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if every element has been consumed.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been consumed.
+    pub fn remaining(&self) -> DynSlice<'a, Dyn> {
+        // SAFETY:
+        // `position <= slice.len()`, so the sub-slice is in bounds. It is a sub-slice of
+        // `self.slice`, so it can have its lifetime.
+        unsafe { extend_lifetime(self.slice.slice_unchecked(self.position, self.len())) }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a reference to the next element, without consuming it, or `None` if every element
+    /// has been consumed.
+    pub fn peek(&self) -> Option<&Dyn> {
+        self.slice.get(self.position)
+    }
+
+    /// Consumes `n` elements.
+    ///
+    /// Returns `false`, without consuming anything, if fewer than `n` elements are left.
+    pub fn advance(&mut self, n: usize) -> bool {
+        if n > self.len() {
+            return false;
+        }
+
+        self.position += n;
+        true
+    }
+
+    #[must_use]
+    /// Consumes `n` elements, returning them as a dyn slice.
+    ///
+    /// Returns `None`, without consuming anything, if fewer than `n` elements are left.
+    pub fn take(&mut self, n: usize) -> Option<DynSlice<'a, Dyn>> {
+        if n > self.len() {
+            return None;
+        }
+
+        // SAFETY:
+        // `position + n <= slice.len()`, so the sub-slice is in bounds. It is a sub-slice of
+        // `self.slice`, so it can have its lifetime.
+        let taken = unsafe { extend_lifetime(self.slice.slice_unchecked(self.position, n)) };
+        self.position += n;
+        Some(taken)
+    }
+
+    #[inline]
+    /// Sets the mark to the current position.
+    pub fn mark(&mut self) {
+        self.mark = self.position;
+    }
+
+    #[inline]
+    /// Returns to the mark, un-consuming the elements after it.
+    ///
+    /// If no mark has been set, this returns to the start.
+    pub fn reset(&mut self) {
+        self.position = self.mark;
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns a cursor at the start of the slice.
+    ///
+    /// See [`DynCursor`].
+    pub const fn cursor(&self) -> DynCursor<'_, Dyn> {
+        DynCursor::new(*self)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug for DynCursor<'a, Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynCursor")
+            .field("position", &self.position)
+            .field("remaining", &self.remaining())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A cursor over a mutable dyn slice, that consumes elements from the front.
+///
+/// Unlike [`DynCursor`], the consumed elements are handed out as disjoint mutable slices with
+/// [`DynCursorMut::take_mut`], so there is no mark to return to.
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::add_assign, DynCursorMut};
+///
+/// let mut array = [1_u8, 2, 3, 4, 5];
+/// let mut cursor = DynCursorMut::new(add_assign::new_mut(&mut array));
+///
+/// let mut header = cursor.take_mut(2).unwrap();
+/// let mut body = cursor.take_mut(2).unwrap();
+/// header[0] += 10;
+/// body[1] += 20;
+/// assert_eq!(cursor.len(), 1);
+///
+/// assert_eq!(array, [11, 2, 3, 24, 5]);
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct DynCursorMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    /// The elements that have not been consumed
+    remaining: DynSliceMut<'a, Dyn>,
+    /// The number of elements that have been consumed
+    position: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynCursorMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a cursor at the start of `slice`.
+    pub const fn new(slice: DynSliceMut<'a, Dyn>) -> Self {
+        Self {
+            remaining: slice,
+            position: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements that have been consumed.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements that have not been consumed.
+    pub const fn len(&self) -> usize {
+        self.remaining.0.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if every element has been consumed.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been consumed.
+    pub fn remaining(&self) -> DynSlice<'_, Dyn> {
+        *self.remaining
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been consumed, as a mutable dyn slice.
+    pub fn remaining_mut(&mut self) -> DynSliceMut<'_, Dyn> {
+        // SAFETY:
+        // The new slice mutably borrows the cursor, so it is the only way to access the elements
+        // while it exists.
+        unsafe {
+            DynSliceMut::from_parts(
+                self.remaining.vtable_ptr,
+                self.len(),
+                self.remaining.as_mut_ptr(),
+            )
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Consumes the cursor, returning the elements that have not been consumed.
+    pub const fn into_remaining(self) -> DynSliceMut<'a, Dyn> {
+        self.remaining
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a reference to the next element, without consuming it, or `None` if every element
+    /// has been consumed.
+    pub fn peek(&self) -> Option<&Dyn> {
+        self.remaining.first()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a mutable reference to the next element, without consuming it, or `None` if every
+    /// element has been consumed.
+    pub fn peek_mut(&mut self) -> Option<&mut Dyn> {
+        self.remaining.first_mut()
+    }
+
+    /// Consumes `n` elements.
+    ///
+    /// Returns `false`, without consuming anything, if fewer than `n` elements are left.
+    pub fn advance(&mut self, n: usize) -> bool {
+        self.take_mut(n).is_some()
+    }
+
+    /// Consumes `n` elements, returning them as a mutable dyn slice that does not borrow the
+    /// cursor.
+    ///
+    /// Returns `None`, without consuming anything, if fewer than `n` elements are left.
+    pub fn take_mut(&mut self, n: usize) -> Option<DynSliceMut<'a, Dyn>> {
+        if n > self.len() {
+            return None;
+        }
+
+        let remaining = mem::take(&mut self.remaining);
+        // SAFETY:
+        // `n <= remaining.len()` is checked above.
+        let (taken, rest) = unsafe { remaining.into_split_at_unchecked_mut(n) };
+        self.remaining = rest;
+        self.position += n;
+        Some(taken)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns a cursor at the start of the slice.
+    ///
+    /// See [`DynCursorMut`].
+    pub fn cursor_mut(&mut self) -> DynCursorMut<'_, Dyn> {
+        // SAFETY:
+        // The new slice mutably borrows `self`, so it is the only way to access the elements
+        // while it exists.
+        DynCursorMut::new(unsafe {
+            DynSliceMut::from_parts(self.vtable_ptr, self.len, self.as_mut_ptr())
+        })
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug
+    for DynCursorMut<'a, Dyn>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynCursorMut")
+            .field("position", &self.position)
+            .field("remaining", &self.remaining())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynCursor, DynCursorMut};
+    use crate::standard::{add_assign, debug, partial_eq};
+
+    #[test]
+    fn consume() {
+        let array = [1_u8, 2, 3, 4];
+        let slice = partial_eq::new(&array);
+        let mut cursor = slice.cursor();
+        assert_eq!(cursor.len(), 4);
+
+        assert!(cursor.take(5).is_none());
+        assert!(!cursor.advance(5));
+        assert_eq!(cursor.position(), 0);
+
+        let first = cursor.take(1).unwrap();
+        assert!(cursor.advance(1));
+        cursor.mark();
+        let rest = cursor.take(2).unwrap();
+        assert!(cursor.is_empty() && cursor.peek().is_none());
+        assert!(cursor.remaining().is_empty());
+        assert!(cursor.take(0).is_some_and(|empty| empty.is_empty()));
+        assert!(first == [1] && rest == [3, 4]);
+
+        // The taken slices borrow the underlying slice, not the cursor
+        let taken = {
+            let mut cursor = slice.cursor();
+            cursor.take(3).unwrap()
+        };
+        assert!(taken == [1, 2, 3]);
+    }
+
+    #[test]
+    fn mark_reset() {
+        let array = ["a", "b", "c"];
+        let mut cursor = DynCursor::new(debug::new(&array));
+
+        cursor.reset();
+        assert_eq!(cursor.position(), 0);
+
+        assert!(cursor.advance(2));
+        cursor.mark();
+        assert!(cursor.advance(1));
+        cursor.reset();
+        assert_eq!(format!("{:?}", cursor.peek().unwrap()), r#""c""#);
+        assert_eq!(
+            format!("{cursor:?}"),
+            r#"DynCursor { position: 2, remaining: ["c"], .. }"#
+        );
+    }
+
+    #[test]
+    fn take_mut() {
+        let mut array = [1_u8, 2, 3, 4, 5];
+        let mut slice = add_assign::new_mut(&mut array);
+        let mut cursor = slice.cursor_mut();
+
+        assert!(cursor.take_mut(6).is_none());
+        assert!(!cursor.advance(6));
+        *cursor.peek_mut().unwrap() += 10;
+
+        let mut first = cursor.take_mut(2).unwrap();
+        assert!(cursor.advance(1));
+        let mut second = cursor.take_mut(1).unwrap();
+        first[1] += 20;
+        second[0] += 40;
+        assert_eq!(cursor.position(), 4);
+
+        cursor.remaining_mut()[0] += 50;
+        assert!(cursor.peek().is_some());
+        let mut rest = cursor.into_remaining();
+        rest[0] += 1;
+
+        assert_eq!(array, [11, 22, 3, 44, 56]);
+    }
+
+    #[test]
+    fn take_mut_empty() {
+        let mut array = [String::new()];
+        let mut cursor = DynCursorMut::new(debug::new_mut(&mut array));
+        assert!(cursor.take_mut(0).is_some_and(|empty| empty.is_empty()));
+        assert!(cursor.advance(1));
+        assert!(cursor.is_empty() && cursor.peek().is_none());
+        assert!(cursor.take_mut(0).is_some());
+        assert_eq!(
+            format!("{cursor:?}"),
+            "DynCursorMut { position: 1, remaining: [], .. }"
+        );
+    }
+}
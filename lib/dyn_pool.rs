@@ -0,0 +1,302 @@
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "stats")]
+use core::cell::Cell;
+use core::{
+    cell::RefCell,
+    marker::{PhantomData, Unsize},
+    mem::transmute,
+    ops::{Deref, DerefMut},
+    ptr::{self, DynMetadata, Pointee},
+};
+
+#[cfg(feature = "stats")]
+use crate::Stats;
+use crate::{utils::metadata_of, DynSlice, DynSliceMut, RawDynSlice};
+
+/// An object pool of elements of one concrete type, that implement a trait.
+///
+/// Elements are checked out with [`DynPool::acquire`], and are returned to the pool when the
+/// [`PoolGuard`] is dropped. Elements are not reset when they are returned.
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::add_assign, DynPool};
+///
+/// let pool = DynPool::<add_assign::Dyn<u8>>::new(vec![0_u8; 2].into_boxed_slice());
+///
+/// let mut a = pool.acquire().unwrap();
+/// let mut b = pool.acquire().unwrap();
+/// assert!(pool.acquire().is_none());
+///
+/// *a += 1;
+/// *b += 2;
+/// drop(a);
+/// assert_eq!(pool.available(), 1);
+/// ```
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+pub struct DynPool<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    raw: RawDynSlice,
+    drop_buffer: unsafe fn(RawDynSlice),
+    /// Indices of the elements that are not checked out, in the order they will be acquired from
+    /// the back
+    free: RefCell<Vec<usize>>,
+    #[cfg(feature = "stats")]
+    stats: Cell<Stats>,
+    phantom: PhantomData<Box<Dyn>>,
+}
+
+/// Drops a buffer created from a `Box<[T]>` with [`Box::into_raw`].
+///
+/// # Safety
+/// `raw` must be the raw parts of a `Box<[T]>` from [`Box::into_raw`], that has not already been dropped.
+unsafe fn drop_buffer<T>(raw: RawDynSlice) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+        raw.data.cast::<T>().cast_mut(),
+        raw.len,
+    )));
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynPool<Dyn> {
+    #[must_use]
+    /// Create a new pool from a boxed slice of elements, which are all available.
+    pub fn new<T: Unsize<Dyn>>(values: Box<[T]>) -> Self {
+        let len = values.len();
+        let data: *mut [T] = Box::into_raw(values);
+        let metadata = metadata_of::<T, Dyn>();
+
+        Self {
+            raw: RawDynSlice {
+                data: data.cast::<()>(),
+                len,
+                // SAFETY:
+                // DynMetadata only contains a single pointer, and has the same layout as *const ().
+                vtable: unsafe { transmute(metadata) },
+            },
+            drop_buffer: drop_buffer::<T>,
+            free: RefCell::new((0..len).rev().collect()),
+            #[cfg(feature = "stats")]
+            stats: Cell::new(Stats::new()),
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the total number of elements in the pool.
+    pub const fn capacity(&self) -> usize {
+        self.raw.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements that are not checked out.
+    pub fn available(&self) -> usize {
+        self.free.borrow().len()
+    }
+
+    #[must_use]
+    /// Check out an element, or returns `None` if every element is checked out.
+    ///
+    /// The element is returned to the pool when the guard is dropped.
+    pub fn acquire(&self) -> Option<PoolGuard<'_, Dyn>> {
+        let index = self.free.borrow_mut().pop()?;
+
+        #[cfg(feature = "stats")]
+        {
+            let mut stats = self.stats.get();
+            stats.record_allocations(1, self.capacity() - self.available(), self.element_size());
+            self.stats.set(stats);
+        }
+
+        Some(PoolGuard { pool: self, index })
+    }
+
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "stats")]
+    #[cfg_attr(doc, doc(cfg(feature = "stats")))]
+    /// Returns the allocation statistics of the pool, where the elements in use are the ones that
+    /// are checked out.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::debug, DynPool};
+    ///
+    /// let pool = DynPool::<debug::Dyn>::new(vec![0_u16; 4].into_boxed_slice());
+    /// let a = pool.acquire().unwrap();
+    /// let b = pool.acquire().unwrap();
+    /// drop(a);
+    /// let c = pool.acquire().unwrap();
+    ///
+    /// let stats = pool.stats();
+    /// assert_eq!((stats.allocations, stats.len, stats.high_water_len), (3, 2, 2));
+    /// assert_eq!((stats.bytes, stats.high_water_bytes, stats.capacity_bytes), (4, 4, 8));
+    /// # drop((b, c));
+    /// ```
+    pub fn stats(&self) -> Stats {
+        let element_size = self.element_size();
+        self.stats.get().with_usage(
+            self.capacity() - self.available(),
+            element_size,
+            self.capacity() * element_size,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the element at `index` is not checked out.
+    ///
+    /// # Panics
+    /// Panics if `index >= self.capacity()`.
+    pub fn is_available(&self, index: usize) -> bool {
+        assert!(index < self.capacity(), "index out of bounds");
+        self.free.borrow().contains(&index)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a dyn slice of every element in the pool.
+    ///
+    /// As this takes `&mut self`, no elements can be checked out.
+    pub fn as_dyn_slice(&mut self) -> DynSliceMut<'_, Dyn> {
+        // SAFETY:
+        // The raw parts are of a valid slice owned by the pool, and the mutable borrow
+        // guarantees that there are no guards.
+        unsafe { DynSliceMut::from_raw(self.raw) }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Returns the size of the underlying type.
+    fn element_size(&self) -> usize {
+        // SAFETY:
+        // The pool always has the vtable of the underlying type, even if it is empty, and
+        // DynMetadata only contains a single pointer, and has the same layout as *const ().
+        unsafe { transmute::<_, DynMetadata<Dyn>>(self.raw.vtable) }.size_of()
+    }
+
+    /// Returns a pointer to the element at `index`.
+    ///
+    /// # Safety
+    /// The caller must ensure that `index < self.capacity()`.
+    unsafe fn element_ptr(&self, index: usize) -> *mut Dyn {
+        let slice = DynSlice::<Dyn>::from_raw(self.raw);
+        let metadata = transmute::<_, DynMetadata<Dyn>>(self.raw.vtable);
+        ptr::from_raw_parts_mut(slice.get_ptr_unchecked(index).cast_mut(), metadata)
+    }
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Drop for DynPool<Dyn> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // `raw` is the raw parts of the boxed slice that `drop_buffer` was created for.
+        unsafe { (self.drop_buffer)(self.raw) };
+    }
+}
+
+/// An element checked out of a [`DynPool`], which is returned to the pool when dropped.
+///
+/// This is created by [`DynPool::acquire`].
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+pub struct PoolGuard<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    pool: &'a DynPool<Dyn>,
+    index: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> PoolGuard<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the index of the element in the pool.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Deref for PoolGuard<'a, Dyn> {
+    type Target = Dyn;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // The index was taken from the free list, so is in bounds and is only accessed through
+        // this guard.
+        unsafe { &*self.pool.element_ptr(self.index) }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DerefMut for PoolGuard<'a, Dyn> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // The index was taken from the free list, so is in bounds and is only accessed through
+        // this guard.
+        unsafe { &mut *self.pool.element_ptr(self.index) }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Drop for PoolGuard<'a, Dyn> {
+    fn drop(&mut self) {
+        self.pool.free.borrow_mut().push(self.index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::rc::Rc;
+
+    use super::DynPool;
+    use crate::standard::{add_assign, debug};
+
+    #[test]
+    fn acquire_release() {
+        let mut pool = DynPool::<add_assign::Dyn<u8>>::new(Box::new([0_u8; 3]));
+        assert_eq!(pool.capacity(), 3);
+        assert_eq!(pool.available(), 3);
+
+        let mut a = pool.acquire().unwrap();
+        let mut b = pool.acquire().unwrap();
+        assert_eq!((a.index(), b.index()), (0, 1));
+        assert!(!pool.is_available(0));
+        assert!(pool.is_available(2));
+
+        *a += 1;
+        *b += 2;
+        drop(a);
+        assert_eq!(pool.available(), 2);
+
+        let mut c = pool.acquire().unwrap();
+        assert_eq!(c.index(), 0);
+        *c += 10;
+        let mut d = pool.acquire().unwrap();
+        *d += 3;
+        assert!(pool.acquire().is_none());
+        drop((b, c, d));
+
+        let mut slice = pool.as_dyn_slice();
+        // SAFETY:
+        // The underlying type is `u8`.
+        let values = unsafe { slice.downcast_unchecked_mut::<u8>() };
+        assert_eq!(values, [11, 2, 3]);
+    }
+
+    #[test]
+    fn drops_elements() {
+        let a = Rc::new(());
+        let pool =
+            DynPool::<debug::Dyn>::new(vec![Rc::clone(&a), Rc::clone(&a)].into_boxed_slice());
+        assert_eq!(Rc::strong_count(&a), 3);
+
+        let guard = pool.acquire().unwrap();
+        assert_eq!(format!("{:?}", &*guard), "()");
+        drop(guard);
+
+        drop(pool);
+        assert_eq!(Rc::strong_count(&a), 1);
+    }
+
+    #[test]
+    fn empty() {
+        let pool = DynPool::<debug::Dyn>::new(Box::<[u8; 0]>::new([]));
+        assert_eq!(pool.capacity(), 0);
+        assert!(pool.acquire().is_none());
+    }
+}
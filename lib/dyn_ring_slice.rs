@@ -0,0 +1,236 @@
+use core::{
+    fmt::{self, Debug},
+    iter::Chain,
+    ops::Index,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime, DynSlice, DynSliceChain, Iter};
+
+/// A view over the elements of a ring buffer, in order from the oldest element.
+///
+/// The elements of a ring buffer are stored in two parts: the head, from the oldest element to
+/// the end of the buffer, and the tail, which wraps around to the start of the buffer. This view
+/// indexes and iterates over both parts as if they were one slice, like
+/// [`VecDeque`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html).
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::debug, DynRingSlice};
+///
+/// // A ring buffer with capacity 4, where the oldest element is at index 2
+/// let buffer = [4, 5, 2, 3];
+/// let ring = DynRingSlice::from_buffer(debug::new(&buffer), 2, 4).unwrap();
+///
+/// # assert_eq!(format!("{:?}", &ring[0]), "2");
+/// println!("{:?}", &ring[0]); // 2
+/// # assert_eq!(format!("{ring:?}"), "[2, 3, 4, 5]");
+/// println!("{ring:?}"); // [2, 3, 4, 5]
+/// ```
+pub struct DynRingSlice<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    chain: DynSliceChain<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DynRingSlice<'a, Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for DynRingSlice<'a, Dyn> {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynRingSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a view over a ring buffer from its `head`, which starts with the oldest element, and
+    /// its `tail`, which holds the elements that wrapped around to the start of the buffer.
+    pub const fn new(head: DynSlice<'a, Dyn>, tail: DynSlice<'a, Dyn>) -> Self {
+        Self {
+            chain: DynSliceChain::new(head, tail),
+        }
+    }
+
+    #[must_use]
+    /// Create a view over the `len` elements of a ring `buffer`, starting from the oldest element
+    /// at index `head`.
+    ///
+    /// Returns `None` if `head > buffer.len()` or `len > buffer.len()`.
+    pub fn from_buffer(buffer: DynSlice<'a, Dyn>, head: usize, len: usize) -> Option<Self> {
+        if head > buffer.len || len > buffer.len {
+            return None;
+        }
+
+        // The index after the end of the buffer wraps around to the start
+        let head = if head == buffer.len { 0 } else { head };
+        let head_len = len.min(buffer.len - head);
+        // SAFETY:
+        // `head < buffer.len()` (or the buffer is empty), `head + head_len <= buffer.len()` and
+        // `len - head_len <= buffer.len()`, so both sub-slices are in bounds. They are sub-slices
+        // of `buffer`, so they can have its lifetime.
+        let (head, tail) = unsafe {
+            (
+                extend_lifetime(buffer.slice_unchecked(head, head_len)),
+                extend_lifetime(buffer.slice_unchecked(0, len - head_len)),
+            )
+        };
+
+        Some(Self::new(head, tail))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the head and tail of the ring buffer.
+    pub const fn as_slices(&self) -> (DynSlice<'a, Dyn>, DynSlice<'a, Dyn>) {
+        self.chain.as_slices()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the ring buffer as a [`DynSliceChain`], which has chunk and window iterators.
+    pub const fn as_chain(&self) -> DynSliceChain<'a, Dyn> {
+        self.chain
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in the ring buffer.
+    pub const fn len(&self) -> usize {
+        self.chain.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the ring buffer has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.chain.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a reference to the element at `index`, counting from the oldest element, or `None`
+    /// if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Dyn> {
+        self.chain.get(index)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a reference to the oldest element, or `None` if the ring buffer is empty.
+    pub fn first(&self) -> Option<&Dyn> {
+        self.get(0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a reference to the newest element, or `None` if the ring buffer is empty.
+    pub fn last(&self) -> Option<&Dyn> {
+        self.len().checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    #[inline]
+    /// Returns an iterator over the elements, from the oldest to the newest.
+    pub fn iter(&self) -> Chain<Iter<'a, Dyn>, Iter<'a, Dyn>> {
+        self.chain.iter()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Index<usize>
+    for DynRingSlice<'a, Dyn>
+{
+    type Output = Dyn;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IntoIterator
+    for DynRingSlice<'a, Dyn>
+{
+    type IntoIter = Chain<Iter<'a, Dyn>, Iter<'a, Dyn>>;
+    type Item = &'a Dyn;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug
+    for DynRingSlice<'a, Dyn>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chain.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::collections::VecDeque;
+
+    use super::DynRingSlice;
+    use crate::standard::partial_eq;
+
+    fn ring_eq(ring: DynRingSlice<dyn PartialEq<u8>>, expected: &VecDeque<u8>) -> bool {
+        ring.len() == expected.len()
+            && ring.iter().zip(expected).all(|(a, b)| a == b)
+            && ring
+                .iter()
+                .rev()
+                .zip(expected.iter().rev())
+                .all(|(a, b)| a == b)
+            && (0..=expected.len()).all(|index| match (ring.get(index), expected.get(index)) {
+                (Some(a), Some(b)) => a == b,
+                (a, b) => a.is_none() && b.is_none(),
+            })
+    }
+
+    #[test]
+    fn vec_deque() {
+        let mut deque = VecDeque::with_capacity(4);
+        for value in 0..12 {
+            if deque.len() == 4 {
+                deque.pop_front();
+            }
+            deque.push_back(value);
+
+            let (head, tail) = deque.as_slices();
+            let ring = DynRingSlice::new(partial_eq::new(head), partial_eq::new(tail));
+            assert!(ring_eq(ring, &deque));
+            assert!(ring
+                .first()
+                .is_some_and(|first| first == deque.front().unwrap()));
+            assert!(ring
+                .last()
+                .is_some_and(|last| last == deque.back().unwrap()));
+        }
+    }
+
+    #[test]
+    fn from_buffer() {
+        let buffer = [0_u8, 1, 2, 3, 4];
+        let slice = partial_eq::new(&buffer);
+
+        for head in 0..=buffer.len() {
+            for len in 0..=buffer.len() {
+                let ring = DynRingSlice::from_buffer(slice, head, len).unwrap();
+                let expected: VecDeque<u8> = buffer
+                    .iter()
+                    .cycle()
+                    .skip(head)
+                    .take(len)
+                    .copied()
+                    .collect();
+                assert!(ring_eq(ring, &expected));
+            }
+        }
+
+        assert!(DynRingSlice::from_buffer(slice, 6, 0).is_none());
+        assert!(DynRingSlice::from_buffer(slice, 0, 6).is_none());
+
+        let empty = partial_eq::new::<u8, u8>(&[]);
+        let ring = DynRingSlice::from_buffer(empty, 0, 0).unwrap();
+        assert!(ring.is_empty());
+        assert!(ring.first().is_none() && ring.last().is_none());
+    }
+}
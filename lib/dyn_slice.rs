@@ -1,16 +1,29 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "alloc")]
+use core::marker::Unsize;
 use core::{
+    alloc::Layout,
+    cmp::Ordering,
     marker::PhantomData,
-    mem::transmute,
+    mem::{self, transmute},
     num::NonZeroUsize,
     ops::{Bound, Index, RangeBounds},
     ptr,
-    ptr::{DynMetadata, Pointee},
+    ptr::{DynMetadata, NonNull, Pointee},
     slice,
 };
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 use crate::{
-    iter::{Chunks, RChunks, Windows},
-    Iter,
+    iter::{Chunks, RChunks, RSplit, RSplitN, Split, SplitInclusive, SplitN, Windows, ZipWith},
+    utils::{byte_offset_unchecked, metadata_of, prefetch_read, LazySize},
+    DynSliceIndex, Iter,
 };
 
 /// `&dyn [Trait]`
@@ -39,6 +52,14 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DynSlice<
 }
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for DynSlice<'a, Dyn> {}
 
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Default for DynSlice<'a, Dyn> {
+    #[inline]
+    /// Returns an empty dyn slice without metadata, like [`DynSlice::empty`].
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
     #[inline]
     #[must_use]
@@ -80,6 +101,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
     /// - `vtable_ptr` is a valid instance of `DynMetadata` transmuted, or optionally, a null pointer if `len == 0`,
     /// - `len` <= the length of the slice in memory from the `data` pointer,
     /// - `data` is a valid pointer to the slice,
+    /// - the total size of the slice, `len` elements, is at most `isize::MAX` bytes,
     /// - the underlying slice is the same layout as [`[T]`](https://doc.rust-lang.org/reference/type-layout.html#slice-layout)
     pub const unsafe fn from_parts(vtable_ptr: *const (), len: usize, data: *const ()) -> Self {
         Self {
@@ -99,13 +121,69 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
     /// - `metadata` is a valid instance of `DynMetadata`,
     /// - `len` <= the length of the slice in memory from the `data` pointer,
     /// - `data` is a valid pointer to the slice,
+    /// - the total size of the slice, `len` elements, is at most `isize::MAX` bytes,
     /// - the underlying slice is the same layout as [`[T]`](https://doc.rust-lang.org/reference/type-layout.html#slice-layout)
+    ///
+    /// # Panics
+    /// In debug builds, panics if [`DynSlice::validate`] finds a problem with the raw parts.
     pub unsafe fn from_parts_with_metadata(
         metadata: DynMetadata<Dyn>,
         len: usize,
         data: *const (),
     ) -> Self {
-        Self::from_parts(transmute(metadata), len, data)
+        let slice = Self::from_parts(transmute(metadata), len, data);
+
+        #[cfg(debug_assertions)]
+        if let Err(error) = slice.validate() {
+            panic!("[dyn-slice] invalid raw parts: {error}!");
+        }
+
+        slice
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct an empty dyn slice without metadata.
+    ///
+    /// [`DynSlice::metadata`] returns `None` for the slice. Use [`DynSlice::empty_with_metadata`]
+    /// if the metadata is needed, for example to downcast the slice.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::Slice::empty();
+    /// assert!(slice.is_empty());
+    /// assert!(slice.metadata().is_none());
+    /// ```
+    pub const fn empty() -> Self {
+        // SAFETY:
+        // The slice is empty, so the vtable pointer can be null, and the data pointer is not
+        // dereferenced.
+        unsafe { Self::from_parts(ptr::null(), 0, NonNull::<u8>::dangling().as_ptr().cast()) }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct an empty dyn slice with `metadata`, so that the slice still has an underlying
+    /// type.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::any, DynSlice};
+    ///
+    /// let slice = DynSlice::empty_with_metadata(any::metadata::<u8>());
+    /// assert!(slice.is_empty());
+    /// assert_eq!(slice.downcast::<u8>(), Some(&[][..]));
+    /// ```
+    pub fn empty_with_metadata(metadata: DynMetadata<Dyn>) -> Self {
+        // Use the alignment as the address, like `NonNull::dangling`, so that the data pointer is
+        // aligned for the underlying type
+        let data = ptr::null::<u8>().wrapping_add(metadata.align_of()).cast();
+        // SAFETY:
+        // The slice is empty, so the data pointer is not dereferenced. The metadata is valid, and
+        // the data pointer is aligned for the underlying type.
+        unsafe { Self::from_parts(transmute(metadata), 0, data) }
     }
 
     #[inline]
@@ -129,6 +207,41 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         })
     }
 
+    #[inline]
+    #[must_use]
+    /// Returns the layout of the elements, or `None` if the slice is empty and has no metadata.
+    ///
+    /// # Example
+    /// ```
+    /// # use core::alloc::Layout;
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[1_u32, 2, 3]);
+    /// assert_eq!(slice.element_layout(), Some(Layout::new::<u32>()));
+    /// ```
+    pub fn element_layout(&self) -> Option<Layout> {
+        self.metadata().map(DynMetadata::layout)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the size of the underlying slice in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[1_u32, 2, 3]);
+    /// assert_eq!(slice.byte_len(), 12);
+    /// ```
+    pub fn byte_len(&self) -> usize {
+        self.metadata().map_or(0, |metadata| {
+            // SAFETY:
+            // The slice is at most `isize::MAX` bytes, as required by the constructors.
+            unsafe { byte_offset_unchecked(metadata.size_of(), self.len) }
+        })
+    }
+
     #[inline]
     #[must_use]
     /// Returns the number of elements in the slice.
@@ -237,6 +350,8 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
     #[must_use]
     /// Returns a reference to the element at the given `index` or `None` if the `index` is out of bounds.
     ///
+    /// If `index` is a range, this returns a sub-slice instead, like [`DynSlice::slice`].
+    ///
     /// # Example
     /// ```
     /// use dyn_slice::standard::debug;
@@ -246,14 +361,44 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
     /// println!("{:?}", slice.get(2)); // Some(3)
     /// # assert!(slice.get(5).is_none());
     /// println!("{:?}", slice.get(5)); // None
+    /// # assert_eq!(format!("{:?}", slice.get(1..3).unwrap()), "[2, 3]");
+    /// println!("{:?}", slice.get(1..3)); // Some([2, 3])
+    /// ```
+    pub fn get<I: DynSliceIndex<Dyn>>(&self, index: I) -> Option<I::Output<'_>> {
+        index.get(self)
+    }
+
+    #[must_use]
+    /// Returns a reference to the element at the given `index`, with the lifetime of the underlying
+    /// data, or `None` if the `index` is out of bounds.
+    ///
+    /// Unlike [`DynSlice::get`] and indexing, which borrow the slice handle, the returned reference
+    /// lives for `'a`. As `DynSlice` is [`Copy`], this takes the slice by value, so the reference
+    /// can outlive a temporary slice handle.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1, 2, 3];
+    /// let element = {
+    ///     let slice = debug::new(&array);
+    ///     slice.get_ref(1).unwrap()
+    /// };
+    /// # assert_eq!(format!("{element:?}"), "2");
+    /// println!("{element:?}"); // 2
     /// ```
-    pub fn get(&self, index: usize) -> Option<&Dyn> {
+    pub fn get_ref(self, index: usize) -> Option<&'a Dyn> {
         (index < self.len).then(|| {
             // SAFETY:
             // The above inequality ensures that the index is less than the
             // length, and is therefore valid. This also ensures that the slice
             // has a valid vtable pointer because the slice guaranteed to not be empty.
-            unsafe { self.get_unchecked(index) }
+            // The data is borrowed for 'a, so the reference is valid for 'a.
+            unsafe {
+                let metadata = transmute::<_, DynMetadata<Dyn>>(self.vtable_ptr());
+                &*ptr::from_raw_parts::<Dyn>(self.get_ptr_unchecked(index), metadata)
+            }
         })
     }
 
@@ -279,25 +424,21 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         );
 
         let metadata = transmute::<_, DynMetadata<Dyn>>(self.vtable_ptr());
-        self.as_ptr().byte_add(metadata.size_of() * index)
+        // `index <= self.len`, so the offset is at most the size of the slice
+        self.as_ptr()
+            .byte_add(byte_offset_unchecked(metadata.size_of(), index))
     }
 
     #[inline]
     #[must_use]
-    /// Returns a reference to the element at the given `index`, without doing bounds checking.
+    /// Returns a reference to the element, or sub-slice, at the given `index`, without doing
+    /// bounds checking.
     ///
     /// # Safety
-    /// The caller must ensure that `index < self.len()`.
-    /// Calling this on an empty `DynSlice` will result in a segfault!
-    pub unsafe fn get_unchecked(&self, index: usize) -> &Dyn {
-        debug_assert!(
-            index < self.len,
-            "[dyn-slice] index is greater than or equal to length!"
-        );
-
-        let metadata = transmute::<_, DynMetadata<Dyn>>(self.vtable_ptr());
-        let data = self.get_ptr_unchecked(index);
-        &*ptr::from_raw_parts::<Dyn>(data, metadata)
+    /// The caller must ensure that `index` is in bounds.
+    /// Calling this with a `usize` on an empty `DynSlice` will result in a segfault!
+    pub unsafe fn get_unchecked<I: DynSliceIndex<Dyn>>(&self, index: I) -> I::Output<'_> {
+        index.get_unchecked(self)
     }
 
     #[inline]
@@ -415,6 +556,38 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         )
     }
 
+    #[inline]
+    #[must_use]
+    /// Returns the size of the underlying type, or 0 if the slice has no metadata.
+    ///
+    /// Iterators cache this so that they do not read it from the vtable on every step.
+    pub(crate) fn element_size(&self) -> usize {
+        self.metadata().map_or(0, DynMetadata::size_of)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Splits the slice at `mid` using `size` as the size of the underlying type, without doing
+    /// bounds checking or reading the size from the vtable.
+    ///
+    /// # Safety
+    /// The caller must ensure that `mid <= self.len()`, and that `size` is the size of the
+    /// underlying type (or that the slice is empty).
+    pub(crate) unsafe fn split_at_with_size_unchecked(
+        &self,
+        mid: usize,
+        size: usize,
+    ) -> (DynSlice<Dyn>, DynSlice<Dyn>) {
+        debug_assert!(mid <= self.len, "[dyn-slice] mid is greater than length!");
+
+        let second = self.as_ptr().byte_add(byte_offset_unchecked(size, mid));
+
+        (
+            DynSlice::from_parts(self.vtable_ptr(), mid, self.as_ptr()),
+            DynSlice::from_parts(self.vtable_ptr(), self.len() - mid, second),
+        )
+    }
+
     #[inline]
     #[must_use]
     /// Returns an iterator over the slice.
@@ -432,7 +605,46 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
     /// println!("{:?}", iter.collect::<Vec<String>>()); // ["1!", "2!", "3!", "4!", "5!"]
     /// ```
     pub const fn iter(&self) -> Iter<'_, Dyn> {
-        Iter { slice: *self }
+        Iter::new(*self)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator that calls `f` on each pair of elements of this slice and `other`, in lockstep.
+    ///
+    /// Unlike zipping two iterators, this only does one bounds check per step.
+    ///
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let a: [u8; 3] = [1, 2, 3];
+    /// let b: [u16; 3] = [10, 20, 30];
+    /// let a = to::new::<u32, _>(&a);
+    /// let b = to::new::<u32, _>(&b);
+    ///
+    /// let sums = a.zip_with(&b, |x, y| x.to() + y.to());
+    /// assert!(sums.eq([11, 22, 33]));
+    /// ```
+    pub fn zip_with<'b, Dyn2, R, F>(
+        &self,
+        other: &DynSlice<'b, Dyn2>,
+        f: F,
+    ) -> ZipWith<'_, 'b, Dyn, Dyn2, F>
+    where
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>>,
+        F: FnMut(&Dyn, &Dyn2) -> R,
+    {
+        assert!(self.len() == other.len(), "slices have different lengths");
+
+        ZipWith {
+            a: self.iter(),
+            b: Iter::new(*other),
+            f,
+        }
     }
 
     #[must_use]
@@ -444,6 +656,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         Chunks {
             slice: *self,
             chunk_size,
+            size: LazySize::new(),
         }
     }
 
@@ -470,6 +683,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         RChunks {
             slice: *self,
             chunk_size,
+            size: LazySize::new(),
         }
     }
 
@@ -487,14 +701,50 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         Some(self.rchunks_non_zero(cs))
     }
 
+    /// Folds over chunks of the slice of length `chunk_size`, like
+    /// `self.chunks_non_zero(chunk_size).fold(init, f)`, but without an iterator.
+    ///
+    /// If `chunk_size` does not exactly divide the length, the last chunk will be shorter.
+    ///
+    /// # Example
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use dyn_slice::standard::any;
+    ///
+    /// let slice = any::new(&[1_u32, 2, 3, 4, 5]);
+    ///
+    /// let sums = slice.fold_chunks(NonZeroUsize::new(2).unwrap(), Vec::new(), |mut sums, chunk| {
+    ///     sums.push(chunk.downcast::<u32>().unwrap().iter().sum::<u32>());
+    ///     sums
+    /// });
+    /// assert_eq!(sums, [3, 7, 5]);
+    /// ```
+    pub fn fold_chunks<B, F: FnMut(B, DynSlice<Dyn>) -> B>(
+        &self,
+        chunk_size: NonZeroUsize,
+        init: B,
+        mut f: F,
+    ) -> B {
+        let chunk_size = chunk_size.get();
+        let mut acc = init;
+        let mut start = 0;
+
+        while start < self.len {
+            let len = chunk_size.min(self.len - start);
+            // SAFETY:
+            // `start < self.len` and `len <= self.len - start`, so the chunk is within the slice.
+            acc = f(acc, unsafe { self.slice_unchecked(start, len) });
+            start += len;
+        }
+
+        acc
+    }
+
     #[must_use]
     #[inline]
     /// Returns an iterator over overlapping subslices of the slice of length `window_size`.
     pub const fn windows_non_zero(&self, window_size: NonZeroUsize) -> Windows<'_, Dyn> {
-        Windows {
-            slice: *self,
-            window_size,
-        }
+        Windows::new(*self, window_size)
     }
 
     #[must_use]
@@ -509,6 +759,692 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
         };
         Some(self.windows_non_zero(ws))
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over overlapping subslices of the slice of length `window_size`, or
+    /// of the whole slice if it is shorter than `window_size`.
+    ///
+    /// Unlike [`DynSlice::windows`], this yields the whole slice once when
+    /// `window_size > self.len()`, so every window has at most `window_size` elements.
+    /// If the slice is empty, nothing is yielded.
+    /// For non-overlapping chunks of at most `chunk_size` elements, use [`DynSlice::chunks`].
+    ///
+    /// If `window_size` is 0, this will return [`None`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1, 2];
+    /// let slice = debug::new(&array);
+    ///
+    /// let mut iter = slice.windows_clamped(3).unwrap();
+    /// let window = iter.next().unwrap();
+    /// # assert_eq!(format!("{window:?}"), "[1, 2]");
+    /// println!("{window:?}"); // [1, 2]
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub const fn windows_clamped(&self, window_size: usize) -> Option<Windows<'_, Dyn>> {
+        // Empty slices use a window size of 1, so that nothing is yielded
+        let len = if self.len == 0 { 1 } else { self.len };
+        let window_size = if window_size > len { len } else { window_size };
+
+        self.windows(window_size)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over subslices separated by elements that match `pred`, like
+    /// [`slice::split`](https://doc.rust-lang.org/std/primitive.slice.html#method.split).
+    ///
+    /// The matched elements are not included in the subslices. If the first or last element
+    /// matches, an empty subslice is yielded before or after it.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::partial_eq;
+    ///
+    /// let array = [1, 0, 2, 3, 0];
+    /// let slice = partial_eq::new(&array);
+    ///
+    /// let mut iter = slice.split(|x| x == &0);
+    /// assert!(iter.next().unwrap() == [1][..]);
+    /// assert!(iter.next().unwrap() == [2, 3][..]);
+    /// assert!(iter.next().unwrap().is_empty());
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn split<P: FnMut(&Dyn) -> bool>(&self, pred: P) -> Split<'_, Dyn, P> {
+        Split::new(*self, pred)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over subslices separated by elements that match `pred`, starting from
+    /// the end of the slice, like
+    /// [`slice::rsplit`](https://doc.rust-lang.org/std/primitive.slice.html#method.rsplit).
+    ///
+    /// The matched elements are not included in the subslices.
+    pub fn rsplit<P: FnMut(&Dyn) -> bool>(&self, pred: P) -> RSplit<'_, Dyn, P> {
+        RSplit {
+            inner: Split::new(*self, pred),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over at most `n` subslices separated by elements that match `pred`,
+    /// like [`slice::splitn`](https://doc.rust-lang.org/std/primitive.slice.html#method.splitn).
+    ///
+    /// The last subslice is the rest of the slice, which may contain matching elements.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::partial_eq;
+    ///
+    /// let array = [1, 0, 2, 0, 3];
+    /// let slice = partial_eq::new(&array);
+    ///
+    /// let mut iter = slice.splitn(2, |x| x == &0);
+    /// assert!(iter.next().unwrap() == [1][..]);
+    /// assert!(iter.next().unwrap() == [2, 0, 3][..]);
+    /// assert!(iter.next().is_none());
+    ///
+    /// let mut iter = slice.rsplitn(2, |x| x == &0);
+    /// assert!(iter.next().unwrap() == [3][..]);
+    /// assert!(iter.next().unwrap() == [1, 0, 2][..]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn splitn<P: FnMut(&Dyn) -> bool>(&self, n: usize, pred: P) -> SplitN<'_, Dyn, P> {
+        SplitN {
+            inner: Split::new(*self, pred),
+            count: n,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over at most `n` subslices separated by elements that match `pred`,
+    /// starting from the end of the slice, like
+    /// [`slice::rsplitn`](https://doc.rust-lang.org/std/primitive.slice.html#method.rsplitn).
+    ///
+    /// The last subslice is the rest of the slice, which may contain matching elements.
+    pub fn rsplitn<P: FnMut(&Dyn) -> bool>(&self, n: usize, pred: P) -> RSplitN<'_, Dyn, P> {
+        RSplitN {
+            inner: Split::new(*self, pred),
+            count: n,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over subslices separated by elements that match `pred`, like
+    /// [`slice::split_inclusive`](https://doc.rust-lang.org/std/primitive.slice.html#method.split_inclusive).
+    ///
+    /// Each matched element is kept at the end of the subslice before it. If the last element
+    /// matches, no empty subslice is yielded after it.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::partial_eq;
+    ///
+    /// let array = [1, 0, 2, 3, 0];
+    /// let slice = partial_eq::new(&array);
+    ///
+    /// let mut iter = slice.split_inclusive(|x| x == &0);
+    /// assert!(iter.next().unwrap() == [1, 0][..]);
+    /// assert!(iter.next().unwrap() == [2, 3, 0][..]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn split_inclusive<P: FnMut(&Dyn) -> bool>(&self, pred: P) -> SplitInclusive<'_, Dyn, P> {
+        SplitInclusive::new(*self, pred)
+    }
+
+    /// Folds every element into an accumulator, like [`Iterator::fold`], while prefetching the
+    /// next element into the cache.
+    ///
+    /// As the stride between elements is known, the next element can be requested before `f` is
+    /// called on the current one, which can hide memory stalls when the elements are large.
+    /// On targets without a stable prefetch instruction, this is the same as [`Iterator::fold`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[1_u8, 2, 3, 4]);
+    /// let sum = slice.fold_prefetched(0, |sum, x| sum + x.to());
+    /// # assert_eq!(sum, 10);
+    /// println!("{sum}"); // 10
+    /// ```
+    pub fn fold_prefetched<B, F: FnMut(B, &Dyn) -> B>(&self, init: B, mut f: F) -> B {
+        let Some(metadata) = self.metadata() else {
+            return init;
+        };
+        let size = metadata.size_of();
+
+        let mut next = self.data;
+        self.iter().fold(init, |acc, element| {
+            // The pointer is only used as a hint, so it is fine for it to go past the end
+            next = next.wrapping_byte_add(size);
+            prefetch_read(next);
+            f(acc, element)
+        })
+    }
+
+    #[must_use]
+    /// Returns `true` if the slice and `other` have the same length, and `eq` returns `true` for
+    /// each pair of elements.
+    ///
+    /// This can compare against any iterable, without needing a [`PartialEq`] implementation
+    /// for the elements or collecting `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[1_u8, 2, 3]);
+    ///
+    /// assert!(slice.eq_by(1..=3, |a, b| a.to() == b));
+    /// assert!(!slice.eq_by(1..=4, |a, b| a.to() == b));
+    /// ```
+    pub fn eq_by<I, F>(&self, other: I, mut eq: F) -> bool
+    where
+        I: IntoIterator,
+        F: FnMut(&Dyn, I::Item) -> bool,
+    {
+        let mut other = other.into_iter();
+        for element in self {
+            let Some(other_element) = other.next() else {
+                return false;
+            };
+
+            if !eq(element, other_element) {
+                return false;
+            }
+        }
+
+        other.next().is_none()
+    }
+
+    #[must_use]
+    /// Lexicographically compares the slice with `other`, using `cmp` to compare each pair of
+    /// elements.
+    ///
+    /// If one is a prefix of the other, the shorter one is less.
+    ///
+    /// # Example
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[1_u8, 2, 3]);
+    ///
+    /// assert_eq!(slice.cmp_by([1, 2, 4], |a, b| a.to().cmp(&b)), Ordering::Less);
+    /// assert_eq!(slice.cmp_by([1, 2], |a, b| a.to().cmp(&b)), Ordering::Greater);
+    /// ```
+    pub fn cmp_by<I, F>(&self, other: I, mut cmp: F) -> Ordering
+    where
+        I: IntoIterator,
+        F: FnMut(&Dyn, I::Item) -> Ordering,
+    {
+        let mut other = other.into_iter();
+        for element in self {
+            let Some(other_element) = other.next() else {
+                return Ordering::Greater;
+            };
+
+            match cmp(element, other_element) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+
+        if other.next().is_some() {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    #[must_use]
+    /// Returns `true` if the slice is sorted using `compare`, which should return `true` if its
+    /// arguments are in order, like [`slice::is_sorted_by`](https://doc.rust-lang.org/std/primitive.slice.html#method.is_sorted_by).
+    ///
+    /// This can be used to check the precondition of a binary search.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[1_u8, 2, 2, 5]);
+    ///
+    /// assert!(slice.is_sorted_by(|a, b| a.to() <= b.to()));
+    /// assert!(!slice.is_sorted_by(|a, b| a.to() < b.to()));
+    /// ```
+    pub fn is_sorted_by<F: FnMut(&Dyn, &Dyn) -> bool>(&self, mut compare: F) -> bool {
+        let mut iter = self.iter();
+        let Some(mut previous) = iter.next() else {
+            return true;
+        };
+
+        iter.all(|element| compare(previous, mem::replace(&mut previous, element)))
+    }
+
+    #[must_use]
+    /// Returns `true` if the keys extracted by `f` are sorted, like
+    /// [`slice::is_sorted_by_key`](https://doc.rust-lang.org/std/primitive.slice.html#method.is_sorted_by_key).
+    ///
+    /// If any pair of keys are not comparable, this returns `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<f64, _>(&[1_u8, 2, 3]);
+    ///
+    /// assert!(slice.is_sorted_by_key(|x| x.to()));
+    /// assert!(!slice.is_sorted_by_key(|x| -x.to()));
+    /// ```
+    pub fn is_sorted_by_key<K: PartialOrd, F: FnMut(&Dyn) -> K>(&self, mut f: F) -> bool {
+        let mut iter = self.iter().map(&mut f);
+        let Some(mut previous) = iter.next() else {
+            return true;
+        };
+
+        iter.all(|key| {
+            let sorted = previous <= key;
+            previous = key;
+            sorted
+        })
+    }
+
+    #[must_use]
+    /// Returns the index of and a reference to the maximum element with respect to `compare`, or
+    /// `None` if the slice is empty.
+    ///
+    /// Like [`Iterator::max_by`], if several elements are equally maximum, the last one is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[1_u8, 5, 2, 5]);
+    ///
+    /// let (index, max) = slice.max_by(|a, b| a.to().cmp(&b.to())).unwrap();
+    /// assert_eq!(index, 3);
+    /// assert_eq!(max.to(), 5);
+    /// ```
+    pub fn max_by<F: FnMut(&Dyn, &Dyn) -> Ordering>(
+        &self,
+        mut compare: F,
+    ) -> Option<(usize, &Dyn)> {
+        self.iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| compare(a, b))
+    }
+
+    #[must_use]
+    /// Returns the index of and a reference to the minimum element with respect to `compare`, or
+    /// `None` if the slice is empty.
+    ///
+    /// Like [`Iterator::min_by`], if several elements are equally minimum, the first one is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[3_u8, 1, 2, 1]);
+    ///
+    /// let (index, min) = slice.min_by(|a, b| a.to().cmp(&b.to())).unwrap();
+    /// assert_eq!(index, 1);
+    /// assert_eq!(min.to(), 1);
+    /// ```
+    pub fn min_by<F: FnMut(&Dyn, &Dyn) -> Ordering>(
+        &self,
+        mut compare: F,
+    ) -> Option<(usize, &Dyn)> {
+        self.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| compare(a, b))
+    }
+
+    /// Binary searches the slice with a comparator function, like
+    /// [`slice::binary_search_by`](https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by).
+    ///
+    /// The slice should be sorted in the order `f` compares elements with the target. If a
+    /// matching element is found, its index is returned as `Ok`. If there are several, any of
+    /// them may be returned. Otherwise, the index that the target could be inserted at to keep
+    /// the slice sorted is returned as `Err`.
+    ///
+    /// # Errors
+    /// Returns `Err` with the insertion index if no element matches.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[1_u8, 3, 5, 7]);
+    ///
+    /// assert_eq!(slice.binary_search_by(|x| x.to().cmp(&5)), Ok(2));
+    /// assert_eq!(slice.binary_search_by(|x| x.to().cmp(&4)), Err(2));
+    /// assert_eq!(slice.binary_search_by(|x| x.to().cmp(&8)), Err(4));
+    /// ```
+    pub fn binary_search_by<F: FnMut(&Dyn) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            // SAFETY:
+            // `low <= mid < high <= self.len`, so `mid` is in bounds.
+            match f(unsafe { self.get_unchecked(mid) }) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
+    }
+
+    #[must_use]
+    /// Converts the slice into a slice of another trait object of the same underlying type.
+    ///
+    /// `example_cast` is called on the first element to get the new vtable. If the slice is empty,
+    /// it is not called.
+    ///
+    /// # Safety
+    /// The caller must ensure that `example_cast` returns its argument, unsized to `NewDyn` from
+    /// the underlying type, so that the new vtable is valid for every element of the slice.
+    ///
+    /// Returning a field of the element, even one at the same location and of the same size (like
+    /// the `bool` in an `Option<bool>`), is undefined behaviour, as other elements may not hold a
+    /// valid value of the field's type.
+    ///
+    /// # Panics
+    /// Panics if `example_cast` returns a reference to a different location or size than its
+    /// argument.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(ptr_metadata)]
+    /// # use core::fmt::{Debug, Display};
+    /// use dyn_slice::declare_new_fns;
+    ///
+    /// pub trait DisplayDebug: Display {
+    ///     fn as_debug(&self) -> &dyn Debug;
+    /// }
+    /// impl<T: Display + Debug> DisplayDebug for T {
+    ///     fn as_debug(&self) -> &dyn Debug {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// declare_new_fns!(display_debug DisplayDebug);
+    ///
+    /// # fn main() {
+    /// let slice = display_debug::new(&["a", "b"]);
+    /// // SAFETY: `as_debug` returns its argument as `dyn Debug`
+    /// let debug_slice = unsafe { slice.retype_with(DisplayDebug::as_debug) };
+    /// # assert_eq!(format!("{debug_slice:?}"), r#"["a", "b"]"#);
+    /// println!("{debug_slice:?}"); // ["a", "b"]
+    /// # }
+    /// ```
+    pub unsafe fn retype_with<NewDyn, F>(&self, example_cast: F) -> DynSlice<'a, NewDyn>
+    where
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>>,
+        F: FnOnce(&'a Dyn) -> &'a NewDyn,
+    {
+        if self.is_empty() {
+            // SAFETY:
+            // The slice is empty, so a null vtable pointer is valid.
+            return DynSlice::from_parts(ptr::null(), 0, self.data);
+        }
+
+        // SAFETY:
+        // The slice is not empty, and its elements are borrowed for `'a`.
+        let first: &'a Dyn = &*(self.get_unchecked(0) as *const Dyn);
+
+        let cast = example_cast(first);
+        let metadata = ptr::metadata(cast);
+        assert!(
+            ptr::eq(
+                (first as *const Dyn).cast::<()>(),
+                (cast as *const NewDyn).cast::<()>()
+            ) && metadata.size_of() == core::mem::size_of_val(first),
+            "cast does not return the element"
+        );
+
+        // SAFETY:
+        // The caller guarantees that the new metadata is for the underlying type, which is the
+        // same for every element of the slice.
+        DynSlice::from_parts_with_metadata(metadata, self.len, self.data)
+    }
+
+    #[must_use]
+    /// Returns `true` if both slices have the same length and vtable, and their elements have
+    /// identical bytes.
+    ///
+    /// This can be used as a fast pre-check before a deep comparison. A result of `true` means
+    /// the elements are bitwise identical, but `false` says nothing about deep equality: equal
+    /// values may have different bytes (e.g. different heap pointers), and the same type may
+    /// have more than one vtable.
+    ///
+    /// To compare a dyn slice with a slice of a type that implements
+    /// [`BytesEq`](crate::standard::BytesEq), use [`DynSlice::bytes_eq_slice`].
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type has no padding or otherwise uninitialised
+    /// bytes, as reading them is undefined behaviour.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let a = debug::new(&[1_u32, 2, 3]);
+    /// let b = debug::new(&[1_u32, 2, 3]);
+    /// let c = debug::new(&[1_u32, 2, 4]);
+    ///
+    /// // SAFETY: `u32` has no padding
+    /// # assert!(unsafe { a.bytes_eq(&b) });
+    /// # assert!(!unsafe { a.bytes_eq(&c) });
+    /// println!("{}", unsafe { a.bytes_eq(&b) }); // true
+    /// println!("{}", unsafe { a.bytes_eq(&c) }); // false
+    /// ```
+    pub unsafe fn bytes_eq(&self, other: &DynSlice<Dyn>) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+        if self.is_empty() {
+            return true;
+        }
+        if self.vtable_ptr != other.vtable_ptr {
+            return false;
+        }
+        if self.data == other.data {
+            return true;
+        }
+
+        let metadata = transmute::<_, DynMetadata<Dyn>>(self.vtable_ptr());
+        let size = byte_offset_unchecked(metadata.size_of(), self.len);
+
+        slice::from_raw_parts(self.data.cast::<u8>(), size)
+            == slice::from_raw_parts(other.data.cast::<u8>(), size)
+    }
+
+    #[must_use]
+    /// Returns the bytes of the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// The number of bytes is the size of the underlying type, read from the metadata. This is
+    /// useful for checksumming, comparing or snapshotting individual elements.
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type has no padding or otherwise uninitialised
+    /// bytes, as reading them is undefined behaviour.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [0x0102_u16, 0x0304];
+    /// let slice = debug::new(&array);
+    ///
+    /// // SAFETY: `u16` has no padding
+    /// let bytes = unsafe { slice.element_bytes(1) }.unwrap();
+    /// assert_eq!(bytes, 0x0304_u16.to_ne_bytes());
+    /// assert!(unsafe { slice.element_bytes(2) }.is_none());
+    /// ```
+    pub unsafe fn element_bytes(&self, index: usize) -> Option<&[u8]> {
+        if index >= self.len {
+            return None;
+        }
+
+        let size = transmute::<_, DynMetadata<Dyn>>(self.vtable_ptr()).size_of();
+        Some(slice::from_raw_parts(
+            self.get_ptr_unchecked(index).cast::<u8>(),
+            size,
+        ))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'static, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Leak a boxed slice, returning a dyn slice that lives for the rest of the program.
+    ///
+    /// This is useful for registries and `OnceCell`-style initialization, where a dyn slice
+    /// without a borrowed lifetime is needed.
+    ///
+    /// # Example
+    /// ```
+    /// # use core::fmt::Debug;
+    /// use dyn_slice::DynSlice;
+    ///
+    /// let slice: DynSlice<'static, dyn Debug> = DynSlice::leak(vec![1, 2, 3].into_boxed_slice());
+    /// # assert_eq!(format!("{slice:?}"), "[1, 2, 3]");
+    /// println!("{slice:?}"); // [1, 2, 3]
+    /// ```
+    pub fn leak<T: Unsize<Dyn> + 'static>(value: Box<[T]>) -> Self {
+        let len = value.len();
+        // The raw pointer keeps the permissions of the box, unlike `Box::leak`, so the allocation
+        // can still be reclaimed with `Box::from_raw`
+        let data: *mut [T] = Box::into_raw(value);
+        let metadata = metadata_of::<T, Dyn>();
+
+        // SAFETY:
+        // The metadata is for `T` as `Dyn`, which is the type of the `len` elements at `data`.
+        // The allocation is never freed, so the data lives for the rest of the program.
+        unsafe { Self::from_parts_with_metadata(metadata, len, data.cast()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Returns the indices of the elements in the order that they would be in if the slice were
+    /// sorted with `compare`, without moving them.
+    ///
+    /// The sort is stable, so equal elements keep their relative order. The elements can then be
+    /// visited in sorted order with [`DynSlice::gather_iter`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::to;
+    ///
+    /// let slice = to::new::<u32, _>(&[30_u8, 10, 20, 10]);
+    ///
+    /// let order = slice.argsort_by(|a, b| a.to().cmp(&b.to()));
+    /// assert_eq!(order, [1, 3, 2, 0]);
+    /// ```
+    pub fn argsort_by<F: FnMut(&Dyn, &Dyn) -> Ordering>(&self, mut compare: F) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.len).collect();
+        // SAFETY:
+        // The indices are all less than `self.len`.
+        indices.sort_by(|&a, &b| unsafe { compare(self.get_unchecked(a), self.get_unchecked(b)) });
+        indices
+    }
+
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Returns the indices of the elements in the order that they would be in if the slice were
+    /// sorted by the keys extracted by `f`, without moving them.
+    ///
+    /// Like [`slice::sort_by_cached_key`](https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_cached_key),
+    /// `f` is called once per element. The sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[300, 1, 20]);
+    ///
+    /// let order = slice.argsort_by_cached_key(|x| format!("{x:?}"));
+    /// assert_eq!(order, [1, 2, 0]);
+    /// ```
+    pub fn argsort_by_cached_key<K: Ord, F: FnMut(&Dyn) -> K>(&self, f: F) -> Vec<usize> {
+        let mut keyed: Vec<(K, usize)> = self.iter().map(f).zip(0..).collect();
+        keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+        keyed.into_iter().map(|(_, index)| index).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "std")))]
+    /// Groups the elements by a key, without copying them.
+    ///
+    /// Consecutive elements with equal keys are borrowed as a single sub-slice, and each key maps
+    /// to the runs with that key, in the order that they appear.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::partial_eq;
+    ///
+    /// let array = [1, 3, 2, 4, 5];
+    /// let slice = partial_eq::new::<i32, _>(&array);
+    ///
+    /// let groups = slice.group_by_key(|x| [1, 3, 5].iter().any(|odd| x == odd));
+    /// assert_eq!(groups[&true].len(), 2);
+    /// assert!(groups[&true][0] == [1, 3][..]);
+    /// assert!(groups[&true][1] == [5][..]);
+    /// assert!(groups[&false][0] == [2, 4][..]);
+    /// ```
+    pub fn group_by_key<K: Eq + Hash, F: FnMut(&Dyn) -> K>(
+        &self,
+        mut key: F,
+    ) -> HashMap<K, Vec<DynSlice<Dyn>>> {
+        let mut groups: HashMap<K, Vec<DynSlice<Dyn>>> = HashMap::new();
+        let Some(first) = self.first() else {
+            return groups;
+        };
+
+        let mut current = key(first);
+        let mut start = 0;
+        for (i, element) in self.iter().enumerate().skip(1) {
+            let next = key(element);
+            if next != current {
+                // SAFETY:
+                // `start < i < self.len`, so the run is within the slice.
+                let run = unsafe { self.slice_unchecked(start, i - start) };
+                groups
+                    .entry(mem::replace(&mut current, next))
+                    .or_default()
+                    .push(run);
+                start = i;
+            }
+        }
+
+        // SAFETY:
+        // `start < self.len`, so the final run is within the slice.
+        let run = unsafe { self.slice_unchecked(start, self.len - start) };
+        groups.entry(current).or_default().push(run);
+
+        groups
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Index<usize> for DynSlice<'a, Dyn> {
@@ -535,7 +1471,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IntoIterator for Dy
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        Iter { slice: self }
+        Iter::new(self)
     }
 }
 
@@ -553,9 +1489,19 @@ impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IntoIterator
 
 #[cfg(test)]
 mod test {
-    use core::{fmt::Display, ptr::addr_of};
-
-    use crate::{declare_new_fns, standard::partial_eq, DynSlice};
+    use core::{
+        alloc::Layout,
+        cmp::Ordering,
+        fmt::{Debug, Display},
+        num::NonZeroUsize,
+        ptr::addr_of,
+    };
+
+    use crate::{
+        declare_new_fns,
+        standard::{debug, partial_eq, to, To},
+        DynSlice, DynSliceMut,
+    };
 
     declare_new_fns!(
         #[crate = crate]
@@ -563,6 +1509,145 @@ mod test {
     );
     pub use display_dyn_slice::new as new_display_dyn_slice;
 
+    pub trait AsDebug: Display {
+        fn as_debug(&self) -> &dyn Debug;
+    }
+    impl<T: Display + Debug> AsDebug for T {
+        fn as_debug(&self) -> &dyn Debug {
+            self
+        }
+    }
+    declare_new_fns!(
+        #[crate = crate]
+        as_debug AsDebug
+    );
+
+    #[test]
+    fn layout() {
+        let slice = debug::new(&[[0_u16; 3]; 4]);
+        assert_eq!(slice.element_layout(), Some(Layout::new::<[u16; 3]>()));
+        assert_eq!(slice.byte_len(), 24);
+        assert_eq!(slice.slice(1..3).unwrap().byte_len(), 12);
+
+        // Empty slices may not have metadata
+        let slice = debug::new::<u64>(&[]);
+        assert_eq!(slice.byte_len(), 0);
+
+        let null = core::ptr::null();
+        // SAFETY:
+        // A null vtable pointer is valid for an empty slice.
+        let slice = unsafe { DynSlice::<dyn Debug>::from_parts(null, 0, null) };
+        assert_eq!(slice.element_layout(), None);
+        assert_eq!(slice.byte_len(), 0);
+    }
+
+    #[test]
+    fn fold_prefetched() {
+        let array: [[u64; 16]; 5] = core::array::from_fn(|i| [i as u64; 16]);
+        let slice = debug::new(&array);
+
+        let mut visited = Vec::new();
+        let count = slice.fold_prefetched(0, |count, x| {
+            visited.push(format!("{x:?}"));
+            count + 1
+        });
+        assert_eq!(count, 5);
+        assert_eq!(
+            visited,
+            slice.iter().map(|x| format!("{x:?}")).collect::<Vec<_>>()
+        );
+
+        let empty = debug::new::<u8>(&[]);
+        assert_eq!(empty.fold_prefetched(7, |_, _| unreachable!()), 7);
+    }
+
+    #[test]
+    fn bytes_eq() {
+        let a: [u16; 3] = [1, 2, 3];
+        let b: [u16; 3] = [1, 2, 3];
+        let c: [u16; 3] = [1, 2, 4];
+        let da = new_display_dyn_slice(&a);
+        let db = new_display_dyn_slice(&b);
+        let dc = new_display_dyn_slice(&c);
+
+        // SAFETY:
+        // `u16` has no padding.
+        unsafe {
+            assert!(da.bytes_eq(&da));
+            assert!(da.bytes_eq(&db));
+            assert!(!da.bytes_eq(&dc));
+            assert!(!da.bytes_eq(&db.slice(1..).unwrap()));
+            assert!(da.slice(..2).unwrap().bytes_eq(&dc.slice(..2).unwrap()));
+
+            let empty = new_display_dyn_slice::<u8>(&[]);
+            assert!(empty.bytes_eq(&da.slice(..0).unwrap()));
+        }
+
+        // Elements of different types with the same bytes are not equal
+        let d: [i16; 3] = [1, 2, 3];
+        let dd = new_display_dyn_slice(&d);
+        // SAFETY:
+        // `u16` and `i16` have no padding.
+        assert!(!unsafe { da.bytes_eq(&dd) });
+    }
+
+    #[test]
+    fn retype_with() {
+        let array: [u8; 3] = [1, 2, 3];
+        let slice = as_debug::new(&array);
+
+        // SAFETY:
+        // `as_debug` returns its argument as `dyn Debug`.
+        let debug_slice = unsafe { slice.retype_with(AsDebug::as_debug) };
+        assert_eq!(debug_slice.len(), 3);
+        assert_eq!(debug_slice.as_ptr(), slice.as_ptr());
+        assert_eq!(format!("{debug_slice:?}"), "[1, 2, 3]");
+
+        let empty = as_debug::new::<u8>(&[]);
+        // SAFETY:
+        // The slice is empty, so the cast is not called.
+        let debug_empty = unsafe { empty.retype_with(|_| -> &dyn Debug { unreachable!() }) };
+        assert!(debug_empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cast does not return the element")]
+    fn retype_with_other() {
+        static OTHER: u64 = 0;
+
+        let slice = new_display_dyn_slice(&[1_u8, 2, 3]);
+        // SAFETY:
+        // The cast returns a different location, so this panics before the new slice is created.
+        let _ = unsafe { slice.retype_with(|_| &OTHER as &dyn Debug) };
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_by_key() {
+        let array = [1, 1, 2, 2, 2, 1, 3];
+        let slice = partial_eq::new::<i32, _>(&array);
+
+        let groups = slice.group_by_key(|x| {
+            (1..=3)
+                .find(|key| x == key)
+                .expect("element should be between 1 and 3")
+        });
+        assert_eq!(groups.len(), 3);
+
+        assert_eq!(groups[&1].len(), 2);
+        assert!(groups[&1][0] == [1, 1][..]);
+        assert!(groups[&1][1] == [1][..]);
+        assert!(groups[&1][0].as_ptr() == addr_of!(array[0]).cast());
+        assert!(groups[&1][1].as_ptr() == addr_of!(array[5]).cast());
+
+        assert_eq!(groups[&2].len(), 1);
+        assert!(groups[&2][0] == [2, 2, 2][..]);
+        assert!(groups[&3][0] == [3][..]);
+
+        let slice = partial_eq::new::<i32, i32>(&[]);
+        assert!(slice.group_by_key(|_| ()).is_empty());
+    }
+
     #[test]
     fn create_dyn_slice() {
         let array: [u8; 5] = [1, 2, 3, 4, 5];
@@ -595,6 +1680,21 @@ mod test {
         assert!(dyn_slice.is_empty());
     }
 
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Container<'a> {
+            values: DynSlice<'a, dyn Display>,
+            values_mut: DynSliceMut<'a, dyn Display>,
+        }
+
+        let container = Container::default();
+        assert!(container.values.is_empty());
+        assert!(container.values.iter().next().is_none());
+        assert_eq!(container.values.validate(), Ok(()));
+        assert!(container.values_mut.is_empty());
+    }
+
     #[test]
     fn test_slice() {
         let array = [1, 2, 3, 4, 5, 6, 7, 8, 9];
@@ -669,6 +1769,45 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn leak() {
+        let slice: DynSlice<'static, dyn PartialEq<u8>> = DynSlice::leak(Box::new([1, 2, 3]));
+        assert!(slice == [1, 2, 3].as_slice());
+        // SAFETY:
+        // The slice was leaked from a boxed `[u8]` of the same length, and is not used again, so
+        // the allocation can be reclaimed.
+        drop(unsafe {
+            Box::from_raw(core::ptr::slice_from_raw_parts_mut(
+                slice.as_ptr().cast::<u8>().cast_mut(),
+                slice.len(),
+            ))
+        });
+
+        let slice: DynSlice<'static, dyn PartialEq<u8>> = DynSlice::leak(Box::<[u8; 0]>::new([]));
+        assert!(slice.is_empty());
+        assert!(slice.metadata().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn argsort() {
+        let array = [3_u8, 1, 2, 1];
+        let slice = to::new::<u32, _>(&array);
+
+        let order = slice.argsort_by(|a, b| a.to().cmp(&b.to()));
+        assert_eq!(order, [1, 3, 2, 0]);
+        assert_eq!(
+            slice.argsort_by_cached_key(|x| u32::MAX - x.to()),
+            [0, 2, 1, 3]
+        );
+        assert_eq!(array, [3, 1, 2, 1]);
+
+        let empty = to::new::<u32, u8>(&[]);
+        assert!(empty.argsort_by(|a, b| a.to().cmp(&b.to())).is_empty());
+        assert!(empty.argsort_by_cached_key(To::to).is_empty());
+    }
+
     #[test]
     #[should_panic(expected = "index out of bounds")]
     fn index_empty() {
@@ -676,6 +1815,266 @@ mod test {
         println!("{}", &slice[0]);
     }
 
+    #[test]
+    fn is_sorted_by() {
+        let slice = to::new::<u32, _>(&[1_u8, 2, 2, 5]);
+        assert!(slice.is_sorted_by(|a, b| a.to() <= b.to()));
+        assert!(!slice.is_sorted_by(|a, b| a.to() < b.to()));
+        assert!(slice.is_sorted_by_key(To::to));
+        assert!(!slice.is_sorted_by_key(|x| u32::MAX - x.to()));
+
+        let empty = to::new::<u32, u8>(&[]);
+        assert!(empty.is_sorted_by(|_, _| false));
+        assert!(empty.is_sorted_by_key(To::to));
+        assert!(to::new::<u32, _>(&[1_u8]).is_sorted_by(|_, _| false));
+
+        let floats = to::new::<f64, _>(&[1.0_f32, f32::NAN, 3.0]);
+        assert!(!floats.is_sorted_by_key(To::to));
+    }
+
+    #[test]
+    fn max_min_by() {
+        fn cmp(a: &(dyn To<u32> + 'static), b: &(dyn To<u32> + 'static)) -> Ordering {
+            a.to().cmp(&b.to())
+        }
+
+        let slice = to::new::<u32, _>(&[3_u8, 1, 5, 1, 5]);
+        let (index, max) = slice.max_by(cmp).unwrap();
+        assert_eq!((index, max.to()), (4, 5));
+        let (index, min) = slice.min_by(cmp).unwrap();
+        assert_eq!((index, min.to()), (1, 1));
+
+        let empty = to::new::<u32, u8>(&[]);
+        assert!(empty.max_by(cmp).is_none());
+        assert!(empty.min_by(cmp).is_none());
+    }
+
+    #[test]
+    fn eq_cmp_by() {
+        let slice = to::new::<u32, _>(&[1_u8, 2, 3]);
+
+        assert!(slice.eq_by([1, 2, 3], |a, b| a.to() == b));
+        assert!(!slice.eq_by([1, 2], |a, b| a.to() == b));
+        assert!(!slice.eq_by([1, 2, 3, 4], |a, b| a.to() == b));
+        assert!(!slice.eq_by([1, 5, 3], |a, b| a.to() == b));
+
+        assert_eq!(
+            slice.cmp_by([1, 2, 3], |a, b| a.to().cmp(&b)),
+            Ordering::Equal
+        );
+        assert_eq!(slice.cmp_by([1, 3], |a, b| a.to().cmp(&b)), Ordering::Less);
+        assert_eq!(
+            slice.cmp_by([1, 2, 3, 0], |a, b| a.to().cmp(&b)),
+            Ordering::Less
+        );
+        assert_eq!(
+            slice.cmp_by([0, 9, 9], |a, b| a.to().cmp(&b)),
+            Ordering::Greater
+        );
+
+        let empty = to::new::<u32, u8>(&[]);
+        assert!(empty.eq_by(core::iter::empty::<u32>(), |_, _| false));
+        assert_eq!(empty.cmp_by([1], |a, b| a.to().cmp(&b)), Ordering::Less);
+    }
+
+    #[test]
+    fn fold_chunks() {
+        let array = [1, 2, 3, 4, 5, 6, 7];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        let lens = slice.fold_chunks(
+            NonZeroUsize::new(3).unwrap(),
+            Vec::new(),
+            |mut lens, chunk| {
+                lens.push(chunk.len());
+                lens
+            },
+        );
+        assert_eq!(lens, [3, 3, 1]);
+
+        let count = slice.fold_chunks(NonZeroUsize::new(7).unwrap(), 0, |count, chunk| {
+            assert!(chunk == array[..]);
+            count + 1
+        });
+        assert_eq!(count, 1);
+
+        let empty = partial_eq::new::<u8, u8>(&[]);
+        assert_eq!(
+            empty.fold_chunks(NonZeroUsize::MIN, 0, |count, _| count + 1),
+            0
+        );
+    }
+
+    #[test]
+    fn huge_zst() {
+        // SAFETY:
+        // `()` is zero-sized, so any number of them fit at a dangling pointer.
+        let array: &[()] = unsafe {
+            core::slice::from_raw_parts(core::ptr::NonNull::dangling().as_ptr(), usize::MAX)
+        };
+        let slice = debug::new(array);
+
+        assert_eq!(slice.len(), usize::MAX);
+        assert_eq!(slice.byte_len(), 0);
+        assert!(slice.get(usize::MAX - 1).is_some());
+        assert!(slice.get(usize::MAX).is_none());
+        assert!(slice.iter().nth(usize::MAX - 1).is_some());
+        assert_eq!(slice.iter().rev().nth(usize::MAX - 2).map(|_| ()), Some(()));
+        assert_eq!(slice.slice(1..).unwrap().len(), usize::MAX - 1);
+
+        let mut chunks = slice.chunks(usize::MAX / 2).unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.nth(2).unwrap().len(), 1);
+        assert_eq!(
+            slice.chunks(2).unwrap().nth(usize::MAX / 2).unwrap().len(),
+            1
+        );
+        assert!(slice.chunks(2).unwrap().nth(usize::MAX).is_none());
+        assert_eq!(slice.windows(usize::MAX).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn const_iterators() {
+        // These fail to compile if the constructors are not const
+        const fn iter<'a>(slice: &'a DynSlice<dyn Debug>) -> crate::Iter<'a, dyn Debug> {
+            slice.iter()
+        }
+        const fn chunks<'a>(
+            slice: &'a DynSlice<dyn Debug>,
+        ) -> Option<crate::iter::Chunks<'a, dyn Debug>> {
+            slice.chunks(2)
+        }
+        const fn rchunks<'a>(
+            slice: &'a DynSlice<dyn Debug>,
+        ) -> Option<crate::iter::RChunks<'a, dyn Debug>> {
+            slice.rchunks(2)
+        }
+        const fn windows<'a>(
+            slice: &'a DynSlice<dyn Debug>,
+        ) -> Option<crate::iter::Windows<'a, dyn Debug>> {
+            slice.windows_clamped(2)
+        }
+
+        let slice = debug::new(&[1_u16, 2, 3]);
+        assert_eq!(format!("{:?}", iter(&slice).nth(1)), "Some(2)");
+        assert_eq!(format!("{:?}", chunks(&slice).unwrap().nth(1)), "Some([3])");
+        assert_eq!(
+            format!("{:?}", rchunks(&slice).unwrap().next()),
+            "Some([2, 3])"
+        );
+        assert_eq!(
+            format!("{:?}", windows(&slice).unwrap().nth(1)),
+            "Some([2, 3])"
+        );
+    }
+
+    #[test]
+    fn large_stride() {
+        let array = [[0_u8; 4096]; 3];
+        let slice = debug::new(&array);
+
+        assert_eq!(slice.byte_len(), 3 * 4096);
+        assert!(slice.iter().nth(usize::MAX).is_none());
+        assert!(slice.get(usize::MAX).is_none());
+        assert!(slice.chunks(usize::MAX).unwrap().nth(1).is_none());
+        assert!(slice.chunks(2).unwrap().nth(usize::MAX).is_none());
+        assert!(slice.rchunks(2).unwrap().nth(usize::MAX / 2).is_none());
+        assert!(slice.windows(usize::MAX).unwrap().next().is_none());
+        assert!(slice.windows(1).unwrap().nth(usize::MAX / 4096).is_none());
+
+        // The number of elements fits in a `usize`, but not the number of bytes
+        assert!(slice.chunks(1 << 20).unwrap().nth(1 << 40).is_none());
+        assert!(slice.rchunks(1 << 20).unwrap().nth(1 << 40).is_none());
+        assert!(slice
+            .chunks(1)
+            .unwrap()
+            .nth_back(usize::MAX / 4096)
+            .is_none());
+        assert!(slice
+            .rchunks(1)
+            .unwrap()
+            .nth_back(usize::MAX / 4096)
+            .is_none());
+
+        let mut array = array;
+        let mut slice = debug::new_mut(&mut array);
+        assert!(slice.chunks_mut(1 << 20).unwrap().nth(1 << 40).is_none());
+        assert!(slice.rchunks_mut(1 << 20).unwrap().nth(1 << 40).is_none());
+        assert!(slice
+            .chunks_mut(1)
+            .unwrap()
+            .nth_back(usize::MAX / 4096)
+            .is_none());
+        assert!(slice
+            .rchunks_mut(1)
+            .unwrap()
+            .nth_back(usize::MAX / 4096)
+            .is_none());
+        assert_eq!(slice.rchunks_mut(2).unwrap().nth(1).unwrap().len(), 1);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn huge_stride() {
+        use crate::utils::checked_chunk_offset;
+
+        // Zero-sized elements only have their count checked
+        assert_eq!(
+            checked_chunk_offset(2, usize::MAX / 2, 0),
+            Some(usize::MAX - 1)
+        );
+        assert_eq!(checked_chunk_offset(2, usize::MAX, 0), None);
+        assert_eq!(
+            checked_chunk_offset(1, isize::MAX.unsigned_abs(), 1),
+            Some(isize::MAX.unsigned_abs())
+        );
+        assert_eq!(
+            checked_chunk_offset(1, isize::MAX.unsigned_abs() + 1, 1),
+            None
+        );
+        assert_eq!(checked_chunk_offset(1 << 20, 1 << 20, 1 << 23), None);
+
+        // An empty slice of elements of 1 TiB
+        let array: [[u8; 1 << 40]; 0] = [];
+        let slice = debug::new(&array);
+        assert_eq!(slice.byte_len(), 0);
+        assert!(slice.chunks(usize::MAX).unwrap().nth(usize::MAX).is_none());
+        assert!(slice.rchunks(1).unwrap().nth_back(usize::MAX).is_none());
+        assert!(slice.windows(1).unwrap().nth(usize::MAX).is_none());
+        assert!(slice.iter().nth(usize::MAX).is_none());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "slice is larger than isize::MAX bytes")]
+    fn too_large() {
+        let metadata = core::ptr::metadata(core::ptr::null::<[u8; 4096]>() as *const dyn Debug);
+
+        // SAFETY:
+        // This is not safe, but the debug assertion panics before the slice is created.
+        let _ = unsafe {
+            DynSlice::<dyn Debug>::from_parts_with_metadata(
+                metadata,
+                usize::MAX / 4096,
+                core::ptr::NonNull::<[u8; 4096]>::dangling().as_ptr().cast(),
+            )
+        };
+    }
+
+    #[test]
+    fn get_ref() {
+        let array = [1, 2, 3];
+        let (first, last) = {
+            let slice = new_display_dyn_slice::<u8>(&array);
+            (slice.get_ref(0).unwrap(), slice.get_ref(2).unwrap())
+        };
+        assert_eq!(format!("{first} {last}"), "1 3");
+
+        let slice = new_display_dyn_slice::<u8>(&array);
+        assert!(slice.get_ref(3).is_none());
+        assert!(new_display_dyn_slice::<u8>(&[]).get_ref(0).is_none());
+    }
+
     #[test]
     fn index() {
         let slice = new_display_dyn_slice::<u8>(&[1, 2, 3, 4]);
@@ -0,0 +1,164 @@
+use core::{
+    marker::{PhantomData, Unsize},
+    mem::{align_of, size_of},
+    ptr::{self, DynMetadata, Pointee},
+};
+
+use crate::{DynSlice, DynSliceMut};
+
+/// A builder for dyn slices of `T` as `Dyn`, for writing new functions without the
+/// [`declare_new_fns`](crate::declare_new_fns) macro.
+///
+/// The builder holds the metadata for `T` as `Dyn`, and checks it when created, so that building
+/// a dyn slice does not need `unsafe`.
+///
+/// # Example
+/// ```
+/// #![feature(ptr_metadata)]
+/// use std::fmt::Debug;
+/// use dyn_slice::{DynSlice, DynSliceBuilder};
+///
+/// pub trait Shape: Debug {
+///     fn area(&self) -> f64;
+/// }
+///
+/// #[derive(Debug)]
+/// struct Square(f64);
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// let squares = [Square(1.0), Square(2.0)];
+/// let slice: DynSlice<dyn Shape> = DynSliceBuilder::with_example(&squares[0]).build(&squares);
+///
+/// let total: f64 = slice.iter().map(Shape::area).sum();
+/// # assert_eq!(total, 5.0);
+/// println!("{total}"); // 5
+/// ```
+pub struct DynSliceBuilder<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, T> {
+    metadata: DynMetadata<Dyn>,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, T> DynSliceBuilder<Dyn, T> {
+    #[inline]
+    #[must_use]
+    /// Create a builder using the metadata of `example` as `Dyn`.
+    pub fn with_example(example: &T) -> Self
+    where
+        T: Unsize<Dyn>,
+    {
+        Self {
+            metadata: ptr::metadata(example as &Dyn),
+            phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    /// Create a builder from `metadata`.
+    ///
+    /// # Safety
+    /// The caller must ensure that `metadata` is the metadata of a `T` as `Dyn`.
+    ///
+    /// # Panics
+    /// Panics if the size or alignment in `metadata` is not that of `T`, which catches most
+    /// mismatched metadata.
+    pub unsafe fn with_metadata(metadata: DynMetadata<Dyn>) -> Self {
+        assert!(
+            metadata.size_of() == size_of::<T>(),
+            "metadata size does not match the size of the type"
+        );
+        assert!(
+            metadata.align_of() == align_of::<T>(),
+            "metadata alignment does not match the alignment of the type"
+        );
+
+        Self {
+            metadata,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the metadata that the builder uses.
+    pub const fn metadata(&self) -> DynMetadata<Dyn> {
+        self.metadata
+    }
+
+    #[inline]
+    #[must_use]
+    /// Builds a dyn slice from `value`.
+    pub const fn build<'a>(&self, value: &'a [T]) -> DynSlice<'a, Dyn> {
+        // SAFETY:
+        // The constructors ensure that the metadata is for `T` as `Dyn`.
+        unsafe { DynSlice::with_metadata(value, self.metadata) }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Builds a mutable dyn slice from `value`.
+    pub fn build_mut<'a>(&self, value: &'a mut [T]) -> DynSliceMut<'a, Dyn> {
+        // SAFETY:
+        // The constructors ensure that the metadata is for `T` as `Dyn`.
+        unsafe { DynSliceMut::with_metadata(value, self.metadata) }
+    }
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, T> Clone for DynSliceBuilder<Dyn, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, T> Copy for DynSliceBuilder<Dyn, T> {}
+
+#[cfg(test)]
+mod test {
+    use core::{fmt::Debug, ptr};
+
+    use super::DynSliceBuilder;
+    use crate::standard::add_assign;
+
+    #[test]
+    fn with_example() {
+        let mut array = [1_u8, 2, 3];
+        let builder = DynSliceBuilder::<add_assign::Dyn<u8>, u8>::with_example(&0);
+
+        let mut slice = builder.build_mut(&mut array);
+        slice.iter_mut().for_each(|x| *x += 1);
+        assert_eq!(builder.build(&array).len(), 3);
+        assert_eq!(array, [2, 3, 4]);
+
+        assert!(builder.build(&[]).is_empty());
+    }
+
+    #[test]
+    fn with_metadata() {
+        let metadata = ptr::metadata(&0_u16 as &dyn Debug);
+        // SAFETY:
+        // `metadata` is for `u16` as `dyn Debug`.
+        let builder = unsafe { DynSliceBuilder::<dyn Debug, u16>::with_metadata(metadata) };
+        assert_eq!(format!("{:?}", builder.build(&[1, 2])), "[1, 2]");
+    }
+
+    #[test]
+    #[should_panic(expected = "metadata size does not match the size of the type")]
+    fn with_metadata_wrong_size() {
+        let metadata = ptr::metadata(&0_u16 as &dyn Debug);
+        // SAFETY:
+        // This panics before the builder is created.
+        let _ = unsafe { DynSliceBuilder::<dyn Debug, u32>::with_metadata(metadata) };
+    }
+
+    #[test]
+    #[should_panic(expected = "metadata alignment does not match the alignment of the type")]
+    fn with_metadata_wrong_align() {
+        let metadata = ptr::metadata(&0_u16 as &dyn Debug);
+        // SAFETY:
+        // This panics before the builder is created.
+        let _ = unsafe { DynSliceBuilder::<dyn Debug, [u8; 2]>::with_metadata(metadata) };
+    }
+}
@@ -0,0 +1,264 @@
+use core::{
+    marker::{PhantomData, Unsize},
+    mem::transmute,
+    ptr::{self, DynMetadata, Pointee},
+};
+
+use crate::{utils::metadata_of, DynSliceMut};
+
+/// A mutable dyn slice whose elements can be swapped through shared references, like
+/// [`Cell::as_slice_of_cells`](core::cell::Cell::as_slice_of_cells).
+///
+/// References to the elements are never given out, so elements can only be moved around, using
+/// [`DynSliceCells::swap`] and the methods on [`DynCellRef`]. This means that index-based
+/// permutation algorithms can hold several handles to elements at once.
+///
+/// Elements of the same cells always have the same underlying type, so handles from them can be
+/// swapped with each other.
+///
+/// This is created by [`DynSliceMut::into_cells`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::debug;
+///
+/// let mut array = [1, 2, 3, 4];
+/// let cells = debug::new_mut(&mut array).into_cells();
+///
+/// let first = cells.get(0).unwrap();
+/// let last = cells.get(3).unwrap();
+/// // SAFETY: both elements are from the same cells
+/// assert!(unsafe { first.swap(&last) });
+/// cells.swap(1, 2);
+///
+/// let slice = cells.into_dyn_slice_mut();
+/// # assert_eq!(format!("{slice:?}"), "[4, 3, 2, 1]");
+/// println!("{slice:?}"); // [4, 3, 2, 1]
+/// ```
+pub struct DynSliceCells<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSliceMut<'a, Dyn>,
+}
+
+/// A handle to an element of a [`DynSliceCells`], like `&Cell<T>`.
+///
+/// This is created by [`DynSliceCells::get`].
+pub struct DynCellRef<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    data: *mut (),
+    metadata: DynMetadata<Dyn>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Converts the slice into a [`DynSliceCells`], which allows elements to be swapped through
+    /// shared references.
+    pub const fn into_cells(self) -> DynSliceCells<'a, Dyn> {
+        DynSliceCells { slice: self }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceCells<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in the slice.
+    pub const fn len(&self) -> usize {
+        self.slice.0.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the slice has a length of 0.
+    pub const fn is_empty(&self) -> bool {
+        self.slice.0.len == 0
+    }
+
+    #[must_use]
+    /// Returns a handle to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<DynCellRef<'_, Dyn>> {
+        (index < self.len()).then(|| {
+            // SAFETY:
+            // `index < self.len()`, so the slice is not empty, and has a valid vtable pointer.
+            // The pointer comes from the exclusive borrow held by `self`, so it can be written to.
+            unsafe {
+                DynCellRef {
+                    data: self.slice.0.get_ptr_unchecked(index).cast_mut(),
+                    metadata: transmute::<_, DynMetadata<Dyn>>(self.slice.0.vtable_ptr()),
+                    phantom: PhantomData,
+                }
+            }
+        })
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&self, a: usize, b: usize) {
+        let (Some(a), Some(b)) = (self.get(a), self.get(b)) else {
+            panic!("index out of bounds");
+        };
+
+        // SAFETY:
+        // Both elements are from this slice, so have the same underlying type.
+        let swapped = unsafe { a.swap(&b) };
+        debug_assert!(swapped, "[dyn-slice] elements have different types!");
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the cells back into a [`DynSliceMut`].
+    pub const fn into_dyn_slice_mut(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynCellRef<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the metadata component of the element's pointer.
+    pub const fn metadata(&self) -> DynMetadata<Dyn> {
+        self.metadata
+    }
+
+    #[must_use]
+    /// Swaps this element with `other`.
+    ///
+    /// The elements are only swapped if their vtables are the same, and `false` is returned
+    /// otherwise. Note that the same type may have more than one vtable.
+    ///
+    /// # Safety
+    /// The caller must ensure that, if the vtables are the same, both elements have the same
+    /// underlying type. This is always the case for elements from the same [`DynSliceCells`].
+    ///
+    /// Identical vtables may be merged for different types, so a matching vtable does not prove
+    /// that the types are the same.
+    pub unsafe fn swap(&self, other: &DynCellRef<'_, Dyn>) -> bool {
+        if self.metadata != other.metadata {
+            return false;
+        }
+
+        if self.data != other.data {
+            // SAFETY:
+            // The caller guarantees that both elements have the same underlying type, and so the
+            // same size.
+            // Each `DynSliceCells` holds an exclusive borrow, so distinct elements of the same
+            // type do not overlap. No references to the elements are given out, so the data is
+            // not aliased.
+            ptr::swap_nonoverlapping(
+                self.data.cast::<u8>(),
+                other.data.cast::<u8>(),
+                self.metadata.size_of(),
+            );
+        }
+
+        true
+    }
+
+    #[must_use]
+    /// Replaces this element with `value`, leaving the old element in `value`.
+    ///
+    /// If the vtable for `T` is not that of the element, nothing is replaced and `false` is
+    /// returned. Note that the same type may have more than one vtable, so this can fail even if
+    /// `T` is the underlying type.
+    ///
+    /// # Safety
+    /// The caller must ensure that, if the vtable for `T` is that of the element, `T` is the
+    /// underlying type of the element.
+    ///
+    /// Identical vtables may be merged for different types, so a matching vtable does not prove
+    /// that the types are the same.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let mut array = [1, 2, 3];
+    /// let cells = debug::new_mut(&mut array).into_cells();
+    ///
+    /// let mut value = 10;
+    /// // SAFETY: the underlying type is `i32`
+    /// assert!(unsafe { cells.get(1).unwrap().replace_with(&mut value) });
+    /// assert_eq!(value, 2);
+    /// # assert_eq!(array, [1, 10, 3]);
+    /// ```
+    pub unsafe fn replace_with<T: Unsize<Dyn>>(&self, value: &mut T) -> bool {
+        if metadata_of::<T, Dyn>() != self.metadata {
+            return false;
+        }
+
+        // SAFETY:
+        // The caller guarantees that `T` is the underlying type. `value` is an exclusive
+        // reference, so it does not overlap with the element, which is exclusively borrowed by
+        // its `DynSliceCells`.
+        ptr::swap_nonoverlapping(self.data.cast::<T>(), value, 1);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::{debug, partial_eq};
+
+    #[test]
+    fn swap() {
+        let mut array = [1, 2, 3, 4, 5];
+        let cells = partial_eq::new_mut::<u8, u8>(&mut array).into_cells();
+        assert_eq!(cells.len(), 5);
+        assert!(!cells.is_empty());
+        assert!(cells.get(5).is_none());
+
+        // Reverse the slice using handles
+        let handles: Vec<_> = (0..cells.len()).map(|i| cells.get(i).unwrap()).collect();
+        for i in 0..handles.len() / 2 {
+            // SAFETY:
+            // Both elements are from the same cells.
+            assert!(unsafe { handles[i].swap(&handles[handles.len() - 1 - i]) });
+        }
+        cells.swap(0, 0);
+        cells.swap(1, 3);
+
+        assert!(cells.into_dyn_slice_mut() == [5, 2, 3, 4, 1][..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn swap_out_of_bounds() {
+        let mut array = [1, 2];
+        let cells = partial_eq::new_mut::<u8, u8>(&mut array).into_cells();
+        cells.swap(0, 2);
+    }
+
+    #[test]
+    fn different_types() {
+        let mut a = [1_u8, 2];
+        let mut b = [3_u16, 4];
+        let a_cells = debug::new_mut(&mut a).into_cells();
+        let b_cells = debug::new_mut(&mut b).into_cells();
+
+        // SAFETY:
+        // The vtables of `u8` and `u16` as `dyn Debug` differ.
+        assert!(!unsafe { a_cells.get(0).unwrap().swap(&b_cells.get(0).unwrap()) });
+        // SAFETY:
+        // The vtables of `u8` and `u16` as `dyn Debug` differ.
+        assert!(!unsafe { a_cells.get(1).unwrap().replace_with(&mut 5_u16) });
+
+        assert_eq!(a, [1, 2]);
+        assert_eq!(b, [3, 4]);
+    }
+
+    #[test]
+    fn between_slices() {
+        let mut a = [1, 2];
+        let mut b = [3, 4];
+        let a_cells = partial_eq::new_mut::<u8, u8>(&mut a).into_cells();
+        let b_cells = partial_eq::new_mut::<u8, u8>(&mut b).into_cells();
+
+        // SAFETY:
+        // Both elements are `u8`s.
+        assert!(unsafe { a_cells.get(1).unwrap().swap(&b_cells.get(0).unwrap()) });
+        assert_eq!(a, [1, 3]);
+        assert_eq!(b, [2, 4]);
+    }
+}
@@ -0,0 +1,493 @@
+use core::{
+    fmt::{self, Debug},
+    iter::Chain,
+    num::NonZeroUsize,
+    ops::{Index, IndexMut},
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{
+    iter::{ChainChunks, ChainWindows},
+    utils::extend_lifetime,
+    DynSlice, DynSliceMut, Iter, IterMut,
+};
+
+/// A view over two dyn slices, as if they were one slice, without copying them.
+///
+/// This is useful when the data is stored in two segments, such as the two halves of a ring
+/// buffer. The slices can have different underlying types.
+///
+/// This is created by [`DynSliceChain::new`] or [`DynSlice::chain`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::debug, DynSliceChain};
+///
+/// let a = [1, 2, 3];
+/// let b = ["four", "five"];
+/// let chain = DynSliceChain::new(debug::new(&a), debug::new(&b));
+///
+/// assert_eq!(chain.len(), 5);
+/// # assert_eq!(format!("{:?}", &chain[3]), r#""four""#);
+/// println!("{:?}", &chain[3]); // "four"
+/// # assert_eq!(format!("{chain:?}"), r#"[1, 2, 3, "four", "five"]"#);
+/// println!("{chain:?}"); // [1, 2, 3, "four", "five"]
+/// ```
+pub struct DynSliceChain<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    front: DynSlice<'a, Dyn>,
+    back: DynSlice<'a, Dyn>,
+}
+
+/// A mutable view over two dyn slices, as if they were one slice, without copying them.
+///
+/// This is created by [`DynSliceMut::chain_mut`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::any;
+///
+/// let mut a = [1_u8, 2];
+/// let mut b = [3_u8];
+/// let mut chain = any::new_mut(&mut a).chain_mut(any::new_mut(&mut b));
+///
+/// for element in chain.iter_mut() {
+///     *element.downcast_mut::<u8>().unwrap() *= 10;
+/// }
+/// assert_eq!((a, b), ([10, 20], [30]));
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct DynSliceChainMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    front: DynSliceMut<'a, Dyn>,
+    back: DynSliceMut<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns a view over this slice followed by `back`, as if they were one slice.
+    pub const fn chain(self, back: Self) -> DynSliceChain<'a, Dyn> {
+        DynSliceChain::new(self, back)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Converts this slice and `back` into a mutable view over both, as if they were one slice.
+    pub const fn chain_mut(self, back: Self) -> DynSliceChainMut<'a, Dyn> {
+        DynSliceChainMut::new(self, back)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DynSliceChain<'a, Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for DynSliceChain<'a, Dyn> {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceChain<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a view over `front` followed by `back`.
+    pub const fn new(front: DynSlice<'a, Dyn>, back: DynSlice<'a, Dyn>) -> Self {
+        Self { front, back }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the two slices that make up the view.
+    pub const fn as_slices(&self) -> (DynSlice<'a, Dyn>, DynSlice<'a, Dyn>) {
+        (self.front, self.back)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in both slices.
+    pub const fn len(&self) -> usize {
+        self.front.len + self.back.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if both slices are empty.
+    pub const fn is_empty(&self) -> bool {
+        self.front.len == 0 && self.back.len == 0
+    }
+
+    #[must_use]
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Dyn> {
+        if index < self.front.len {
+            self.front.get(index)
+        } else {
+            self.back.get(index - self.front.len)
+        }
+    }
+
+    #[must_use]
+    /// Divides the view into two at `mid`, or returns `None` if `mid > self.len()`.
+    ///
+    /// The first view contains indices from `0..mid`, and the second from `mid..self.len()`.
+    pub fn split_at(&self, mid: usize) -> Option<(Self, Self)> {
+        (mid <= self.len()).then(|| {
+            // SAFETY:
+            // `mid <= self.len()` is checked above.
+            unsafe { self.split_at_unchecked(mid) }
+        })
+    }
+
+    #[must_use]
+    /// Divides the view into two at `mid`, without doing bounds checking.
+    ///
+    /// # Safety
+    /// The caller must ensure that `mid <= self.len()`.
+    pub unsafe fn split_at_unchecked(&self, mid: usize) -> (Self, Self) {
+        debug_assert!(mid <= self.len(), "[dyn-slice] mid is greater than length!");
+
+        let (front, back) = (self.front, self.back);
+        // The parts are subslices of `front` and `back`, so they can have their lifetime
+        if mid < front.len {
+            // The split is in the front slice, so the first view ends with an empty back slice
+            let (first, second) = front.split_at_unchecked(mid);
+            let (empty, _) = back.split_at_unchecked(0);
+            (
+                Self::new(extend_lifetime(first), extend_lifetime(empty)),
+                Self::new(extend_lifetime(second), back),
+            )
+        } else {
+            // The split is in the back slice, so the second view starts with an empty front slice
+            let (_, empty) = front.split_at_unchecked(front.len);
+            let (first, second) = back.split_at_unchecked(mid - front.len);
+            (
+                Self::new(front, extend_lifetime(first)),
+                Self::new(extend_lifetime(empty), extend_lifetime(second)),
+            )
+        }
+    }
+
+    #[inline]
+    /// Returns an iterator over the elements of both slices.
+    pub fn iter(&self) -> Chain<Iter<'a, Dyn>, Iter<'a, Dyn>> {
+        self.front.into_iter().chain(self.back)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over chunks of the view of length `chunk_size`.
+    ///
+    /// Chunks can span both slices. If `chunk_size` does not exactly divide the length, the last
+    /// chunk will be shorter.
+    pub const fn chunks_non_zero(&self, chunk_size: NonZeroUsize) -> ChainChunks<'a, Dyn> {
+        ChainChunks {
+            chain: *self,
+            chunk_size,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over chunks of the view of length `chunk_size`.
+    ///
+    /// If `chunk_size` is 0, this will return [`None`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::debug, DynSliceChain};
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = [4, 5];
+    /// let chain = DynSliceChain::new(debug::new(&a), debug::new(&b));
+    ///
+    /// let chunks: Vec<_> = chain.chunks(2).unwrap().map(|c| format!("{c:?}")).collect();
+    /// assert_eq!(chunks, ["[1, 2]", "[3, 4]", "[5]"]);
+    /// ```
+    pub fn chunks(&self, chunk_size: usize) -> Option<ChainChunks<'a, Dyn>> {
+        NonZeroUsize::new(chunk_size).map(|cs| self.chunks_non_zero(cs))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over overlapping views of length `window_size`.
+    ///
+    /// Windows can span both slices.
+    pub const fn windows_non_zero(&self, window_size: NonZeroUsize) -> ChainWindows<'a, Dyn> {
+        ChainWindows {
+            chain: *self,
+            window_size,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over overlapping views of length `window_size`.
+    ///
+    /// If `window_size` is 0, this will return [`None`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::debug, DynSliceChain};
+    ///
+    /// let a = [1, 2];
+    /// let b = [3];
+    /// let chain = DynSliceChain::new(debug::new(&a), debug::new(&b));
+    ///
+    /// let windows: Vec<_> = chain.windows(2).unwrap().map(|w| format!("{w:?}")).collect();
+    /// assert_eq!(windows, ["[1, 2]", "[2, 3]"]);
+    /// ```
+    pub fn windows(&self, window_size: usize) -> Option<ChainWindows<'a, Dyn>> {
+        NonZeroUsize::new(window_size).map(|ws| self.windows_non_zero(ws))
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Index<usize>
+    for DynSliceChain<'a, Dyn>
+{
+    type Output = Dyn;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IntoIterator
+    for DynSliceChain<'a, Dyn>
+{
+    type IntoIter = Chain<Iter<'a, Dyn>, Iter<'a, Dyn>>;
+    type Item = &'a Dyn;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug
+    for DynSliceChain<'a, Dyn>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceChainMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a mutable view over `front` followed by `back`.
+    pub const fn new(front: DynSliceMut<'a, Dyn>, back: DynSliceMut<'a, Dyn>) -> Self {
+        Self { front, back }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns an immutable view over both slices.
+    pub const fn as_chain(&self) -> DynSliceChain<'_, Dyn> {
+        DynSliceChain::new(self.front.0, self.back.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the two slices that make up the view.
+    pub fn as_mut_slices(&mut self) -> (DynSliceMut<'_, Dyn>, DynSliceMut<'_, Dyn>) {
+        // SAFETY:
+        // The created slices are from index 0 and have the same lengths as the original slices,
+        // so must be valid.
+        unsafe {
+            (
+                self.front.slice_unchecked_mut(0, self.front.len),
+                self.back.slice_unchecked_mut(0, self.back.len),
+            )
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the view back into the two slices that make it up.
+    pub const fn into_slices(self) -> (DynSliceMut<'a, Dyn>, DynSliceMut<'a, Dyn>) {
+        (self.front, self.back)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in both slices.
+    pub const fn len(&self) -> usize {
+        self.front.0.len + self.back.0.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if both slices are empty.
+    pub const fn is_empty(&self) -> bool {
+        self.front.0.len == 0 && self.back.0.len == 0
+    }
+
+    #[must_use]
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Dyn> {
+        if index < self.front.len {
+            self.front.get(index)
+        } else {
+            self.back.get(index - self.front.len)
+        }
+    }
+
+    #[must_use]
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Dyn> {
+        match index.checked_sub(self.front.len) {
+            None => self.front.get_mut(index),
+            Some(index) => self.back.get_mut(index),
+        }
+    }
+
+    #[inline]
+    /// Returns an iterator over the elements of both slices.
+    pub fn iter(&self) -> Chain<Iter<'_, Dyn>, Iter<'_, Dyn>> {
+        self.front.iter().chain(self.back.iter())
+    }
+
+    #[inline]
+    /// Returns a mutable iterator over the elements of both slices.
+    pub fn iter_mut(&mut self) -> Chain<IterMut<'_, Dyn>, IterMut<'_, Dyn>> {
+        self.front.iter_mut().chain(self.back.iter_mut())
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Index<usize>
+    for DynSliceChainMut<'a, Dyn>
+{
+    type Output = Dyn;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IndexMut<usize>
+    for DynSliceChainMut<'a, Dyn>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug
+    for DynSliceChainMut<'a, Dyn>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_chain().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DynSliceChain;
+    use crate::standard::{any, partial_eq};
+
+    const SPLITS: [(&[u8], &[u8]); 5] = [
+        (&[], &[]),
+        (&[1, 2, 3], &[]),
+        (&[], &[1, 2, 3]),
+        (&[1, 2], &[3, 4, 5]),
+        (&[1, 2, 3, 4], &[5]),
+    ];
+
+    fn chain_eq(chain: DynSliceChain<dyn PartialEq<u8>>, expected: &[u8]) -> bool {
+        chain.len() == expected.len() && chain.iter().zip(expected).all(|(a, b)| a == b)
+    }
+
+    #[test]
+    fn get() {
+        for (front, back) in SPLITS {
+            let expected: Vec<u8> = front.iter().chain(back).copied().collect();
+            let chain = DynSliceChain::new(partial_eq::new(front), partial_eq::new(back));
+            assert!(chain_eq(chain, &expected));
+            assert_eq!(chain.is_empty(), expected.is_empty());
+
+            for (index, value) in expected.iter().enumerate() {
+                assert!(chain[index] == *value);
+            }
+            assert!(chain.get(expected.len()).is_none());
+            assert!(chain
+                .iter()
+                .rev()
+                .zip(expected.iter().rev())
+                .all(|(a, b)| a == b));
+
+            for mid in 0..=expected.len() {
+                let (left, right) = chain.split_at(mid).unwrap();
+                assert!(chain_eq(left, &expected[..mid]));
+                assert!(chain_eq(right, &expected[mid..]));
+            }
+            assert!(chain.split_at(expected.len() + 1).is_none());
+        }
+    }
+
+    #[test]
+    fn chunks_and_windows() {
+        for (front, back) in SPLITS {
+            let expected: Vec<u8> = front.iter().chain(back).copied().collect();
+            let chain = DynSliceChain::new(partial_eq::new(front), partial_eq::new(back));
+
+            for size in 1..=6 {
+                let chunks = chain.chunks(size).unwrap();
+                assert_eq!(chunks.len(), expected.chunks(size).len());
+                assert!(chunks
+                    .zip(expected.chunks(size))
+                    .all(|(chunk, expected)| chain_eq(chunk, expected)));
+                assert!(chain
+                    .chunks(size)
+                    .unwrap()
+                    .rev()
+                    .zip(expected.chunks(size).rev())
+                    .all(|(chunk, expected)| chain_eq(chunk, expected)));
+
+                let windows = chain.windows(size).unwrap();
+                assert_eq!(windows.len(), expected.windows(size).len());
+                assert!(windows
+                    .zip(expected.windows(size))
+                    .all(|(window, expected)| chain_eq(window, expected)));
+                assert!(chain
+                    .windows(size)
+                    .unwrap()
+                    .rev()
+                    .zip(expected.windows(size).rev())
+                    .all(|(window, expected)| chain_eq(window, expected)));
+            }
+            assert!(chain.chunks(0).is_none());
+            assert!(chain.windows(0).is_none());
+        }
+    }
+
+    #[test]
+    fn chain() {
+        let a = [1_u8, 2];
+        let b = [3_u8];
+        // The chain borrows the arrays, not the temporary slices it was created from
+        let chain = partial_eq::new(&a).chain(partial_eq::new(&b));
+        assert!(chain_eq(chain, &[1, 2, 3]));
+    }
+
+    #[test]
+    fn chain_mut() {
+        let mut a = [1_u8, 2];
+        let mut b = [3_u16];
+        let mut chain = any::new_mut(&mut a).chain_mut(any::new_mut(&mut b));
+        assert_eq!(chain.len(), 3);
+
+        *chain[1].downcast_mut::<u8>().unwrap() = 20;
+        *chain.get_mut(2).unwrap().downcast_mut::<u16>().unwrap() = 30;
+        assert!(chain.get_mut(3).is_none());
+        assert!(chain.as_chain()[2].is::<u16>());
+
+        let (front, back) = chain.as_mut_slices();
+        assert_eq!((front.len(), back.len()), (2, 1));
+        assert_eq!(chain.iter_mut().count(), 3);
+        assert_eq!(chain.iter().count(), 3);
+
+        let (front, back) = chain.into_slices();
+        assert_eq!(front.downcast::<u8>(), Some(&[1, 20][..]));
+        assert_eq!(back.downcast::<u16>(), Some(&[30][..]));
+    }
+}
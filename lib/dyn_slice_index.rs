@@ -0,0 +1,238 @@
+use core::{
+    mem::transmute,
+    ops::{
+        Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+    },
+    ptr::{self, DynMetadata, Pointee},
+};
+
+use crate::{DynSlice, DynSliceMut};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A type that can be used to index into a [`DynSlice`] or [`DynSliceMut`], like
+/// [`SliceIndex`](core::slice::SliceIndex) for slices.
+///
+/// This is implemented for [`usize`], which gives a reference to an element, and for the range
+/// types, which give a sub-slice.
+///
+/// This trait is sealed, so cannot be implemented outside of this crate.
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::debug;
+///
+/// let slice = debug::new(&[1, 2, 3, 4, 5]);
+/// # assert_eq!(format!("{:?}", slice.get(1).unwrap()), "2");
+/// println!("{:?}", slice.get(1)); // Some(2)
+/// # assert_eq!(format!("{:?}", slice.get(1..3).unwrap()), "[2, 3]");
+/// println!("{:?}", slice.get(1..3)); // Some([2, 3])
+/// ```
+pub trait DynSliceIndex<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>>:
+    sealed::Sealed
+{
+    /// The type returned when indexing a [`DynSlice`].
+    type Output<'a>
+    where
+        Dyn: 'a;
+    /// The type returned when indexing a [`DynSliceMut`].
+    type OutputMut<'a>
+    where
+        Dyn: 'a;
+
+    /// Returns the output at this location, or `None` if it is out of bounds.
+    fn get<'a>(self, slice: &'a DynSlice<Dyn>) -> Option<Self::Output<'a>>;
+
+    /// Returns the output at this location, without doing bounds checking.
+    ///
+    /// # Safety
+    /// The caller must ensure that the location is in bounds.
+    unsafe fn get_unchecked<'a>(self, slice: &'a DynSlice<Dyn>) -> Self::Output<'a>;
+
+    /// Returns the mutable output at this location, or `None` if it is out of bounds.
+    fn get_mut<'a>(self, slice: &'a mut DynSliceMut<Dyn>) -> Option<Self::OutputMut<'a>>;
+
+    /// Returns the mutable output at this location, without doing bounds checking.
+    ///
+    /// # Safety
+    /// The caller must ensure that the location is in bounds.
+    unsafe fn get_unchecked_mut<'a>(self, slice: &'a mut DynSliceMut<Dyn>) -> Self::OutputMut<'a>;
+}
+
+impl sealed::Sealed for usize {}
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceIndex<Dyn> for usize {
+    type Output<'a> = &'a Dyn where Dyn: 'a;
+    type OutputMut<'a> = &'a mut Dyn where Dyn: 'a;
+
+    #[inline]
+    fn get<'a>(self, slice: &'a DynSlice<Dyn>) -> Option<Self::Output<'a>> {
+        (self < slice.len).then(|| {
+            // SAFETY:
+            // The above inequality ensures that the index is less than the
+            // length, and is therefore valid. This also ensures that the slice
+            // has a valid vtable pointer because the slice guaranteed to not be empty.
+            unsafe { self.get_unchecked(slice) }
+        })
+    }
+
+    #[inline]
+    unsafe fn get_unchecked<'a>(self, slice: &'a DynSlice<Dyn>) -> Self::Output<'a> {
+        debug_assert!(
+            self < slice.len,
+            "[dyn-slice] index is greater than or equal to length!"
+        );
+
+        let metadata = transmute::<_, DynMetadata<Dyn>>(slice.vtable_ptr());
+        let data = slice.get_ptr_unchecked(self);
+        &*ptr::from_raw_parts::<Dyn>(data, metadata)
+    }
+
+    #[inline]
+    fn get_mut<'a>(self, slice: &'a mut DynSliceMut<Dyn>) -> Option<Self::OutputMut<'a>> {
+        (self < slice.len).then(|| {
+            // SAFETY:
+            // The above inequality ensures that the index is less than the
+            // length, and is therefore valid. This also ensures that the slice
+            // has a valid vtable pointer because the slice guaranteed to not be empty.
+            unsafe { self.get_unchecked_mut(slice) }
+        })
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut<'a>(self, slice: &'a mut DynSliceMut<Dyn>) -> Self::OutputMut<'a> {
+        debug_assert!(
+            self < slice.len,
+            "[dyn-slice] index is greater than or equal to length!"
+        );
+
+        let metadata = transmute::<_, DynMetadata<Dyn>>(slice.vtable_ptr());
+        let data = slice.get_ptr_unchecked(self).cast_mut();
+        &mut *ptr::from_raw_parts_mut::<Dyn>(data, metadata)
+    }
+}
+
+/// Returns the start and length of a range, without checking that it is valid.
+fn unchecked_start_len<R: RangeBounds<usize>>(range: &R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i + 1,
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+
+    (start, end - start)
+}
+
+macro_rules! impl_range_index {
+    ( $( $range:ty ),* $(,)? ) => {
+        $(
+            impl sealed::Sealed for $range {}
+            impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceIndex<Dyn> for $range {
+                type Output<'a> = DynSlice<'a, Dyn> where Dyn: 'a;
+                type OutputMut<'a> = DynSliceMut<'a, Dyn> where Dyn: 'a;
+
+                #[inline]
+                fn get<'a>(self, slice: &'a DynSlice<Dyn>) -> Option<Self::Output<'a>> {
+                    slice.slice(self)
+                }
+
+                #[inline]
+                unsafe fn get_unchecked<'a>(self, slice: &'a DynSlice<Dyn>) -> Self::Output<'a> {
+                    let (start, len) = unchecked_start_len(&self, slice.len);
+                    slice.slice_unchecked(start, len)
+                }
+
+                #[inline]
+                fn get_mut<'a>(self, slice: &'a mut DynSliceMut<Dyn>) -> Option<Self::OutputMut<'a>> {
+                    slice.slice_mut(self)
+                }
+
+                #[inline]
+                unsafe fn get_unchecked_mut<'a>(
+                    self,
+                    slice: &'a mut DynSliceMut<Dyn>,
+                ) -> Self::OutputMut<'a> {
+                    let (start, len) = unchecked_start_len(&self, slice.len);
+                    slice.slice_unchecked_mut(start, len)
+                }
+            }
+        )*
+    };
+}
+impl_range_index!(
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    RangeFull,
+    (Bound<usize>, Bound<usize>),
+);
+
+#[cfg(test)]
+mod test {
+    use core::ops::Bound;
+
+    use crate::standard::{add_assign, partial_eq};
+
+    #[test]
+    fn get() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        assert!(slice.get(1).unwrap() == &2);
+        assert!(slice.get(5).is_none());
+        assert!(slice.get(1..3).unwrap() == [2, 3][..]);
+        assert!(slice.get(1..=3).unwrap() == [2, 3, 4][..]);
+        assert!(slice.get(3..).unwrap() == [4, 5][..]);
+        assert!(slice.get(..2).unwrap() == [1, 2][..]);
+        assert!(slice.get(..=1).unwrap() == [1, 2][..]);
+        assert!(slice.get(..).unwrap() == array[..]);
+        assert!(slice.get((Bound::Excluded(0), Bound::Included(1))).unwrap() == [2][..]);
+        assert!(slice.get(4..6).is_none());
+        assert!(slice.get(6..).is_none());
+    }
+
+    #[test]
+    fn get_unchecked() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        // SAFETY:
+        // All of the indices are in bounds.
+        unsafe {
+            assert!(slice.get_unchecked(4) == &5);
+            assert!(slice.get_unchecked(1..3) == [2, 3][..]);
+            assert!(slice.get_unchecked(..=1) == [1, 2][..]);
+            assert!(slice.get_unchecked(5..).is_empty());
+        }
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut array = [1, 2, 3, 4, 5];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+
+        *slice.get_mut(0).unwrap() += 10;
+        slice
+            .get_mut(2..)
+            .unwrap()
+            .iter_mut()
+            .for_each(|x| *x += 20);
+        assert!(slice.get_mut(2..6).is_none());
+        // SAFETY:
+        // The range is in bounds.
+        unsafe { slice.get_unchecked_mut(1..2) }
+            .iter_mut()
+            .for_each(|x| *x += 30);
+
+        assert_eq!(array, [11, 32, 23, 24, 25]);
+    }
+}
@@ -1,5 +1,9 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::marker::Unsize;
 use core::{
-    mem::transmute,
+    mem::{self, transmute},
     num::NonZeroUsize,
     ops::{Bound, Deref, Index, IndexMut, RangeBounds},
     ptr::{self, DynMetadata, Pointee},
@@ -7,8 +11,12 @@ use core::{
 };
 
 use crate::{
-    iter::{ChunksMut, RChunksMut},
-    DynSlice, Iter, IterMut,
+    iter::{
+        ArrayChunksMut, ChunksMut, RChunksMut, RSplitMut, RSplitNMut, SplitInclusiveMut, SplitMut,
+        SplitNMut,
+    },
+    utils::{byte_offset_unchecked, extend_lifetime_mut, metadata_of},
+    DynSlice, DynSliceIndex, Iter, IterMut,
 };
 
 /// `&mut dyn [Trait]`
@@ -38,6 +46,23 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> AsRef<DynSlice<'a,
     }
 }
 
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> From<DynSliceMut<'a, Dyn>>
+    for DynSlice<'a, Dyn>
+{
+    #[inline]
+    fn from(value: DynSliceMut<'a, Dyn>) -> Self {
+        value.into_immutable()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Default for DynSliceMut<'a, Dyn> {
+    #[inline]
+    /// Returns an empty mutable dyn slice without metadata, like [`DynSliceMut::empty`].
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Deref for DynSliceMut<'a, Dyn> {
     type Target = DynSlice<'a, Dyn>;
 
@@ -83,6 +108,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     /// - `vtable_ptr` is a valid instance of `DynMetadata` transmuted, or optionally, a null pointer if `len == 0`,
     /// - `len` <= the length of the slice in memory from the `data` pointer,
     /// - `data` is a valid pointer to the slice,
+    /// - the total size of the slice, `len` elements, is at most `isize::MAX` bytes,
     /// - the underlying slice is the same layout as [`[T]`](https://doc.rust-lang.org/reference/type-layout.html#slice-layout)
     pub const unsafe fn from_parts(vtable_ptr: *const (), len: usize, data: *mut ()) -> Self {
         Self(DynSlice::from_parts(vtable_ptr, len, data))
@@ -97,13 +123,36 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     /// - `metadata` is a valid instance of `DynMetadata`,
     /// - `len` <= the length of the slice in memory from the `data` pointer,
     /// - `data` is a valid pointer to the slice,
+    /// - the total size of the slice, `len` elements, is at most `isize::MAX` bytes,
     /// - the underlying slice is the same layout as [`[T]`](https://doc.rust-lang.org/reference/type-layout.html#slice-layout)
-    pub const unsafe fn from_parts_with_metadata(
+    ///
+    /// # Panics
+    /// In debug builds, panics if [`DynSlice::validate`] finds a problem with the raw parts.
+    pub unsafe fn from_parts_with_metadata(
         metadata: DynMetadata<Dyn>,
         len: usize,
         data: *mut (),
     ) -> Self {
-        Self::from_parts(transmute(metadata), len, data)
+        Self(DynSlice::from_parts_with_metadata(metadata, len, data))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct an empty mutable dyn slice without metadata.
+    ///
+    /// See [`DynSlice::empty`].
+    pub const fn empty() -> Self {
+        Self(DynSlice::empty())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct an empty mutable dyn slice with `metadata`, so that the slice still has an
+    /// underlying type.
+    ///
+    /// See [`DynSlice::empty_with_metadata`].
+    pub fn empty_with_metadata(metadata: DynMetadata<Dyn>) -> Self {
+        Self(DynSlice::empty_with_metadata(metadata))
     }
 
     #[inline]
@@ -179,9 +228,69 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
         })
     }
 
+    #[must_use]
+    /// Returns a mutable reference to the first element of the slice and a mutable slice of the
+    /// rest of the elements, or `None` if it is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// let (first, rest) = slice.split_first_mut().unwrap();
+    /// *first += 10;
+    /// rest.into_iter().for_each(|x| *x += 20);
+    /// assert_eq!(array, [11, 22, 23, 24, 25]);
+    /// ```
+    pub fn split_first_mut(&mut self) -> Option<(&mut Dyn, DynSliceMut<Dyn>)> {
+        (!self.0.is_empty()).then(|| {
+            // SAFETY:
+            // The above statement ensures that slice is not empty, so 1 is a valid split point.
+            // The two halves do not overlap, so the first element can be borrowed separately from
+            // the rest.
+            unsafe {
+                let (first, rest) = self.split_at_unchecked_mut(1);
+                (first.into_iter().next().unwrap_unchecked(), rest)
+            }
+        })
+    }
+
+    #[must_use]
+    /// Returns a mutable reference to the last element of the slice and a mutable slice of the
+    /// rest of the elements, or `None` if it is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// let (last, rest) = slice.split_last_mut().unwrap();
+    /// *last += 10;
+    /// rest.into_iter().for_each(|x| *x += 20);
+    /// assert_eq!(array, [21, 22, 23, 24, 15]);
+    /// ```
+    pub fn split_last_mut(&mut self) -> Option<(&mut Dyn, DynSliceMut<Dyn>)> {
+        (!self.0.is_empty()).then(|| {
+            // SAFETY:
+            // The above statement ensures that slice is not empty, so len - 1 is a valid split
+            // point. The two halves do not overlap, so the last element can be borrowed
+            // separately from the rest.
+            unsafe {
+                let (rest, last) = self.split_at_unchecked_mut(self.0.len - 1);
+                (last.into_iter().next().unwrap_unchecked(), rest)
+            }
+        })
+    }
+
     #[must_use]
     /// Returns a mutable reference to the element at the given `index` or `None` if the `index` is out of bounds.
     ///
+    /// If `index` is a range, this returns a mutable sub-slice instead, like [`DynSliceMut::slice_mut`].
+    ///
     /// # Example
     /// ```
     /// use dyn_slice::standard::add_assign;
@@ -192,32 +301,23 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     /// *slice.get_mut(2).unwrap() += 10;
     /// assert_eq!(array, [1, 2, 13, 4, 5]);
     /// ```
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut Dyn> {
-        (index < self.0.len).then(|| {
-            // SAFETY:
-            // The above inequality ensures that the index is less than the
-            // length, and is therefore valid. This also ensures that the slice
-            // has a valid vtable pointer because the slice guaranteed to not be empty.
-            unsafe { self.get_unchecked_mut(index) }
-        })
+    pub fn get_mut<I: DynSliceIndex<Dyn>>(&mut self, index: I) -> Option<I::OutputMut<'_>> {
+        index.get_mut(self)
     }
 
     #[inline]
     #[must_use]
-    /// Returns a mutable reference to the element at the given `index`, without doing bounds checking.
+    /// Returns a mutable reference to the element, or mutable sub-slice, at the given `index`,
+    /// without doing bounds checking.
     ///
     /// # Safety
-    /// The caller must ensure that `index < self.len()`
-    /// Calling this on an empty dyn Slice will result in a segfault!
-    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut Dyn {
-        debug_assert!(
-            index < self.len,
-            "[dyn-slice] index is greater than or equal to length!"
-        );
-
-        let metadata = transmute::<_, DynMetadata<Dyn>>(self.0.vtable_ptr());
-        let data = self.0.get_ptr_unchecked(index).cast_mut();
-        &mut *ptr::from_raw_parts_mut::<Dyn>(data, metadata)
+    /// The caller must ensure that `index` is in bounds.
+    /// Calling this with a `usize` on an empty dyn Slice will result in a segfault!
+    pub unsafe fn get_unchecked_mut<I: DynSliceIndex<Dyn>>(
+        &mut self,
+        index: I,
+    ) -> I::OutputMut<'_> {
+        index.get_unchecked_mut(self)
     }
 
     #[inline]
@@ -310,10 +410,13 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
 
     #[inline]
     #[must_use]
-    /// Splits the mutable slice into two mutable slices at the index `mid`, without doing bounds checking .
+    /// Splits the mutable slice into two mutable slices at the index `mid`, without doing bounds checking.
     ///
     /// The first slice contains indices from `0..mid`, and the second from `mid..self.len()`.
     ///
+    /// See [`DynSliceMut::into_split_at_unchecked_mut`] for a version that keeps the original
+    /// lifetime.
+    ///
     /// # Safety
     /// The caller must ensure that `mid <= self.len()`.
     pub unsafe fn split_at_unchecked_mut(
@@ -336,6 +439,306 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
         )
     }
 
+    #[inline]
+    #[must_use]
+    /// Splits the mutable slice at `mid` using `size` as the size of the underlying type, without
+    /// doing bounds checking or reading the size from the vtable.
+    ///
+    /// # Safety
+    /// The caller must ensure that `mid <= self.len()`, and that `size` is the size of the
+    /// underlying type (or that the slice is empty).
+    pub(crate) unsafe fn split_at_with_size_unchecked_mut(
+        &mut self,
+        mid: usize,
+        size: usize,
+    ) -> (DynSliceMut<Dyn>, DynSliceMut<Dyn>) {
+        let (first, second) = self.0.split_at_with_size_unchecked(mid, size);
+        (DynSliceMut(first), DynSliceMut(second))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Splits the mutable slice into two mutable slices at the index `mid`, consuming it so that
+    /// both parts keep the original lifetime.
+    ///
+    /// The first slice contains indices from `0..mid`, and the second from `mid..self.len()`.
+    ///
+    /// If `mid > self.len()`, [`None`] is returned.
+    ///
+    /// This is the building block for custom mutable iterators, as the parts can outlive the
+    /// borrow of the iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use core::ops::AddAssign;
+    /// use dyn_slice::{standard::add_assign, DynSliceMut};
+    ///
+    /// /// Iterator over pairs of elements
+    /// struct Pairs<'a> {
+    ///     slice: Option<DynSliceMut<'a, dyn AddAssign<u8>>>,
+    /// }
+    ///
+    /// impl<'a> Iterator for Pairs<'a> {
+    ///     type Item = DynSliceMut<'a, dyn AddAssign<u8>>;
+    ///
+    ///     fn next(&mut self) -> Option<Self::Item> {
+    ///         let (pair, remaining) = self.slice.take()?.into_split_at_mut(2)?;
+    ///         self.slice = Some(remaining);
+    ///         Some(pair)
+    ///     }
+    /// }
+    ///
+    /// let mut array: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let pairs = Pairs {
+    ///     slice: Some(add_assign::new_mut(&mut array)),
+    /// };
+    ///
+    /// for (i, mut pair) in (0..).zip(pairs) {
+    ///     pair[0] += i * 10;
+    ///     pair[1] += i * 10;
+    /// }
+    /// assert_eq!(array, [1, 2, 13, 14, 5]);
+    /// ```
+    pub fn into_split_at_mut(
+        self,
+        mid: usize,
+    ) -> Option<(DynSliceMut<'a, Dyn>, DynSliceMut<'a, Dyn>)> {
+        (mid <= self.0.len()).then(|| {
+            // SAFETY:
+            // `mid <= length` is checked above, so is a valid split point.
+            unsafe { self.into_split_at_unchecked_mut(mid) }
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    /// Splits the mutable slice into two mutable slices at the index `mid`, consuming it so that
+    /// both parts keep the original lifetime, without doing bounds checking.
+    ///
+    /// The first slice contains indices from `0..mid`, and the second from `mid..self.len()`.
+    ///
+    /// # Safety
+    /// The caller must ensure that `mid <= self.len()`.
+    pub unsafe fn into_split_at_unchecked_mut(
+        mut self,
+        mid: usize,
+    ) -> (DynSliceMut<'a, Dyn>, DynSliceMut<'a, Dyn>) {
+        let (first, second) = self.split_at_unchecked_mut(mid);
+        // SAFETY:
+        // `self` is consumed, so the parts are the only references to the data, and can have
+        // its lifetime.
+        (extend_lifetime_mut(first), extend_lifetime_mut(second))
+    }
+
+    /// Swaps the elements at indices `a` and `b`, without doing bounds checking.
+    ///
+    /// # Safety
+    /// The caller must ensure that `a < self.len()` and `b < self.len()`.
+    pub(crate) unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        debug_assert!(
+            a < self.len && b < self.len,
+            "[dyn-slice] index is greater than or equal to length!"
+        );
+
+        if a != b {
+            let size = transmute::<_, DynMetadata<Dyn>>(self.0.vtable_ptr()).size_of();
+            let a = self.0.get_ptr_unchecked(a).cast_mut().cast::<u8>();
+            let b = self.0.get_ptr_unchecked(b).cast_mut().cast::<u8>();
+            ptr::swap_nonoverlapping(a, b, size);
+        }
+    }
+
+    #[must_use]
+    /// Moves all but the first of consecutive elements that `same_bucket` considers equal to the
+    /// end of the slice, like [`slice::partition_dedup_by`](https://doc.rust-lang.org/std/primitive.slice.html#method.partition_dedup_by).
+    ///
+    /// `same_bucket` is called with the current element and the last element that was kept.
+    ///
+    /// Returns the deduplicated elements, in their original order, and the duplicates, in no
+    /// particular order.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let mut array = [1_u8, 1, 2, 3, 3, 3, 1];
+    /// let mut slice = any::new_mut(&mut array);
+    ///
+    /// let (unique, duplicates) =
+    ///     slice.partition_dedup_by(|a, b| a.downcast_ref::<u8>() == b.downcast_ref::<u8>());
+    /// assert_eq!(unique.len(), 4);
+    /// assert_eq!(duplicates.len(), 3);
+    /// assert_eq!(array[..4], [1, 2, 3, 1]);
+    /// ```
+    pub fn partition_dedup_by<F: FnMut(&mut Dyn, &mut Dyn) -> bool>(
+        &mut self,
+        mut same_bucket: F,
+    ) -> (DynSliceMut<Dyn>, DynSliceMut<Dyn>) {
+        let len = self.len;
+        let mut next_write = 1.min(len);
+
+        for next_read in 1..len {
+            // SAFETY:
+            // Both indices are less than the length, and `next_write - 1 < next_read`, so the
+            // elements are distinct and the mutable references do not alias.
+            // The slice is not empty, so has a valid vtable pointer.
+            let is_duplicate = unsafe {
+                let metadata = transmute::<_, DynMetadata<Dyn>>(self.0.vtable_ptr());
+                let read = ptr::from_raw_parts_mut::<Dyn>(
+                    self.0.get_ptr_unchecked(next_read).cast_mut(),
+                    metadata,
+                );
+                let previous = ptr::from_raw_parts_mut::<Dyn>(
+                    self.0.get_ptr_unchecked(next_write - 1).cast_mut(),
+                    metadata,
+                );
+
+                same_bucket(&mut *read, &mut *previous)
+            };
+
+            if !is_duplicate {
+                // SAFETY:
+                // `next_write <= next_read < len`, so both indices are in bounds.
+                unsafe { self.swap_unchecked(next_read, next_write) };
+                next_write += 1;
+            }
+        }
+
+        // SAFETY:
+        // `next_write <= len`, so is a valid split point.
+        unsafe { self.split_at_unchecked_mut(next_write) }
+    }
+
+    /// Calls `f` on each run of consecutive elements with equal keys, with the key and the run as a
+    /// mutable slice.
+    ///
+    /// `key` is called once per element, and the runs are visited in order. As the runs are
+    /// disjoint, each one can be mutated freely.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let mut array = [1_u8, 3, 2, 4, 6, 5];
+    /// let mut slice = any::new_mut(&mut array);
+    ///
+    /// slice.for_each_group_mut(
+    ///     |x| x.downcast_ref::<u8>().unwrap() % 2 == 0,
+    ///     |even, mut run| {
+    ///         if even {
+    ///             run.downcast_mut::<u8>().unwrap().fill(0);
+    ///         }
+    ///     },
+    /// );
+    /// assert_eq!(array, [1, 3, 0, 0, 0, 5]);
+    /// ```
+    pub fn for_each_group_mut<K, FK, F>(&mut self, mut key: FK, mut f: F)
+    where
+        K: PartialEq,
+        FK: FnMut(&Dyn) -> K,
+        F: FnMut(K, DynSliceMut<Dyn>),
+    {
+        let Some(first) = self.first() else {
+            return;
+        };
+
+        let mut current = key(first);
+        let mut start = 0;
+        for i in 1..self.len {
+            // SAFETY:
+            // `i < self.len`.
+            let next = key(unsafe { self.0.get_unchecked(i) });
+            if next != current {
+                // SAFETY:
+                // `start < i < self.len`, so the run is within the slice.
+                let run = unsafe { self.slice_unchecked_mut(start, i - start) };
+                f(mem::replace(&mut current, next), run);
+                start = i;
+            }
+        }
+
+        // SAFETY:
+        // `start < self.len`, so the final run is within the slice.
+        let run = unsafe { self.slice_unchecked_mut(start, self.len - start) };
+        f(current, run);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    /// Splits the mutable slice into multiple disjoint mutable slices at each of the `indices`.
+    ///
+    /// The indices are sorted first, so they may be given in any order, and there is always one
+    /// more slice than there are indices. Repeated indices give empty slices.
+    ///
+    /// If any index is greater than `self.len()`, [`None`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// let parts = slice.split_mut_at_each(&[3, 1]).unwrap();
+    /// assert_eq!(parts.len(), 3);
+    /// for (i, part) in (0..).zip(parts) {
+    ///     part.into_iter().for_each(|x| *x += i * 10);
+    /// }
+    /// assert_eq!(array, [1, 12, 13, 24, 25]);
+    /// ```
+    pub fn split_mut_at_each(&mut self, indices: &[usize]) -> Option<Vec<DynSliceMut<'_, Dyn>>> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        if indices.last().is_some_and(|&index| index > self.len()) {
+            return None;
+        }
+
+        let mut parts = Vec::with_capacity(indices.len() + 1);
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        let mut remaining = unsafe { self.slice_unchecked_mut(0, self.len) };
+        let mut start = 0;
+
+        for index in indices {
+            // SAFETY:
+            // The indices are sorted and are at most `self.len()`, and `remaining` starts at
+            // `start`, so `index - start <= remaining.len()`.
+            let (part, rest) = unsafe { remaining.into_split_at_unchecked_mut(index - start) };
+            parts.push(part);
+            remaining = rest;
+            start = index;
+        }
+        parts.push(remaining);
+
+        Some(parts)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the mutable slice into an immutable slice, consuming it so that the immutable
+    /// slice keeps the original lifetime.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::add_assign, DynSlice};
+    ///
+    /// fn increment<'a>(
+    ///     mut slice: add_assign::SliceMut<'a, u8>,
+    /// ) -> add_assign::Slice<'a, u8> {
+    ///     slice.iter_mut().for_each(|x| *x += 1);
+    ///     slice.into_immutable()
+    /// }
+    ///
+    /// let mut array = [1_u8, 2, 3];
+    /// let slice = increment(add_assign::new_mut(&mut array));
+    /// assert_eq!(slice.len(), 3);
+    /// ```
+    pub const fn into_immutable(self) -> DynSlice<'a, Dyn> {
+        self.0
+    }
+
     #[inline]
     #[must_use]
     /// Returns a mutable iterator over the slice.
@@ -351,12 +754,10 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     /// assert_eq!(array, [11, 12, 13, 14, 15]);
     /// ```
     pub fn iter_mut(&mut self) -> IterMut<'_, Dyn> {
-        IterMut {
-            // SAFETY:
-            // The created slice is from index 0 and has the same length as the
-            // original slice, so must be valid.
-            slice: unsafe { self.slice_unchecked_mut(0, self.len) },
-        }
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        IterMut::new(unsafe { self.slice_unchecked_mut(0, self.len) })
     }
 
     #[must_use]
@@ -366,6 +767,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     /// If `chunk_size` does not exactly divide the length, the last chunk will be shorter.
     pub fn chunks_mut_non_zero(&mut self, chunk_size: NonZeroUsize) -> ChunksMut<'_, Dyn> {
         ChunksMut {
+            size: self.element_size(),
             // SAFETY:
             // This creates copy of the slice with an inferior lifetime.
             slice: unsafe {
@@ -392,6 +794,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     /// If `chunk_size` does not exactly divide the length, the last chunk will be shorter.
     pub fn rchunks_mut_non_zero(&mut self, chunk_size: NonZeroUsize) -> RChunksMut<'_, Dyn> {
         RChunksMut {
+            size: self.element_size(),
             // SAFETY:
             // This creates copy of the slice with an inferior lifetime.
             slice: unsafe {
@@ -410,6 +813,320 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn
     pub fn rchunks_mut(&mut self, chunk_size: usize) -> Option<RChunksMut<'_, Dyn>> {
         NonZeroUsize::new(chunk_size).map(|cs| self.rchunks_mut_non_zero(cs))
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over mutable subslices separated by elements that match `pred`, like
+    /// [`slice::split_mut`](https://doc.rust-lang.org/std/primitive.slice.html#method.split_mut).
+    ///
+    /// The matched elements are not included in the subslices.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let mut array = [1_u8, 0, 2, 3, 0, 4];
+    /// let mut slice = any::new_mut(&mut array);
+    ///
+    /// for mut split in slice.split_mut(|x| x.downcast_ref() == Some(&0_u8)) {
+    ///     if let Some(first) = split.first_mut() {
+    ///         *first.downcast_mut::<u8>().unwrap() = 9;
+    ///     }
+    /// }
+    /// assert_eq!(array, [9, 0, 9, 3, 0, 9]);
+    /// ```
+    pub fn split_mut<P: FnMut(&Dyn) -> bool>(&mut self, pred: P) -> SplitMut<'_, Dyn, P> {
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        let slice = unsafe { self.slice_unchecked_mut(0, self.len) };
+        SplitMut::new(slice, pred)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over mutable subslices separated by elements that match `pred`,
+    /// starting from the end of the slice, like
+    /// [`slice::rsplit_mut`](https://doc.rust-lang.org/std/primitive.slice.html#method.rsplit_mut).
+    ///
+    /// The matched elements are not included in the subslices.
+    pub fn rsplit_mut<P: FnMut(&Dyn) -> bool>(&mut self, pred: P) -> RSplitMut<'_, Dyn, P> {
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        let slice = unsafe { self.slice_unchecked_mut(0, self.len) };
+        RSplitMut {
+            inner: SplitMut::new(slice, pred),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over at most `n` mutable subslices separated by elements that match
+    /// `pred`, like [`slice::splitn_mut`](https://doc.rust-lang.org/std/primitive.slice.html#method.splitn_mut).
+    ///
+    /// The last subslice is the rest of the slice, which may contain matching elements.
+    pub fn splitn_mut<P: FnMut(&Dyn) -> bool>(
+        &mut self,
+        n: usize,
+        pred: P,
+    ) -> SplitNMut<'_, Dyn, P> {
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        let slice = unsafe { self.slice_unchecked_mut(0, self.len) };
+        SplitNMut {
+            inner: SplitMut::new(slice, pred),
+            count: n,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over at most `n` mutable subslices separated by elements that match
+    /// `pred`, starting from the end of the slice, like
+    /// [`slice::rsplitn_mut`](https://doc.rust-lang.org/std/primitive.slice.html#method.rsplitn_mut).
+    ///
+    /// The last subslice is the rest of the slice, which may contain matching elements.
+    pub fn rsplitn_mut<P: FnMut(&Dyn) -> bool>(
+        &mut self,
+        n: usize,
+        pred: P,
+    ) -> RSplitNMut<'_, Dyn, P> {
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        let slice = unsafe { self.slice_unchecked_mut(0, self.len) };
+        RSplitNMut {
+            inner: SplitMut::new(slice, pred),
+            count: n,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns an iterator over mutable subslices separated by elements that match `pred`, like
+    /// [`slice::split_inclusive_mut`](https://doc.rust-lang.org/std/primitive.slice.html#method.split_inclusive_mut).
+    ///
+    /// Each matched element is kept at the end of the subslice before it. If the last element
+    /// matches, no empty subslice is yielded after it.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let mut array = [1_u8, 0, 2, 3, 0];
+    /// let mut slice = any::new_mut(&mut array);
+    ///
+    /// for mut split in slice.split_inclusive_mut(|x| x.downcast_ref() == Some(&0_u8)) {
+    ///     let len = split.len() as u8;
+    ///     *split.first_mut().unwrap().downcast_mut::<u8>().unwrap() = len;
+    /// }
+    /// assert_eq!(array, [2, 0, 3, 3, 0]);
+    /// ```
+    pub fn split_inclusive_mut<P: FnMut(&Dyn) -> bool>(
+        &mut self,
+        pred: P,
+    ) -> SplitInclusiveMut<'_, Dyn, P> {
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        SplitInclusiveMut::new(unsafe { self.slice_unchecked_mut(0, self.len) }, pred)
+    }
+
+    #[must_use]
+    /// Returns an iterator over chunks of the slice as arrays of `N` mutable references.
+    ///
+    /// If `N` does not exactly divide the length, the last elements are not yielded, and can be
+    /// retrieved with [`ArrayChunksMut::into_remainder`].
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// for [a, b] in slice.chunks_exact_mut_arrays() {
+    ///     *a += 10;
+    ///     *b += 20;
+    /// }
+    /// assert_eq!(array, [11, 22, 13, 24, 5]);
+    /// ```
+    pub fn chunks_exact_mut_arrays<const N: usize>(&mut self) -> ArrayChunksMut<'_, Dyn, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let len = self.len() - self.len() % N;
+        // SAFETY:
+        // `len <= self.len()`, so is a valid split point.
+        let (chunks, remainder) = unsafe { self.split_at_unchecked_mut(len) };
+
+        ArrayChunksMut {
+            iter: IterMut::new(chunks),
+            remainder,
+        }
+    }
+
+    /// Drops the element at `index` in place, then calls `write` with a pointer to it, so that a new
+    /// value can be written.
+    ///
+    /// The element is dropped through the vtable, so its destructor runs as it would for the
+    /// underlying type.
+    ///
+    /// # Safety
+    /// The caller must ensure that `write` writes a valid value of the underlying type to the pointer,
+    /// and that it does not unwind, as the old element has already been dropped.
+    ///
+    /// # Panics
+    /// Panics if `index >= self.len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let mut array = [String::from("a"), String::from("b")];
+    /// let mut slice = debug::new_mut(&mut array);
+    ///
+    /// // SAFETY: the underlying type is `String`, and writing cannot unwind
+    /// unsafe { slice.replace_raw(1, |ptr| ptr.cast::<String>().write(String::from("c"))) };
+    /// assert_eq!(array, ["a", "c"]);
+    /// ```
+    pub unsafe fn replace_raw<F: FnOnce(*mut ())>(&mut self, index: usize, write: F) {
+        let element: *mut Dyn = self.index_mut(index);
+
+        ptr::drop_in_place(element);
+        write(element.cast());
+    }
+
+    /// Copies the elements in the `src` range to the range starting at `dest`, by copying their
+    /// bytes, like [`slice::copy_within`](https://doc.rust-lang.org/std/primitive.slice.html#method.copy_within).
+    ///
+    /// The ranges may overlap. The overwritten elements are not dropped.
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type can be duplicated by copying its bytes,
+    /// for example, if it implements [`Copy`].
+    ///
+    /// # Panics
+    /// Panics if `src` is out of bounds, or if `dest` is more than `self.len()` minus the length of
+    /// `src`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = debug::new_mut(&mut array);
+    ///
+    /// // SAFETY: the underlying type, `i32`, is `Copy`
+    /// unsafe { slice.copy_within(1..4, 0) };
+    /// assert_eq!(array, [2, 3, 4, 4, 5]);
+    /// ```
+    pub unsafe fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
+        let src = self.0.slice(src).expect("src is out of bounds");
+        assert!(dest <= self.len() - src.len(), "dest is out of bounds");
+
+        if let Some(metadata) = src.metadata() {
+            ptr::copy(
+                src.as_ptr().cast::<u8>(),
+                self.0.get_ptr_unchecked(dest).cast_mut().cast::<u8>(),
+                byte_offset_unchecked(metadata.size_of(), src.len()),
+            );
+        }
+    }
+
+    #[must_use]
+    /// Returns the bytes of the element at `index` mutably, or `None` if it is out of bounds.
+    ///
+    /// The number of bytes is the size of the underlying type, read from the metadata.
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type has no padding or otherwise uninitialised
+    /// bytes, as reading them is undefined behaviour, and that any bytes written make a valid
+    /// value of the underlying type.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let mut array = [1_u16, 2];
+    /// let mut slice = debug::new_mut(&mut array);
+    ///
+    /// // SAFETY: `u16` has no padding, and any bytes make a valid `u16`
+    /// let bytes = unsafe { slice.element_bytes_mut(0) }.unwrap();
+    /// bytes.copy_from_slice(&7_u16.to_ne_bytes());
+    /// assert_eq!(array, [7, 2]);
+    /// ```
+    pub unsafe fn element_bytes_mut(&mut self, index: usize) -> Option<&mut [u8]> {
+        if index >= self.len {
+            return None;
+        }
+
+        let size = transmute::<_, DynMetadata<Dyn>>(self.vtable_ptr()).size_of();
+        Some(slice::from_raw_parts_mut(
+            self.0.get_ptr_unchecked(index).cast_mut().cast::<u8>(),
+            size,
+        ))
+    }
+
+    /// Calls one function from `src` on each element of the slice, in order, returning the number of
+    /// functions that were applied.
+    ///
+    /// This stops when either the slice or `src` runs out.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// let applied = slice.write_each((1..=3).map(|n| move |x: &mut add_assign::Dyn<i32>| *x += n));
+    /// assert_eq!(applied, 3);
+    /// assert_eq!(array, [2, 4, 6, 4, 5]);
+    /// ```
+    pub fn write_each<F, I>(&mut self, src: I) -> usize
+    where
+        F: FnOnce(&mut Dyn),
+        I: IntoIterator<Item = F>,
+    {
+        let mut applied = 0;
+        for (element, f) in self.iter_mut().zip(src) {
+            f(element);
+            applied += 1;
+        }
+
+        applied
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'static, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Leak a boxed slice, returning a mutable dyn slice that lives for the rest of the program.
+    ///
+    /// # Example
+    /// ```
+    /// # use core::ops::AddAssign;
+    /// use dyn_slice::DynSliceMut;
+    ///
+    /// let mut slice: DynSliceMut<'static, dyn AddAssign<u8>> =
+    ///     DynSliceMut::leak(vec![1u8, 2, 3].into_boxed_slice());
+    /// slice.iter_mut().for_each(|x| *x += 10);
+    /// # assert_eq!(unsafe { slice.downcast_unchecked_mut::<u8>() }, [11, 12, 13]);
+    /// ```
+    pub fn leak<T: Unsize<Dyn> + 'static>(value: Box<[T]>) -> Self {
+        let value: &'static mut [T] = Box::leak(value);
+        let metadata = metadata_of::<T, Dyn>();
+
+        // SAFETY:
+        // The metadata is for `T` as `Dyn`, which is the type of the elements of `value`.
+        unsafe { Self::with_metadata(value, metadata) }
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Index<usize> for DynSliceMut<'a, Dyn> {
@@ -444,7 +1161,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IntoIterator for Dy
     type Item = &'a mut Dyn;
 
     fn into_iter(self) -> Self::IntoIter {
-        IterMut { slice: self }
+        IterMut::new(self)
     }
 }
 
@@ -474,7 +1191,11 @@ impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> IntoIterator
 mod test {
     use core::{fmt::Display, ptr::addr_of};
 
-    use crate::{declare_new_fns, standard::partial_eq, DynSliceMut};
+    use crate::{
+        declare_new_fns,
+        standard::{add_assign, any, partial_eq},
+        DynSlice, DynSliceMut,
+    };
 
     declare_new_fns!(
         #[crate = crate]
@@ -606,6 +1327,153 @@ mod test {
         }
     }
 
+    #[test]
+    fn split_first_last_mut() {
+        let mut array = [1, 2, 3];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+
+        let (first, mut rest) = slice.split_first_mut().unwrap();
+        *first += 10;
+        let (last, middle) = rest.split_last_mut().unwrap();
+        *last += 20;
+        middle.into_iter().for_each(|x| *x += 30);
+        assert_eq!(array, [11, 32, 23]);
+
+        let mut array = [1];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+        let (first, rest) = slice.split_first_mut().unwrap();
+        *first += 1;
+        assert!(rest.is_empty());
+        let (last, rest) = slice.split_last_mut().unwrap();
+        *last += 1;
+        assert!(rest.is_empty());
+        assert_eq!(array, [3]);
+
+        let mut array: [u8; 0] = [];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+        assert!(slice.split_first_mut().is_none());
+        assert!(slice.split_last_mut().is_none());
+    }
+
+    #[test]
+    fn into_split_at_mut() {
+        let mut array = [1, 2, 3, 4];
+
+        let slice = partial_eq::new_mut::<u8, u8>(&mut array);
+        assert!(slice.into_split_at_mut(5).is_none());
+
+        let slice = partial_eq::new_mut::<u8, u8>(&mut array);
+        let (first, second) = slice.into_split_at_mut(1).unwrap();
+        assert!(first == [1][..]);
+        assert!(second == [2, 3, 4][..]);
+
+        let (first, second) = second.into_split_at_mut(3).unwrap();
+        assert!(first == [2, 3, 4][..]);
+        assert!(second.is_empty());
+
+        let slice = partial_eq::new_mut::<u8, u8>(&mut []);
+        let (first, second) = slice.into_split_at_mut(0).unwrap();
+        assert!(first.is_empty());
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn split_mut_at_each() {
+        let mut array = [1, 2, 3, 4, 5, 6];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+
+        let parts = slice.split_mut_at_each(&[4, 1, 4, 6]).unwrap();
+        let lengths: Vec<_> = parts.iter().map(|part| part.len()).collect();
+        assert_eq!(lengths, [1, 3, 0, 2, 0]);
+
+        for (i, part) in (0..).zip(parts) {
+            part.into_iter().for_each(|x| *x += i * 10);
+        }
+        assert_eq!(array, [1, 12, 13, 14, 35, 36]);
+
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+        assert_eq!(slice.split_mut_at_each(&[]).unwrap().len(), 1);
+        assert!(slice.split_mut_at_each(&[2, 7]).is_none());
+
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut []);
+        assert_eq!(slice.split_mut_at_each(&[0, 0]).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn partition_dedup_by() {
+        let mut array = [1_u8, 1, 2, 3, 3, 3, 1, 4, 4];
+        let mut slice = any::new_mut(&mut array);
+
+        let (unique, duplicates) =
+            slice.partition_dedup_by(|a, b| a.downcast_ref::<u8>() == b.downcast_ref::<u8>());
+        assert_eq!(unique.len(), 5);
+        assert_eq!(duplicates.len(), 4);
+        array[5..].sort_unstable();
+        assert_eq!(array, [1, 2, 3, 1, 4, 1, 3, 3, 4]);
+
+        let mut array = [1_u8, 2, 3];
+        let mut slice = any::new_mut(&mut array);
+        let (unique, duplicates) = slice.partition_dedup_by(|_, _| false);
+        assert_eq!((unique.len(), duplicates.len()), (3, 0));
+        let (unique, duplicates) = slice.partition_dedup_by(|_, _| true);
+        assert_eq!((unique.len(), duplicates.len()), (1, 2));
+
+        let mut array: [u8; 0] = [];
+        let mut slice = any::new_mut(&mut array);
+        let (unique, duplicates) = slice.partition_dedup_by(|_, _| true);
+        assert!(unique.is_empty() && duplicates.is_empty());
+    }
+
+    #[test]
+    fn for_each_group_mut() {
+        let mut array = [1_u8, 1, 2, 3, 3, 1];
+        let mut slice = any::new_mut(&mut array);
+
+        let mut groups = Vec::new();
+        slice.for_each_group_mut(
+            |x| *x.downcast_ref::<u8>().unwrap(),
+            |key, mut run| {
+                groups.push((key, run.len()));
+                run.downcast_mut::<u8>().unwrap().fill(key * 10);
+            },
+        );
+        assert_eq!(groups, [(1, 2), (2, 1), (3, 2), (1, 1)]);
+        assert_eq!(array, [10, 10, 20, 30, 30, 10]);
+
+        let mut array: [u8; 0] = [];
+        let mut slice = any::new_mut(&mut array);
+        slice.for_each_group_mut(|_| (), |(), _| panic!("expected no groups"));
+    }
+
+    #[test]
+    fn into_immutable() {
+        let mut array = [1, 2, 3];
+        let slice = {
+            let mut slice = partial_eq::new_mut::<u8, u8>(&mut array);
+            assert!(slice.first_mut().is_some());
+            slice.into_immutable()
+        };
+        assert!(slice == [1, 2, 3][..]);
+
+        let slice: DynSlice<_> = partial_eq::new_mut::<u8, u8>(&mut array).into();
+        assert!(slice == [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn write_each() {
+        let mut array = [1, 2, 3];
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+
+        let applied = slice.write_each((1..=5).map(|n| move |x: &mut add_assign::Dyn<u8>| *x += n));
+        assert_eq!(applied, 3);
+        assert_eq!(array, [2, 4, 6]);
+
+        let mut slice = add_assign::new_mut::<u8, u8>(&mut array);
+        let applied = slice.write_each([|x: &mut add_assign::Dyn<u8>| *x += 1]);
+        assert_eq!(applied, 1);
+        assert_eq!(array, [3, 4, 6]);
+    }
+
     #[test]
     #[should_panic(expected = "index out of bounds")]
     fn index_empty() {
@@ -638,4 +1506,65 @@ mod test {
         let slice = partial_eq::new_mut::<u8, u8>(&mut array);
         _ = &slice[6];
     }
+
+    #[test]
+    fn copy_within() {
+        let mut array = [1, 2, 3, 4, 5, 6];
+        let mut slice = partial_eq::new_mut::<u8, u8>(&mut array);
+
+        // SAFETY:
+        // The underlying type, `u8`, is `Copy`.
+        unsafe {
+            slice.copy_within(..2, 4);
+            assert!(slice == [1, 2, 3, 4, 1, 2]);
+            slice.copy_within(0..4, 1);
+            assert!(slice == [1, 1, 2, 3, 4, 2]);
+            slice.copy_within(2..=4, 1);
+            assert!(slice == [1, 2, 3, 4, 4, 2]);
+            slice.copy_within(6.., 6);
+            slice.copy_within(3..3, 0);
+        }
+        assert_eq!(array, [1, 2, 3, 4, 4, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "src is out of bounds")]
+    fn copy_within_src_out_of_bounds() {
+        let mut array = [1, 2, 3];
+        // SAFETY:
+        // The underlying type, `u8`, is `Copy`.
+        unsafe { partial_eq::new_mut::<u8, u8>(&mut array).copy_within(1..4, 0) };
+    }
+
+    #[test]
+    #[should_panic(expected = "dest is out of bounds")]
+    fn copy_within_dest_out_of_bounds() {
+        let mut array = [1, 2, 3];
+        // SAFETY:
+        // The underlying type, `u8`, is `Copy`.
+        unsafe { partial_eq::new_mut::<u8, u8>(&mut array).copy_within(1..3, 2) };
+    }
+
+    #[test]
+    fn element_bytes_mut() {
+        let mut array = [1_u32, 2, 3];
+        let mut slice = partial_eq::new_mut::<u32, _>(&mut array);
+
+        // SAFETY:
+        // `u32` has no padding, and any bytes make a valid `u32`.
+        unsafe {
+            assert!(slice.element_bytes_mut(3).is_none());
+            let bytes = slice.element_bytes_mut(1).unwrap();
+            assert_eq!(bytes, 2_u32.to_ne_bytes());
+            bytes.copy_from_slice(&10_u32.to_ne_bytes());
+            assert_eq!(slice.element_bytes(1).unwrap(), 10_u32.to_ne_bytes());
+        }
+        assert_eq!(array, [1, 10, 3]);
+
+        let mut array = [(); 2];
+        let mut slice = partial_eq::new_mut::<(), _>(&mut array);
+        // SAFETY:
+        // `()` has no bytes.
+        assert_eq!(unsafe { slice.element_bytes_mut(1) }, Some(&mut [][..]));
+    }
 }
@@ -0,0 +1,218 @@
+use core::{
+    marker::Unsize,
+    mem::MaybeUninit,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::metadata_of, DynSliceMut};
+
+/// `&mut dyn [MaybeUninit<Trait>]`
+///
+/// A mutable type erased slice of possibly uninitialised elements, that can be initialised one
+/// element at a time, then converted into a [`DynSliceMut`] with [`DynSliceUninit::assume_init`].
+///
+/// Like [`MaybeUninit`], elements are never dropped by this type.
+///
+/// # Example
+/// ```
+/// use core::mem::MaybeUninit;
+/// use dyn_slice::{standard::display, DynSliceUninit};
+///
+/// let mut array = [MaybeUninit::<u8>::uninit(); 3];
+/// let mut slice = DynSliceUninit::<display::Dyn>::new(&mut array);
+///
+/// for i in 0..slice.len() {
+///     // SAFETY: the underlying type is `u8`
+///     unsafe { slice.init_with(i, i as u8 * 2) };
+/// }
+///
+/// // SAFETY: every element has been initialised
+/// let slice = unsafe { slice.assume_init() };
+/// # assert_eq!(format!("{}", slice.display_list()), "0, 2, 4");
+/// println!("{}", slice.display_list()); // 0, 2, 4
+/// ```
+pub struct DynSliceUninit<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSliceMut<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceUninit<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Construct an uninitialised dyn slice from a slice of [`MaybeUninit`].
+    pub fn new<T: Unsize<Dyn>>(value: &'a mut [MaybeUninit<T>]) -> Self {
+        let metadata = metadata_of::<T, Dyn>();
+
+        Self {
+            // SAFETY:
+            // `MaybeUninit<T>` has the same layout as `T`, and the metadata is for `T` as `Dyn`.
+            // The elements are not accessed through the dyn slice until they are initialised.
+            slice: unsafe { DynSliceMut::with_metadata(value, metadata) },
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in the slice.
+    pub const fn len(&self) -> usize {
+        self.slice.0.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the slice has a length of 0.
+    pub const fn is_empty(&self) -> bool {
+        self.slice.0.len == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the metadata component of the element's pointers.
+    pub fn metadata(&self) -> DynMetadata<Dyn> {
+        debug_assert!(
+            !self.slice.vtable_ptr.is_null(),
+            "[dyn-slice] vtable pointer is null!"
+        );
+
+        // SAFETY:
+        // The vtable pointer is always set from the metadata in `new`.
+        unsafe { self.slice.metadata().unwrap_unchecked() }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a mutable pointer to the underlying slice.
+    pub fn as_mut_ptr(&mut self) -> *mut () {
+        self.slice.as_mut_ptr()
+    }
+
+    /// Calls `write` with a pointer to the element at `index`, so that it can be initialised.
+    ///
+    /// If the element was already initialised, it is overwritten without being dropped.
+    ///
+    /// # Safety
+    /// The caller must ensure that, if `write` returns, it has written a valid value of the
+    /// underlying type to the pointer.
+    ///
+    /// # Panics
+    /// Panics if `index >= self.len()`.
+    pub unsafe fn write<F: FnOnce(*mut ())>(&mut self, index: usize, write: F) {
+        assert!(index < self.len(), "index out of bounds");
+
+        write(self.slice.get_ptr_unchecked(index).cast_mut());
+    }
+
+    /// Initialises the element at `index` with `value`.
+    ///
+    /// If the element was already initialised, it is overwritten without being dropped.
+    ///
+    /// # Safety
+    /// The caller must ensure that, if the metadata for `T` matches the metadata of the slice, `T`
+    /// is the underlying type of the slice.
+    ///
+    /// Identical vtables may be merged for different types, so matching metadata does not prove
+    /// that the types are the same.
+    ///
+    /// # Panics
+    /// Panics if `index >= self.len()`, or if the metadata for `T` does not match the metadata of
+    /// the slice.
+    pub unsafe fn init_with<T: Unsize<Dyn>>(&mut self, index: usize, value: T) {
+        assert!(
+            metadata_of::<T, Dyn>() == self.metadata(),
+            "type is not the underlying type of the slice"
+        );
+
+        // SAFETY:
+        // The metadata matches, so the caller guarantees that `T` is the underlying type.
+        self.write(index, |ptr| ptr.cast::<T>().write(value));
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the slice into a [`DynSliceMut`].
+    ///
+    /// # Safety
+    /// The caller must ensure that every element of the slice has been initialised.
+    pub const unsafe fn assume_init(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::MaybeUninit;
+
+    use crate::{
+        standard::{debug, partial_eq},
+        DynSliceUninit,
+    };
+
+    #[test]
+    fn init_with() {
+        let mut array = [MaybeUninit::<u16>::uninit(); 4];
+        let mut slice = DynSliceUninit::<partial_eq::Dyn<u16>>::new(&mut array);
+        assert_eq!(slice.len(), 4);
+        assert!(!slice.is_empty());
+
+        for (i, value) in (1..=4_u16).enumerate() {
+            // SAFETY:
+            // The underlying type is `u16`.
+            unsafe { slice.init_with(i, value) };
+        }
+
+        // SAFETY:
+        // Every element has been initialised.
+        let slice = unsafe { slice.assume_init() };
+        assert!(slice == [1, 2, 3, 4][..]);
+    }
+
+    #[test]
+    fn write() {
+        let mut array = [MaybeUninit::<String>::uninit(), MaybeUninit::uninit()];
+        let mut slice = DynSliceUninit::<debug::Dyn>::new(&mut array);
+
+        for (i, s) in ["a", "b"].into_iter().enumerate() {
+            // SAFETY:
+            // The underlying type is `String`.
+            unsafe { slice.write(i, |ptr| ptr.cast::<String>().write(s.to_owned())) };
+        }
+
+        // SAFETY:
+        // Every element has been initialised.
+        let slice = unsafe { slice.assume_init() };
+        assert_eq!(format!("{slice:?}"), "[\"a\", \"b\"]");
+
+        // SAFETY:
+        // Every element has been initialised, and is not used again.
+        unsafe { array.iter_mut().for_each(|s| s.assume_init_drop()) };
+    }
+
+    #[test]
+    fn empty() {
+        let mut array: [MaybeUninit<u8>; 0] = [];
+        let slice = DynSliceUninit::<debug::Dyn>::new(&mut array);
+        assert!(slice.is_empty());
+
+        // SAFETY:
+        // There are no elements to initialise.
+        let slice = unsafe { slice.assume_init() };
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_bounds() {
+        let mut array = [MaybeUninit::<u8>::uninit(); 2];
+        // SAFETY:
+        // The underlying type is `u8`.
+        unsafe { DynSliceUninit::<debug::Dyn>::new(&mut array).init_with(2, 0_u8) };
+    }
+
+    #[test]
+    #[should_panic(expected = "type is not the underlying type of the slice")]
+    fn wrong_type() {
+        let mut array = [MaybeUninit::<u8>::uninit(); 2];
+        // SAFETY:
+        // The metadata of `u8` and `u16` as `dyn Debug` differ.
+        unsafe { DynSliceUninit::<debug::Dyn>::new(&mut array).init_with(0, 0_u16) };
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug},
+    ptr::{DynMetadata, Pointee},
+    slice,
+};
+
+use crate::{utils::byte_offset_unchecked, DynSlice, DynSliceMut};
+
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+/// A copy of the bytes of the elements of a dyn slice, with their metadata, for finding which
+/// elements have changed since.
+///
+/// This is created by [`DynSlice::snapshot`], and compared with [`DynSliceMut::changed_indices`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::add_assign;
+///
+/// let mut array = [1_u32, 2, 3, 4];
+/// let mut slice = add_assign::new_mut(&mut array);
+///
+/// // SAFETY: `u32` has no padding
+/// let snapshot = unsafe { slice.snapshot() };
+///
+/// *slice.get_mut(1).unwrap() += 1;
+/// *slice.get_mut(3).unwrap() += 0;
+///
+/// // SAFETY: the snapshot was taken from this slice
+/// let changed = unsafe { slice.changed_indices(&snapshot) };
+/// # assert_eq!(changed, [1]);
+/// println!("{changed:?}"); // [1]
+/// ```
+pub struct DynSnapshot<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    metadata: Option<DynMetadata<Dyn>>,
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSnapshot<Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the number of elements in the snapshot.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the snapshot has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the metadata of the elements, or `None` if the slice that the snapshot was taken of
+    /// had no vtable.
+    pub const fn metadata(&self) -> Option<DynMetadata<Dyn>> {
+        self.metadata
+    }
+
+    #[must_use]
+    /// Returns the captured bytes of the element at `index`, or `None` if it is out of bounds.
+    pub fn element_bytes(&self, index: usize) -> Option<&[u8]> {
+        if index >= self.len {
+            return None;
+        }
+
+        let size = self.metadata?.size_of();
+        self.bytes.get(index * size..(index + 1) * size)
+    }
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DynSnapshot<Dyn> {
+    fn clone(&self) -> Self {
+        Self {
+            metadata: self.metadata,
+            len: self.len,
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Debug for DynSnapshot<Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynSnapshot")
+            .field("len", &self.len)
+            .field("bytes", &self.bytes.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Captures the bytes and metadata of the elements in the slice.
+    ///
+    /// See [`DynSnapshot`].
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type has no padding or otherwise uninitialised
+    /// bytes, as reading them is undefined behaviour.
+    pub unsafe fn snapshot(&self) -> DynSnapshot<Dyn> {
+        let Some(metadata) = self.metadata() else {
+            return DynSnapshot {
+                metadata: None,
+                len: 0,
+                bytes: Vec::new(),
+            };
+        };
+
+        let size = byte_offset_unchecked(metadata.size_of(), self.len);
+        DynSnapshot {
+            metadata: Some(metadata),
+            len: self.len,
+            bytes: slice::from_raw_parts(self.data.cast::<u8>(), size).to_vec(),
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Returns the indices of the elements that differ from `snapshot`, in ascending order.
+    ///
+    /// Elements are compared by their bytes. Elements past the end of `snapshot` are always
+    /// changed, as are all elements if the metadata does not match the snapshot's.
+    ///
+    /// See [`DynSnapshot`].
+    ///
+    /// # Safety
+    /// The caller must ensure that, if the metadata matches the snapshot's, the underlying type is
+    /// the same as the one the snapshot was taken of, as equal metadata does not prove that the
+    /// types are the same. That type must have no padding or otherwise uninitialised bytes, as
+    /// reading them is undefined behaviour.
+    pub unsafe fn changed_indices(&self, snapshot: &DynSnapshot<Dyn>) -> Vec<usize> {
+        let Some(metadata) = self.metadata() else {
+            return Vec::new();
+        };
+        if snapshot.metadata != Some(metadata) {
+            return (0..self.len()).collect();
+        }
+
+        let size = metadata.size_of();
+        (0..self.len())
+            .filter(|&index| {
+                snapshot.element_bytes(index).map_or(true, |old| {
+                    // SAFETY:
+                    // The metadata matches the snapshot's, so the caller guarantees that the
+                    // underlying type is the same, and that it has no padding.
+                    // `index` is in bounds of the slice.
+                    let new = unsafe {
+                        slice::from_raw_parts(self.get_ptr_unchecked(index).cast::<u8>(), size)
+                    };
+                    old != new
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::{add_assign, debug};
+
+    #[test]
+    fn changed_indices() {
+        let mut array = [1_u64, 2, 3, 4, 5];
+        let mut slice = add_assign::new_mut(&mut array);
+
+        // SAFETY:
+        // `u64` has no padding.
+        let snapshot = unsafe { slice.snapshot() };
+        assert_eq!(snapshot.len(), 5);
+        assert_eq!(snapshot.element_bytes(2), Some(&3_u64.to_ne_bytes()[..]));
+        assert!(snapshot.element_bytes(5).is_none());
+        // SAFETY:
+        // All of the slices in this test have the underlying type `u64`, which has no padding.
+        unsafe {
+            assert!(slice.changed_indices(&snapshot).is_empty());
+
+            *slice.get_mut(0).unwrap() += 1;
+            *slice.get_mut(4).unwrap() += 1;
+            assert_eq!(slice.changed_indices(&snapshot), [0, 4]);
+
+            let mut longer = [1_u64, 2, 3, 4, 5, 6];
+            let longer = add_assign::new_mut(&mut longer);
+            assert_eq!(longer.changed_indices(&snapshot), [5]);
+        }
+    }
+
+    #[test]
+    fn changed_indices_metadata() {
+        let mut array = [1_u32, 2];
+        let slice = debug::new_mut(&mut array);
+
+        let mut other = [1_u16, 2];
+        // SAFETY:
+        // `u16` has no padding.
+        let snapshot = unsafe { debug::new(&other).snapshot() };
+        // SAFETY:
+        // The metadata does not match, so no bytes are compared.
+        assert_eq!(unsafe { slice.changed_indices(&snapshot) }, [0, 1]);
+
+        // SAFETY:
+        // The slice is empty.
+        let empty = unsafe { debug::new::<u32>(&[]).snapshot() };
+        assert!(empty.is_empty());
+        assert!(empty.metadata().is_none());
+        // SAFETY:
+        // The snapshot has no metadata, so no bytes are compared.
+        assert_eq!(unsafe { slice.changed_indices(&empty) }, [0, 1]);
+        // SAFETY:
+        // The slice is empty, so no bytes are compared.
+        assert!(unsafe { debug::new_mut(&mut other[..0]).changed_indices(&snapshot) }.is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{
+    fmt::{self, Debug},
+    mem::{size_of_val, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr::{self, DynMetadata, Pointee},
+};
+
+use crate::DynSliceMut;
+
+/// The largest element, in bytes, that an [`ElementGuard`] backs up without allocating
+const INLINE_SIZE: usize = 64;
+
+/// A copy of the bytes of an element
+enum Backup {
+    Inline([MaybeUninit<u8>; INLINE_SIZE]),
+    #[cfg(feature = "alloc")]
+    Heap(Box<[MaybeUninit<u8>]>),
+}
+
+impl Backup {
+    /// Copies the bytes of `element`, or returns `None` if it is too large to copy without
+    /// allocating and the `alloc` feature is disabled.
+    fn new<Dyn: ?Sized>(element: &Dyn) -> Option<Self> {
+        let size = size_of_val(element);
+        let source = (element as *const Dyn).cast::<MaybeUninit<u8>>();
+
+        let mut backup = if size <= INLINE_SIZE {
+            Self::Inline([MaybeUninit::uninit(); INLINE_SIZE])
+        } else {
+            Self::heap(size)?
+        };
+
+        // SAFETY:
+        // `source` points to the `size` bytes of `element`, and the backup has space for at least
+        // `size` bytes. The backup is a new buffer, so they do not overlap.
+        unsafe { ptr::copy_nonoverlapping(source, backup.as_mut_ptr(), size) };
+
+        Some(backup)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Allocates a backup of `size` bytes.
+    #[allow(clippy::unnecessary_wraps)]
+    fn heap(size: usize) -> Option<Self> {
+        Some(Self::Heap(
+            alloc::vec![MaybeUninit::uninit(); size].into_boxed_slice(),
+        ))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    /// Large elements cannot be backed up without allocating.
+    const fn heap(_size: usize) -> Option<Self> {
+        None
+    }
+
+    const fn as_ptr(&self) -> *const MaybeUninit<u8> {
+        match self {
+            Self::Inline(bytes) => bytes.as_ptr(),
+            #[cfg(feature = "alloc")]
+            Self::Heap(bytes) => bytes.as_ptr(),
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut MaybeUninit<u8> {
+        match self {
+            Self::Inline(bytes) => bytes.as_mut_ptr(),
+            #[cfg(feature = "alloc")]
+            Self::Heap(bytes) => bytes.as_mut_ptr(),
+        }
+    }
+}
+
+/// Mutable access to an element of a dyn slice, that can be rolled back to the element's value
+/// when the guard was created.
+///
+/// Dropping the guard, or calling [`ElementGuard::commit`], keeps the changes.
+/// [`ElementGuard::rollback`] restores the original bytes of the element.
+///
+/// Elements of up to 64 bytes are backed up on the stack. Larger elements are backed up on the
+/// heap, which requires the `alloc` feature.
+///
+/// This is created by [`DynSliceMut::edit`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::add_assign;
+///
+/// let mut array = [1_u8, 2, 3];
+/// let mut slice = add_assign::new_mut(&mut array);
+///
+/// // SAFETY: `u8` is `Copy`
+/// let mut guard = unsafe { slice.edit(1) }.unwrap();
+/// *guard += 10;
+/// guard.rollback();
+///
+/// // SAFETY: `u8` is `Copy`
+/// let mut guard = unsafe { slice.edit(2) }.unwrap();
+/// *guard += 10;
+/// guard.commit();
+///
+/// assert_eq!(array, [1, 2, 13]);
+/// ```
+pub struct ElementGuard<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    element: &'a mut Dyn,
+    backup: Backup,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> ElementGuard<'a, Dyn> {
+    #[inline]
+    // The heap backup cannot be dropped in a const fn
+    #[cfg_attr(not(feature = "alloc"), allow(clippy::missing_const_for_fn))]
+    /// Keeps the changes to the element.
+    ///
+    /// This is the same as dropping the guard.
+    pub fn commit(self) {}
+
+    /// Restores the element to its value when the guard was created.
+    ///
+    /// The changed value is overwritten without being dropped.
+    pub fn rollback(self) {
+        let size = size_of_val(self.element);
+        let destination = (self.element as *mut Dyn).cast::<MaybeUninit<u8>>();
+
+        // SAFETY:
+        // The backup holds the `size` bytes of the element when the guard was created, and the
+        // creator of the guard guaranteed that they are still a valid value of the underlying
+        // type. The element is borrowed mutably, so it does not overlap the backup.
+        unsafe { ptr::copy_nonoverlapping(self.backup.as_ptr(), destination, size) };
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Deref for ElementGuard<'a, Dyn> {
+    type Target = Dyn;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.element
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DerefMut for ElementGuard<'a, Dyn> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.element
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug
+    for ElementGuard<'a, Dyn>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ElementGuard")
+            .field(&&*self.element)
+            .finish()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    /// Returns a guard for editing the element at `index`, that can roll the element back to its
+    /// current value, or `None` if `index` is out of bounds.
+    ///
+    /// Without the `alloc` feature, this also returns `None` if the element is larger than 64
+    /// bytes.
+    ///
+    /// See [`ElementGuard`].
+    ///
+    /// # Safety
+    /// Rolling back restores a copy of the element's bytes without dropping the changed value, so
+    /// the caller must ensure that the copy is still a valid value after any changes made through
+    /// the guard, and that the changed value can be forgotten. This is true for `Copy` types, but
+    /// not for types that own resources, like `String`.
+    pub unsafe fn edit(&mut self, index: usize) -> Option<ElementGuard<'_, Dyn>> {
+        let element = self.get_mut(index)?;
+        let backup = Backup::new(element)?;
+
+        Some(ElementGuard { element, backup })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::{add_assign, as_mut};
+
+    #[test]
+    fn edit_large() {
+        let mut array = [[1_u64; 16], [2; 16]];
+        let mut slice = as_mut::new_mut::<[u64], _>(&mut array);
+        assert!(
+            // SAFETY:
+            // The index is out of bounds, so no guard is created.
+            unsafe { slice.edit(2) }.is_none()
+        );
+
+        // SAFETY:
+        // `[u64; 16]` is `Copy`.
+        let mut guard = unsafe { slice.edit(0) }.unwrap();
+        guard.as_mut()[3] = 9;
+        assert_eq!(guard.as_mut()[3], 9);
+        guard.rollback();
+
+        // SAFETY:
+        // `[u64; 16]` is `Copy`.
+        let mut guard = unsafe { slice.edit(1) }.unwrap();
+        guard.as_mut()[15] = 9;
+        guard.commit();
+
+        let mut expected = [[1; 16], [2; 16]];
+        expected[1][15] = 9;
+        assert_eq!(array, expected);
+    }
+
+    #[test]
+    fn edit_inline() {
+        let mut array = [10_u32, 20];
+        let mut slice = add_assign::new_mut(&mut array);
+
+        // SAFETY:
+        // `u32` is `Copy`.
+        let mut guard = unsafe { slice.edit(0) }.unwrap();
+        *guard += 5;
+        guard.rollback();
+
+        // SAFETY:
+        // `u32` is `Copy`.
+        let mut guard = unsafe { slice.edit(1) }.unwrap();
+        *guard += 5;
+        drop(guard);
+
+        assert_eq!(array, [10, 25]);
+    }
+}
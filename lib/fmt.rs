@@ -0,0 +1,357 @@
+use core::{
+    fmt::{self, Debug, Display},
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::DynSlice;
+
+/// An allocation free [`Display`] adapter that displays each element of a [`DynSlice`], separated by a separator.
+///
+/// The formatting options (width, fill, alignment, precision, etc.) are forwarded to each element.
+///
+/// This is created by [`DynSlice::display_list`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::display;
+///
+/// let slice = display::new(&[1.0, 2.25, 3.5]);
+///
+/// # assert_eq!(format!("{:.1}", slice.display_list()), "1.0, 2.2, 3.5");
+/// println!("{:.1}", slice.display_list()); // 1.0, 2.2, 3.5
+/// # assert_eq!(format!("{:>5}", slice.display_list().separator("|")), "    1| 2.25|  3.5");
+/// println!("{:>5}", slice.display_list().separator("|")); //     1| 2.25|  3.5
+/// ```
+pub struct DisplayList<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSlice<'a, Dyn>,
+    separator: &'a str,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DisplayList<'a, Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for DisplayList<'a, Dyn> {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Display> DisplayList<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a new display adapter for `slice`, with the default separator, `", "`.
+    pub const fn new(slice: DynSlice<'a, Dyn>) -> Self {
+        Self {
+            slice,
+            separator: ", ",
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set the separator that is displayed between elements.
+    pub const fn separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Display> Display
+    for DisplayList<'a, Dyn>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.slice.iter();
+
+        if let Some(first) = iter.next() {
+            first.fmt(f)?;
+
+            for element in iter {
+                f.write_str(self.separator)?;
+                element.fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Display> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns a [`Display`] adapter that displays each element, separated by `", "`.
+    ///
+    /// See [`DisplayList`] for more details.
+    pub const fn display_list(&self) -> DisplayList<'_, Dyn> {
+        DisplayList::new(*self)
+    }
+
+    /// Writes each element into `w`, separated by `separator`.
+    ///
+    /// This is a shorthand for writing a [`DisplayList`] with the given separator.
+    ///
+    /// # Errors
+    /// Returns an error if `w` or an element returns an error.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::display;
+    ///
+    /// let slice = display::new(&[1, 2, 3]);
+    ///
+    /// let mut s = String::new();
+    /// slice.write_all_into(&mut s, " + ").unwrap();
+    /// # assert_eq!(s, "1 + 2 + 3");
+    /// println!("{s}"); // 1 + 2 + 3
+    /// ```
+    pub fn write_all_into<W: fmt::Write + ?Sized>(
+        &self,
+        w: &mut W,
+        separator: &str,
+    ) -> fmt::Result {
+        write!(w, "{}", self.display_list().separator(separator))
+    }
+}
+
+/// A [`Debug`] adapter that formats a [`DynSlice`] as a map from indices to elements, optionally
+/// truncated after a number of elements.
+///
+/// If the slice is truncated, the last entry has the key `...` and the number of elements that
+/// were left out.
+///
+/// This is created by [`DynSlice::debug_indexed`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::debug;
+///
+/// let slice = debug::new(&[10, 20, 30, 40, 50]);
+///
+/// # assert_eq!(format!("{:?}", slice.debug_indexed()), "{0: 10, 1: 20, 2: 30, 3: 40, 4: 50}");
+/// println!("{:?}", slice.debug_indexed()); // {0: 10, 1: 20, 2: 30, 3: 40, 4: 50}
+/// # assert_eq!(format!("{:?}", slice.debug_indexed().limit(2)), "{0: 10, 1: 20, ...: 3 more}");
+/// println!("{:?}", slice.debug_indexed().limit(2)); // {0: 10, 1: 20, ...: 3 more}
+/// ```
+pub struct DebugIndexed<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSlice<'a, Dyn>,
+    limit: Option<usize>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for DebugIndexed<'a, Dyn> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for DebugIndexed<'a, Dyn> {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> DebugIndexed<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a new indexed debug adapter for `slice`, which is not truncated.
+    pub const fn new(slice: DynSlice<'a, Dyn>) -> Self {
+        Self { slice, limit: None }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set the maximum number of elements that are formatted.
+    pub const fn limit(self, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> Debug
+    for DebugIndexed<'a, Dyn>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shown = self
+            .limit
+            .map_or(self.slice.len, |limit| limit.min(self.slice.len));
+
+        let mut map = f.debug_map();
+        map.entries(self.slice.iter().take(shown).enumerate());
+
+        let remaining = self.slice.len - shown;
+        if remaining != 0 {
+            map.entry(&format_args!("..."), &format_args!("{remaining} more"));
+        }
+
+        map.finish()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns a [`Debug`] adapter that formats the elements with their indices.
+    ///
+    /// See [`DebugIndexed`] for more details.
+    pub const fn debug_indexed(&self) -> DebugIndexed<'_, Dyn> {
+        DebugIndexed::new(*self)
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(doc, doc(cfg(feature = "tracing")))]
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Debug> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the slice as a [`tracing`] field value, recorded with its [`Debug`] representation.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[1, 2, 3]);
+    ///
+    /// tracing::info!(values = slice.as_debug_value(), "processing"); // values=[1, 2, 3]
+    /// # assert_eq!(format!("{:?}", slice.as_debug_value()), "[1, 2, 3]");
+    /// ```
+    pub fn as_debug_value(&self) -> tracing::field::DebugValue<DynSlice<'_, Dyn>> {
+        tracing::field::debug(*self)
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(doc, doc(cfg(feature = "tracing")))]
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Display> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the slice as a [`tracing`] field value, recorded as a [`DisplayList`].
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::display;
+    ///
+    /// let slice = display::new(&[1, 2, 3]);
+    ///
+    /// tracing::info!(values = slice.as_display_value(), "processing"); // values=1, 2, 3
+    /// # assert_eq!(format!("{:?}", slice.as_display_value()), "1, 2, 3");
+    /// ```
+    pub fn as_display_value(&self) -> tracing::field::DisplayValue<DisplayList<'_, Dyn>> {
+        tracing::field::display(self.display_list())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::{self, Write};
+
+    use crate::standard::{debug, display};
+
+    /// A fixed capacity buffer, to test formatting without allocation
+    struct Buffer<const N: usize> {
+        data: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> Write for Buffer<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.data
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn basic() {
+        let slice = display::new(&[1, 2, 3]);
+        assert_eq!(format!("{}", slice.display_list()), "1, 2, 3");
+        assert_eq!(format!("{}", slice.display_list().separator("")), "123");
+
+        let slice = display::new::<u8>(&[]);
+        assert_eq!(format!("{}", slice.display_list()), "");
+
+        let slice = display::new(&["a"]);
+        assert_eq!(format!("{}", slice.display_list()), "a");
+    }
+
+    #[test]
+    fn flags() {
+        let slice = display::new(&[1, 22, 333]);
+        assert_eq!(format!("{:<3}", slice.display_list()), "1  , 22 , 333");
+        assert_eq!(
+            format!("{:*^5}", slice.display_list()),
+            "**1**, *22**, *333*"
+        );
+
+        let slice = display::new(&[0.5, 1.25]);
+        assert_eq!(format!("{:+.2}", slice.display_list()), "+0.50, +1.25");
+    }
+
+    #[test]
+    fn write_all_into() {
+        let slice = display::new(&["a", "b", "c"]);
+
+        let mut s = String::from(">");
+        slice.write_all_into(&mut s, ", ").unwrap();
+        assert_eq!(s, ">a, b, c");
+
+        let mut buffer = Buffer {
+            data: [0; 4],
+            len: 0,
+        };
+        assert!(slice.write_all_into(&mut buffer, "--").is_err());
+    }
+
+    #[test]
+    fn fixed_buffer() {
+        let slice = display::new(&[1, 2, 3]);
+
+        let mut buffer = Buffer {
+            data: [0; 16],
+            len: 0,
+        };
+        write!(buffer, "[{:02}]", slice.display_list().separator(" ")).unwrap();
+        assert_eq!(&buffer.data[..buffer.len], b"[01 02 03]");
+
+        let mut buffer = Buffer {
+            data: [0; 4],
+            len: 0,
+        };
+        assert!(write!(buffer, "{}", slice.display_list()).is_err());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_values() {
+        const fn assert_value<T: tracing::Value>(value: &T) -> &T {
+            value
+        }
+
+        let slice = display::new(&[1, 2, 3]);
+        let value = slice.as_display_value();
+        assert_eq!(format!("{:?}", assert_value(&value)), "1, 2, 3");
+
+        let slice = debug::new(&["a", "b"]);
+        let value = slice.as_debug_value();
+        assert_eq!(format!("{:?}", assert_value(&value)), r#"["a", "b"]"#);
+    }
+
+    #[test]
+    fn debug_indexed() {
+        let slice = debug::new(&["a", "b", "c"]);
+        assert_eq!(
+            format!("{:?}", slice.debug_indexed()),
+            r#"{0: "a", 1: "b", 2: "c"}"#
+        );
+        assert_eq!(
+            format!("{:?}", slice.debug_indexed().limit(3)),
+            r#"{0: "a", 1: "b", 2: "c"}"#
+        );
+        assert_eq!(
+            format!("{:?}", slice.debug_indexed().limit(0)),
+            "{...: 3 more}"
+        );
+        assert_eq!(
+            format!("{:#?}", slice.debug_indexed().limit(1)),
+            "{\n    0: \"a\",\n    ...: 2 more,\n}"
+        );
+
+        let empty = debug::new::<u8>(&[]);
+        assert_eq!(format!("{:?}", empty.debug_indexed().limit(0)), "{}");
+    }
+}
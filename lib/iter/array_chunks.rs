@@ -0,0 +1,212 @@
+use core::{
+    array,
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime, DynSlice, Iter};
+
+/// Iterator over non-overlapping chunks of a [`DynSlice`], as arrays of `N` references.
+///
+/// If `N` does not exactly divide the length, the last elements are not yielded, and can be
+/// retrieved with [`ArrayChunks::remainder`].
+///
+/// This is created by [`Iter::array_chunks`].
+pub struct ArrayChunks<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize> {
+    /// An iterator over the elements of the full chunks, so its length is a multiple of `N`
+    iter: Iter<'a, Dyn>,
+    remainder: DynSlice<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize>
+    ArrayChunks<'a, Dyn, N>
+{
+    #[inline]
+    #[must_use]
+    /// Returns the elements at the end of the slice that do not fit into a full chunk.
+    pub const fn remainder(&self) -> DynSlice<'a, Dyn> {
+        self.remainder
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iter<'a, Dyn> {
+    #[must_use]
+    /// Returns an iterator over the remaining elements as arrays of `N` references.
+    ///
+    /// If `N` does not exactly divide the number of remaining elements, the last elements are not
+    /// yielded, and can be retrieved with [`ArrayChunks::remainder`].
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1, 2, 3, 4, 5];
+    /// let slice = debug::new(&array);
+    ///
+    /// let mut iter = slice.iter().array_chunks();
+    /// for [a, b] in iter.by_ref() {
+    ///     println!("{a:?} {b:?}"); // 1 2, then 3 4
+    /// }
+    /// # assert_eq!(format!("{:?}", iter.remainder()), "[5]");
+    /// println!("{:?}", iter.remainder()); // [5]
+    /// ```
+    pub fn array_chunks<const N: usize>(self) -> ArrayChunks<'a, Dyn, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let len = self.slice.len() - self.slice.len() % N;
+        // SAFETY:
+        // `len <= self.slice.len()`, so is a valid split point.
+        // The data lives for 'a, and the iterator is consumed, so the lifetime can be extended.
+        let (chunks, remainder) = unsafe {
+            let (chunks, remainder) = self.slice.split_at_unchecked(len);
+            (extend_lifetime(chunks), extend_lifetime(remainder))
+        };
+
+        ArrayChunks {
+            iter: Iter::new(chunks),
+            remainder,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> Clone
+    for ArrayChunks<'a, Dyn, N>
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            remainder: self.remainder,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> Iterator
+    for ArrayChunks<'a, Dyn, N>
+{
+    type Item = [&'a Dyn; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        Some(array::from_fn(|_| {
+            // SAFETY:
+            // The length of the iterator is a non-zero multiple of `N`, so there are
+            // at least `N` elements left.
+            unsafe { self.iter.next_unchecked() }
+        }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Use impl for ExactSizeIterator
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize>
+    DoubleEndedIterator for ArrayChunks<'a, Dyn, N>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        let mut chunk: Self::Item = array::from_fn(|_| {
+            // SAFETY:
+            // The length of the iterator is a non-zero multiple of `N`, so there are
+            // at least `N` elements left.
+            unsafe { self.iter.next_back_unchecked() }
+        });
+        chunk.reverse();
+
+        Some(chunk)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> ExactSizeIterator
+    for ArrayChunks<'a, Dyn, N>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len() / N
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> FusedIterator
+    for ArrayChunks<'a, Dyn, N>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::partial_eq;
+
+    #[test]
+    fn basic() {
+        let array = [1, 2, 3, 4, 5, 6, 7];
+        let slice = partial_eq::new::<i32, _>(&array);
+
+        let mut iter = slice.iter().array_chunks::<2>();
+        assert_eq!(iter.len(), 3);
+        let [first, second] = iter.next().unwrap();
+        assert!(first == &1 && second == &2);
+        let [first, second] = iter.next_back().unwrap();
+        assert!(first == &5 && second == &6);
+        assert_eq!(iter.len(), 1);
+        let [first, second] = iter.next().unwrap();
+        assert!(first == &3 && second == &4);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+
+        assert!(iter.remainder() == [7][..]);
+    }
+
+    #[test]
+    fn partially_consumed() {
+        let array = [1, 2, 3, 4, 5, 6];
+        let slice = partial_eq::new::<i32, _>(&array);
+
+        let mut iter = slice.iter();
+        iter.next();
+        let iter = iter.array_chunks::<3>();
+        assert_eq!(iter.len(), 1);
+        assert!(iter.remainder() == [5, 6][..]);
+
+        let chunks: Vec<_> = iter.collect();
+        assert!(chunks[0][0] == &2 && chunks[0][2] == &4);
+    }
+
+    #[test]
+    fn short() {
+        let array = [1, 2];
+        let slice = partial_eq::new::<i32, _>(&array);
+
+        let mut iter = slice.iter().array_chunks::<3>();
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert_eq!(iter.remainder().len(), 2);
+
+        let array: [u8; 0] = [];
+        let slice = partial_eq::new::<u8, _>(&array);
+        assert!(slice.iter().array_chunks::<1>().next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero() {
+        let array = [1, 2];
+        let slice = partial_eq::new::<i32, _>(&array);
+        let _ = slice.iter().array_chunks::<0>();
+    }
+}
@@ -0,0 +1,167 @@
+use core::{
+    array,
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{DynSliceMut, IterMut};
+
+/// Iterator over non-overlapping chunks of a [`DynSliceMut`], as arrays of `N` mutable references.
+///
+/// If `N` does not exactly divide the length, the last elements are not yielded, and can be
+/// retrieved with [`ArrayChunksMut::into_remainder`].
+///
+/// This is created by [`DynSliceMut::chunks_exact_mut_arrays`].
+pub struct ArrayChunksMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize> {
+    /// An iterator over the elements of the full chunks, so its length is a multiple of `N`
+    pub(crate) iter: IterMut<'a, Dyn>,
+    pub(crate) remainder: DynSliceMut<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, const N: usize>
+    ArrayChunksMut<'a, Dyn, N>
+{
+    #[inline]
+    #[must_use]
+    /// Returns the elements at the end of the slice that do not fit into a full chunk.
+    pub const fn into_remainder(self) -> DynSliceMut<'a, Dyn> {
+        self.remainder
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> Iterator
+    for ArrayChunksMut<'a, Dyn, N>
+{
+    type Item = [&'a mut Dyn; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        Some(array::from_fn(|_| {
+            // SAFETY:
+            // The length of the iterator is a non-zero multiple of `N`, so there are
+            // at least `N` elements left.
+            unsafe { self.iter.next().unwrap_unchecked() }
+        }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Use impl for ExactSizeIterator
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize>
+    DoubleEndedIterator for ArrayChunksMut<'a, Dyn, N>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        let mut chunk: Self::Item = array::from_fn(|_| {
+            // SAFETY:
+            // The length of the iterator is a non-zero multiple of `N`, so there are
+            // at least `N` elements left.
+            unsafe { self.iter.next_back().unwrap_unchecked() }
+        });
+        chunk.reverse();
+
+        Some(chunk)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> ExactSizeIterator
+    for ArrayChunksMut<'a, Dyn, N>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len() / N
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, const N: usize> FusedIterator
+    for ArrayChunksMut<'a, Dyn, N>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::add_assign;
+
+    #[test]
+    fn basic() {
+        let mut array = [1, 2, 3, 4, 5, 6, 7];
+        let mut slice = add_assign::new_mut(&mut array);
+
+        let mut iter = slice.chunks_exact_mut_arrays::<2>();
+        assert_eq!(iter.len(), 3);
+        let [a, b] = iter.next().unwrap();
+        *a += 10;
+        *b += 20;
+        let [e, f] = iter.next_back().unwrap();
+        *e += 50;
+        *f += 60;
+        assert_eq!(iter.len(), 1);
+        for [c, d] in iter.by_ref() {
+            *c += 30;
+            *d += 40;
+        }
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+
+        let mut remainder = iter.into_remainder();
+        assert_eq!(remainder.len(), 1);
+        remainder[0] += 70;
+
+        assert_eq!(array, [11, 22, 33, 44, 55, 66, 77]);
+    }
+
+    #[test]
+    fn exact() {
+        let mut array = [1, 2, 3, 4, 5, 6];
+        let mut slice = add_assign::new_mut(&mut array);
+
+        let mut iter = slice.chunks_exact_mut_arrays::<3>();
+        for chunk in iter.by_ref() {
+            for (i, x) in chunk.into_iter().enumerate() {
+                *x += i32::try_from(i).unwrap();
+            }
+        }
+        assert!(iter.into_remainder().is_empty());
+
+        assert_eq!(array, [1, 3, 5, 4, 6, 8]);
+    }
+
+    #[test]
+    fn short() {
+        let mut array = [1, 2];
+        let mut slice = add_assign::new_mut::<i32, _>(&mut array);
+
+        let mut iter = slice.chunks_exact_mut_arrays::<3>();
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert_eq!(iter.into_remainder().len(), 2);
+
+        let mut array: [u8; 0] = [];
+        let mut slice = add_assign::new_mut::<u8, _>(&mut array);
+        assert!(slice.chunks_exact_mut_arrays::<1>().next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero() {
+        let mut array = [1, 2];
+        let mut slice = add_assign::new_mut::<i32, _>(&mut array);
+        let _ = slice.chunks_exact_mut_arrays::<0>();
+    }
+}
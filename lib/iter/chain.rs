@@ -0,0 +1,182 @@
+use core::{
+    iter::FusedIterator,
+    num::NonZeroUsize,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::DynSliceChain;
+
+/// Iterator over non-overlapping chunks of a [`DynSliceChain`], which can span both slices.
+///
+/// This is created by [`DynSliceChain::chunks`].
+#[allow(clippy::module_name_repetitions)]
+pub struct ChainChunks<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    pub(crate) chain: DynSliceChain<'a, Dyn>,
+    pub(crate) chunk_size: NonZeroUsize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for ChainChunks<'a, Dyn> {
+    fn clone(&self) -> Self {
+        Self {
+            chain: self.chain,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Iterator for ChainChunks<'a, Dyn> {
+    type Item = DynSliceChain<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.chain.is_empty() {
+            return None;
+        }
+
+        let mid = self.chunk_size.get().min(self.chain.len());
+        // SAFETY:
+        // `mid` is at most the length of the view.
+        let (chunk, remaining) = unsafe { self.chain.split_at_unchecked(mid) };
+        self.chain = remaining;
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Use impl for ExactSizeIterator
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DoubleEndedIterator
+    for ChainChunks<'a, Dyn>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.chain.len();
+        if len == 0 {
+            return None;
+        }
+
+        // The last chunk holds the remainder, if there is one
+        let last_size = match len % self.chunk_size {
+            0 => self.chunk_size.get(),
+            remainder => remainder,
+        };
+        // SAFETY:
+        // `last_size <= len`, so the split point is in bounds.
+        let (remaining, chunk) = unsafe { self.chain.split_at_unchecked(len - last_size) };
+        self.chain = remaining;
+        Some(chunk)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> ExactSizeIterator
+    for ChainChunks<'a, Dyn>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        let len = self.chain.len();
+        len / self.chunk_size + usize::from(len % self.chunk_size != 0)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> FusedIterator
+    for ChainChunks<'a, Dyn>
+{
+}
+
+/// Iterator over overlapping windows of a [`DynSliceChain`], which can span both slices.
+///
+/// This is created by [`DynSliceChain::windows`].
+#[allow(clippy::module_name_repetitions)]
+pub struct ChainWindows<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    /// The part of the view that the remaining windows are in
+    pub(crate) chain: DynSliceChain<'a, Dyn>,
+    pub(crate) window_size: NonZeroUsize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for ChainWindows<'a, Dyn> {
+    fn clone(&self) -> Self {
+        Self {
+            chain: self.chain,
+            window_size: self.window_size,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Iterator for ChainWindows<'a, Dyn> {
+    type Item = DynSliceChain<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        // SAFETY:
+        // There is at least one window left, so the length of the view is at least
+        // `self.window_size`, which is not 0.
+        let (window, remaining) = unsafe {
+            (
+                self.chain.split_at_unchecked(self.window_size.get()).0,
+                self.chain.split_at_unchecked(1).1,
+            )
+        };
+        self.chain = remaining;
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Use impl for ExactSizeIterator
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DoubleEndedIterator
+    for ChainWindows<'a, Dyn>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let len = self.chain.len();
+        // SAFETY:
+        // There is at least one window left, so `len >= self.window_size`, which is not 0.
+        let (remaining, window) = unsafe {
+            (
+                self.chain.split_at_unchecked(len - 1).0,
+                self.chain
+                    .split_at_unchecked(len - self.window_size.get())
+                    .1,
+            )
+        };
+        self.chain = remaining;
+        Some(window)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> ExactSizeIterator
+    for ChainWindows<'a, Dyn>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.chain.len().saturating_sub(self.window_size.get() - 1)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> FusedIterator
+    for ChainWindows<'a, Dyn>
+{
+}
@@ -0,0 +1,61 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use rand::seq::index::IndexVecIntoIter;
+
+use crate::DynSlice;
+
+/// Iterator over randomly chosen, distinct elements of a [`DynSlice`].
+///
+/// This is created by [`DynSlice::choose_multiple`].
+pub struct ChooseMultiple<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    pub(crate) slice: DynSlice<'a, Dyn>,
+    pub(crate) indices: IndexVecIntoIter,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for ChooseMultiple<'a, Dyn> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator
+    for ChooseMultiple<'a, Dyn>
+{
+    type Item = &'a Dyn;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        debug_assert!(
+            index < self.slice.len(),
+            "[dyn-slice] chosen index is greater than or equal to length!"
+        );
+
+        // SAFETY:
+        // The indices are sampled from `0..self.slice.len()`.
+        let element = unsafe { self.slice.get_unchecked(index) };
+        // SAFETY:
+        // The element is borrowed from the underlying slice, which lives for `'a`.
+        Some(unsafe { &*(element as *const Dyn) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> ExactSizeIterator
+    for ChooseMultiple<'a, Dyn>
+{
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> FusedIterator
+    for ChooseMultiple<'a, Dyn>
+{
+}
@@ -4,12 +4,26 @@ use core::{
     ptr::{DynMetadata, Pointee},
 };
 
-use crate::{utils::extend_lifetime, DynSlice};
+use crate::{
+    utils::{checked_chunk_offset, extend_lifetime, LazySize},
+    DynSlice,
+};
 
 /// Iterator over non-overlapping chunks of a [`DynSlice`].
 pub struct Chunks<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
     pub(crate) slice: DynSlice<'a, Dyn>,
     pub(crate) chunk_size: NonZeroUsize,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: LazySize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Chunks<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the elements of the chunks that have not been yielded yet.
+    pub const fn as_slice(&self) -> DynSlice<'a, Dyn> {
+        self.slice
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for Chunks<'a, Dyn> {
@@ -21,10 +35,11 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for C
         } else {
             let len = cmp::min(self.slice.len(), self.chunk_size.get());
 
-            // SAFETY:
-            // `len` is upper bounded by the slice length, so splitting
-            // here is valid.
-            let (chunk, remaining) = unsafe { self.slice.split_at_unchecked(len) };
+            let (chunk, remaining) =
+                // SAFETY:
+                // `len` is upper bounded by the slice length, so splitting
+                // here is valid.
+                unsafe { self.slice.split_at_with_size_unchecked(len, self.size.get(&self.slice)) };
             let (chunk, remaining) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -53,19 +68,28 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for C
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         // Get the number of elements that should be skipped
-        let Some(skip_len) = self.chunk_size.get().checked_mul(n) else {
+        let Some(skip_len) =
+            checked_chunk_offset(self.chunk_size.get(), n, self.size.get(&self.slice))
+        else {
             self.slice.len = 0;
             return None;
         };
 
-        let Some(remaining) = self.slice.slice(skip_len..) else {
+        if skip_len > self.slice.len() {
             self.slice.len = 0;
             return None;
-        };
+        }
         // SAFETY:
-        // The original slice is immediately replaced with the slice,
+        // `skip_len <= slice.len()`, so splitting here is valid.
+        // The original slice is immediately replaced with the second part,
         // so the lifetime can be extended to match it.
-        self.slice = unsafe { extend_lifetime(remaining) };
+        self.slice = unsafe {
+            extend_lifetime(
+                self.slice
+                    .split_at_with_size_unchecked(skip_len, self.size.get(&self.slice))
+                    .1,
+            )
+        };
 
         self.next()
     }
@@ -94,10 +118,11 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
             // len <= slice length, so this cannot underflow
             let mid = self.slice.len() - len;
 
-            // SAFETY:
-            // As explained above, `mid` is upperbounded by `slice.len()`, so splitting
-            // here is valid.
-            let (remaining, chunk) = unsafe { self.slice.split_at_unchecked(mid) };
+            let (remaining, chunk) =
+                // SAFETY:
+                // As explained above, `mid` is upperbounded by `slice.len()`, so splitting
+                // here is valid.
+                unsafe { self.slice.split_at_with_size_unchecked(mid, self.size.get(&self.slice)) };
             let (remaining, chunk) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -116,7 +141,9 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
 
         if let Some(m) = n.checked_sub(1) {
             // Get the length of all but the last chunk
-            let Some(mut skipped) = m.checked_mul(self.chunk_size.get()) else {
+            let Some(mut skipped) =
+                checked_chunk_offset(self.chunk_size.get(), m, self.size.get(&self.slice))
+            else {
                 self.slice.len = 0;
                 return None;
             };
@@ -211,4 +238,19 @@ mod test {
             s => s.chunks(3).rev(),
         }
     }
+
+    #[test]
+    fn as_slice() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = ped::new::<u8, u8>(&array);
+
+        let mut iter = slice.chunks(2).unwrap();
+        assert!(iter.as_slice() == array[..]);
+        iter.next();
+        assert!(iter.as_slice() == [3, 4, 5][..]);
+        iter.next_back();
+        assert!(iter.as_slice() == [3, 4][..]);
+        iter.next();
+        assert!(iter.as_slice().is_empty());
+    }
 }
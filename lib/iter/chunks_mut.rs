@@ -4,12 +4,34 @@ use core::{
     ptr::{DynMetadata, Pointee},
 };
 
-use crate::{utils::extend_lifetime_mut, DynSliceMut};
+use crate::{
+    utils::{checked_chunk_offset, extend_lifetime_mut},
+    DynSlice, DynSliceMut,
+};
 
 /// Iterator over non-overlapping chunks of a [`DynSliceMut`].
 pub struct ChunksMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
     pub(crate) slice: DynSliceMut<'a, Dyn>,
     pub(crate) chunk_size: NonZeroUsize,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> ChunksMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the elements of the chunks that have not been yielded yet.
+    pub const fn as_slice(&self) -> DynSlice<'_, Dyn> {
+        self.slice.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the iterator into a mutable slice of the elements of the chunks that have not
+    /// been yielded yet, so that they can be used after stopping early.
+    pub const fn into_remainder(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for ChunksMut<'a, Dyn> {
@@ -21,10 +43,11 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for C
         } else {
             let len = cmp::min(self.slice.len(), self.chunk_size.get());
 
-            // SAFETY:
-            // `len` is upper bounded by the slice length, so splitting
-            // here is valid.
-            let (chunk, remaining) = unsafe { self.slice.split_at_unchecked_mut(len) };
+            let (chunk, remaining) =
+                // SAFETY:
+                // `len` is upper bounded by the slice length, so splitting
+                // here is valid.
+                unsafe { self.slice.split_at_with_size_unchecked_mut(len, self.size) };
             let (chunk, remaining) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -53,19 +76,26 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for C
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         // Get the number of elements that should be skipped
-        let Some(skip_len) = self.chunk_size.get().checked_mul(n) else {
+        let Some(skip_len) = checked_chunk_offset(self.chunk_size.get(), n, self.size) else {
             self.slice.0.len = 0;
             return None;
         };
 
-        let Some(remaining) = self.slice.slice_mut(skip_len..) else {
+        if skip_len > self.slice.len() {
             self.slice.0.len = 0;
             return None;
-        };
+        }
         // SAFETY:
-        // The original slice is immediately replaced with the slice,
+        // `skip_len <= slice.len()`, so splitting here is valid.
+        // The original slice is immediately replaced with the second part,
         // so the lifetime can be extended to match it.
-        self.slice = unsafe { extend_lifetime_mut(remaining) };
+        self.slice = unsafe {
+            extend_lifetime_mut(
+                self.slice
+                    .split_at_with_size_unchecked_mut(skip_len, self.size)
+                    .1,
+            )
+        };
 
         self.next()
     }
@@ -94,10 +124,11 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
             // len <= slice length, so this cannot underflow
             let mid = self.slice.len() - len;
 
-            // SAFETY:
-            // As explained above, `mid` is upperbounded by `slice.len()`, so splitting
-            // here is valid.
-            let (remaining, chunk) = unsafe { self.slice.split_at_unchecked_mut(mid) };
+            let (remaining, chunk) =
+                // SAFETY:
+                // As explained above, `mid` is upperbounded by `slice.len()`, so splitting
+                // here is valid.
+                unsafe { self.slice.split_at_with_size_unchecked_mut(mid, self.size) };
             let (remaining, chunk) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -115,7 +146,8 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
 
         if let Some(m) = n.checked_sub(1) {
             // Get the length of all but the last chunk
-            let Some(mut skipped) = m.checked_mul(self.chunk_size.get()) else {
+            let Some(mut skipped) = checked_chunk_offset(self.chunk_size.get(), m, self.size)
+            else {
                 self.slice.0.len = 0;
                 return None;
             };
@@ -264,4 +296,16 @@ mod test {
             s => s.rchunks(3).rev(),
         }
     }
+
+    #[test]
+    fn into_remainder() {
+        let mut array = [1, 2, 3, 4, 5];
+        let mut slice = ped::new_mut::<u8, u8>(&mut array);
+
+        let mut iter = slice.chunks_mut(2).unwrap();
+        iter.next();
+        assert!(iter.as_slice() == [3, 4, 5][..]);
+        let remainder = iter.into_remainder();
+        assert!(remainder == [3, 4, 5][..]);
+    }
 }
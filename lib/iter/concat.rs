@@ -0,0 +1,175 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+    slice,
+};
+
+use crate::{DynSlice, Iter};
+
+/// Iterator over the elements of a sequence of dyn slices, as if they were one slice.
+///
+/// This is created by [`ConcatIter::new`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::{iter::ConcatIter, standard::debug};
+///
+/// let a = [1_u8, 2];
+/// let b = [3];
+/// let slices = [debug::new(&a), debug::new::<u8>(&[]), debug::new(&b)];
+///
+/// let iter = ConcatIter::new(&slices);
+/// # assert_eq!(iter.len(), 3);
+/// # assert_eq!(format!("{:?}", iter.clone().collect::<Vec<_>>()), "[1, 2, 3]");
+/// println!("{:?}", iter.collect::<Vec<_>>()); // [1, 2, 3]
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub struct ConcatIter<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slices: slice::Iter<'b, DynSlice<'a, Dyn>>,
+    front: Option<Iter<'a, Dyn>>,
+    back: Option<Iter<'a, Dyn>>,
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> ConcatIter<'a, 'b, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create an iterator over the elements of each of the `slices` in order.
+    pub fn new(slices: &'b [DynSlice<'a, Dyn>]) -> Self {
+        Self {
+            slices: slices.iter(),
+            front: None,
+            back: None,
+        }
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Clone
+    for ConcatIter<'a, 'b, Dyn>
+{
+    fn clone(&self) -> Self {
+        Self {
+            slices: self.slices.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator
+    for ConcatIter<'a, 'b, Dyn>
+{
+    type Item = &'a Dyn;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self.front.as_mut().and_then(Iterator::next) {
+                return Some(element);
+            }
+
+            match self.slices.next() {
+                Some(&slice) => self.front = Some(slice.into_iter()),
+                // Take the last elements from the back iterator
+                None => return self.back.as_mut().and_then(Iterator::next),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Use impl for ExactSizeIterator
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIterator
+    for ConcatIter<'a, 'b, Dyn>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self.back.as_mut().and_then(DoubleEndedIterator::next_back) {
+                return Some(element);
+            }
+
+            match self.slices.next_back() {
+                Some(&slice) => self.back = Some(slice.into_iter()),
+                // Take the last elements from the front iterator
+                None => return self.front.as_mut().and_then(DoubleEndedIterator::next_back),
+            }
+        }
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> ExactSizeIterator
+    for ConcatIter<'a, 'b, Dyn>
+{
+    fn len(&self) -> usize {
+        self.front.as_ref().map_or(0, ExactSizeIterator::len)
+            + self.slices.clone().map(DynSlice::len).sum::<usize>()
+            + self.back.as_ref().map_or(0, ExactSizeIterator::len)
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> FusedIterator
+    for ConcatIter<'a, 'b, Dyn>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConcatIter;
+    use crate::standard::partial_eq;
+
+    #[test]
+    fn basic() {
+        let (a, b, c) = ([1, 2], [3, 4, 5], [6]);
+        let slices = [
+            partial_eq::new::<u8, u8>(&a),
+            partial_eq::new::<u8, u8>(&[]),
+            partial_eq::new(&b),
+            partial_eq::new(&c),
+        ];
+
+        let mut iter = ConcatIter::new(&slices);
+        for expected in 1..=6 {
+            assert_eq!(iter.len(), 7 - usize::from(expected));
+            assert!(iter.next().unwrap() == &expected);
+        }
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn both_ends() {
+        let (a, b) = ([1, 2, 3], [4, 5]);
+        let slices = [partial_eq::new::<u8, u8>(&a), partial_eq::new(&b)];
+
+        let mut iter = ConcatIter::new(&slices);
+        assert!(iter.next_back().unwrap() == &5);
+        assert!(iter.next().unwrap() == &1);
+        assert_eq!(iter.len(), 3);
+        assert!(iter.next_back().unwrap() == &4);
+        assert!(iter.next_back().unwrap() == &3);
+        assert!(iter.next().unwrap() == &2);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn empty() {
+        let mut iter = ConcatIter::<partial_eq::Dyn<u8>>::new(&[]);
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+
+        let slices = [
+            partial_eq::new::<u8, u8>(&[]),
+            partial_eq::new::<u8, u8>(&[]),
+        ];
+        assert_eq!(ConcatIter::new(&slices).count(), 0);
+    }
+}
@@ -0,0 +1,110 @@
+use core::iter::{FusedIterator, Map};
+
+/// Extension methods for iterators over references to dyn slice elements.
+///
+/// This is implemented for all iterators with an item type of `&Dyn`, such as [`Iter`](crate::Iter)
+/// and user-written iterators.
+///
+/// # Example
+/// ```
+/// use dyn_slice::{iter::DynSliceIterExt, standard::debug};
+///
+/// let slice = debug::new(&[1, 2, 3, 4, 5]);
+/// let odd: Vec<String> = slice
+///     .iter()
+///     .filter_index(|i| i % 2 == 0)
+///     .map_elements(|x| format!("{x:?}"))
+///     .collect();
+/// # assert_eq!(odd, ["1", "3", "5"]);
+/// println!("{odd:?}"); // ["1", "3", "5"]
+/// ```
+pub trait DynSliceIterExt<'a, Dyn: ?Sized + 'a>: Iterator<Item = &'a Dyn> + Sized {
+    #[inline]
+    /// Calls `f` on each element, yielding the results.
+    fn map_elements<R, F: FnMut(&'a Dyn) -> R>(self, f: F) -> Map<Self, F> {
+        self.map(f)
+    }
+
+    #[inline]
+    /// Yields only the elements whose index (in this iterator) satisfies `predicate`.
+    fn filter_index<P: FnMut(usize) -> bool>(self, predicate: P) -> FilterIndex<Self, P> {
+        FilterIndex {
+            iter: self,
+            index: 0,
+            predicate,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + 'a, I: Iterator<Item = &'a Dyn>> DynSliceIterExt<'a, Dyn> for I {}
+
+#[derive(Clone)]
+/// Iterator that filters elements by their index.
+///
+/// This is created by [`DynSliceIterExt::filter_index`].
+pub struct FilterIndex<I, P> {
+    iter: I,
+    index: usize,
+    predicate: P,
+}
+
+impl<I: Iterator, P: FnMut(usize) -> bool> Iterator for FilterIndex<I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let element = self.iter.next()?;
+            let index = self.index;
+            self.index += 1;
+
+            if (self.predicate)(index) {
+                return Some(element);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, P: FnMut(usize) -> bool> FusedIterator for FilterIndex<I, P> {}
+
+#[cfg(test)]
+mod test {
+    use super::DynSliceIterExt;
+    use crate::standard::partial_eq;
+
+    #[test]
+    fn map_elements() {
+        let slice = partial_eq::new::<u8, _>(&[1, 2, 3]);
+        let mut iter = slice.iter().map_elements(|x| x == &2);
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(false));
+        assert_eq!(iter.next_back(), Some(false));
+        assert_eq!(iter.next(), Some(true));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn filter_index() {
+        let slice = partial_eq::new::<u8, _>(&[1, 2, 3, 4, 5]);
+        let mut iter = slice.iter().filter_index(|i| i % 2 == 1);
+
+        assert_eq!(iter.size_hint(), (0, Some(5)));
+        assert!(iter.next().unwrap() == &2);
+        assert!(iter.next().unwrap() == &4);
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+
+        let mut iter = slice.iter().skip(1).filter_index(|i| i < 2);
+        assert!(iter.next().unwrap() == &2);
+        assert!(iter.next().unwrap() == &3);
+        assert!(iter.next().is_none());
+
+        let slice = partial_eq::new::<u8, u8>(&[]);
+        assert!(slice.iter().filter_index(|_| true).next().is_none());
+    }
+}
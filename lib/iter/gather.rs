@@ -0,0 +1,167 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+    slice,
+};
+
+use crate::DynSlice;
+
+/// Iterator over the elements of a [`DynSlice`] at a list of indices.
+///
+/// Each item is `None` if its index is out of bounds.
+///
+/// This is created by [`DynSlice::gather_iter`].
+pub struct Gather<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSlice<'a, Dyn>,
+    indices: slice::Iter<'b, usize>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns an iterator over the elements at each of the `indices`, in order.
+    ///
+    /// Each item is `None` if its index is out of bounds. Indices may repeat.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[1, 2, 3, 4]);
+    ///
+    /// for element in slice.gather_iter(&[3, 0, 7]) {
+    ///     println!("{element:?}"); // Some(4), then Some(1), then None
+    /// }
+    /// # let elements: Vec<_> = slice.gather_iter(&[3, 0, 7]).collect();
+    /// # assert_eq!(format!("{elements:?}"), "[Some(4), Some(1), None]");
+    /// ```
+    pub fn gather_iter<'b>(&self, indices: &'b [usize]) -> Gather<'_, 'b, Dyn> {
+        Gather {
+            slice: *self,
+            indices: indices.iter(),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    /// Returns the elements at each of the `indices`, in order, or `None` if any index is out of
+    /// bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[1, 2, 3, 4]);
+    ///
+    /// let elements = slice.gather(&[3, 0, 0]).unwrap();
+    /// # assert_eq!(format!("{elements:?}"), "[4, 1, 1]");
+    /// println!("{elements:?}"); // [4, 1, 1]
+    /// assert!(slice.gather(&[1, 4]).is_none());
+    /// ```
+    pub fn gather(&self, indices: &[usize]) -> Option<Vec<&Dyn>> {
+        self.gather_iter(indices).collect()
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Clone
+    for Gather<'a, 'b, Dyn>
+{
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator
+    for Gather<'a, 'b, Dyn>
+{
+    type Item = Option<&'a Dyn>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let &index = self.indices.next()?;
+        Some(self.slice.get_ref(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.indices.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let &index = self.indices.nth(n)?;
+        Some(self.slice.get_ref(index))
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIterator
+    for Gather<'a, 'b, Dyn>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let &index = self.indices.next_back()?;
+        Some(self.slice.get_ref(index))
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> ExactSizeIterator
+    for Gather<'a, 'b, Dyn>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<'a, 'b, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> FusedIterator
+    for Gather<'a, 'b, Dyn>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::partial_eq;
+
+    #[test]
+    fn gather_iter() {
+        let array = [1, 2, 3, 4];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        let mut iter = slice.gather_iter(&[2, 2, 9, 0, 3]);
+        assert_eq!(iter.len(), 5);
+        assert!(iter.next().unwrap().unwrap() == &3);
+        assert!(iter.next_back().unwrap().unwrap() == &4);
+        assert!(iter.next().unwrap().unwrap() == &3);
+        assert!(iter.next().unwrap().is_none());
+        assert!(iter.next().unwrap().unwrap() == &1);
+        assert!(iter.next().is_none());
+
+        assert!(slice.gather_iter(&[0, 1, 2]).nth(2).unwrap().unwrap() == &3);
+        assert_eq!(slice.gather_iter(&[]).count(), 0);
+    }
+
+    #[test]
+    fn gather() {
+        let array = [1, 2, 3, 4];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        let elements = slice.gather(&[3, 1, 3]).unwrap();
+        assert!(elements[0] == &4 && elements[1] == &2 && elements[2] == &4);
+        assert!(slice.gather(&[]).unwrap().is_empty());
+        assert!(slice.gather(&[0, 4]).is_none());
+
+        let empty = partial_eq::new::<u8, u8>(&[]);
+        assert!(empty.gather(&[0]).is_none());
+    }
+}
@@ -1,19 +1,111 @@
 use core::{
     iter::FusedIterator,
     mem::transmute,
-    ptr::{metadata, DynMetadata, Pointee},
+    ptr::{self, DynMetadata, Pointee},
 };
 
-use crate::DynSlice;
+use crate::{
+    utils::{byte_offset_unchecked, LazySize},
+    DynSlice,
+};
 
 /// Dyn slice iterator
 pub struct Iter<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
     pub(crate) slice: DynSlice<'a, Dyn>,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: LazySize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iter<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create an iterator over `slice`.
+    pub(crate) const fn new(slice: DynSlice<'a, Dyn>) -> Self {
+        Self {
+            slice,
+            size: LazySize::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been yielded yet, like
+    /// [`slice::Iter::as_slice`](core::slice::Iter::as_slice).
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1, 2, 3, 4];
+    /// let slice = debug::new(&array);
+    ///
+    /// let mut iter = slice.iter();
+    /// iter.next();
+    /// iter.next_back();
+    /// let rest = iter.as_slice();
+    /// # assert_eq!(format!("{rest:?}"), "[2, 3]");
+    /// println!("{rest:?}"); // [2, 3]
+    /// ```
+    pub const fn as_slice(&self) -> DynSlice<'a, Dyn> {
+        self.slice
+    }
+
+    /// Returns the next element, without checking that there is one.
+    ///
+    /// # Safety
+    /// The caller must ensure that the iterator is not empty.
+    pub(crate) unsafe fn next_unchecked(&mut self) -> &'a Dyn {
+        debug_assert!(!self.slice.is_empty(), "[dyn-slice] iterator is empty!");
+
+        // SAFETY:
+        // As the slice is not empty, it must have a first element and a valid vtable pointer, which
+        // can be transmuted to `DynMetadata<Dyn>`.
+        // The data is guaranteed to live for at least 'a, and not have a mutable reference to it
+        // in that time, so the lifetime can be extended.
+        let element: &'a Dyn = transmute(self.slice.first_unchecked());
+
+        // SAFETY:
+        // As the slice is not empty, incrementing the pointer by one unit of the underlying type will
+        // yield either a valid pointer of the next element, or will yield a pointer one byte after the
+        // last element, which is valid as per [`core::ptr::const_ptr::add`]'s safety section.
+        self.slice.data = self.slice.data.byte_add(self.size.get(&self.slice));
+        self.slice.len -= 1;
+
+        element
+    }
+
+    /// Returns the next element from the back, without checking that there is one.
+    ///
+    /// # Safety
+    /// The caller must ensure that the iterator is not empty.
+    pub(crate) unsafe fn next_back_unchecked(&mut self) -> &'a Dyn {
+        debug_assert!(!self.slice.is_empty(), "[dyn-slice] iterator is empty!");
+
+        self.slice.len -= 1;
+
+        // SAFETY:
+        // As the slice was not empty, it has a last element (now at `slice.len()`) and a valid
+        // vtable pointer, which can be transmuted to `DynMetadata<Dyn>`.
+        // The data is guaranteed to live for at least 'a, and not have a mutable reference to it
+        // in that time, so the lifetime can be extended.
+        let element: &'a Dyn = &*ptr::from_raw_parts(
+            self.slice.data.byte_add(byte_offset_unchecked(
+                self.size.get(&self.slice),
+                self.slice.len,
+            )),
+            transmute(self.slice.vtable_ptr),
+        );
+
+        element
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Clone for Iter<'a, Dyn> {
     fn clone(&self) -> Self {
-        Self { slice: self.slice }
+        Self {
+            slice: self.slice,
+            size: self.size,
+        }
     }
 }
 
@@ -25,20 +117,8 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for I
             None
         } else {
             // SAFETY:
-            // As the slice is not empty, it must have a first element and a valid vtable pointer, which
-            // can be transmuted to `DynMetadata<Dyn>`.
-            // The data is guaranteed to live for at least 'a, and not have a mutable reference to it
-            // in that time, so the lifetime can be extended.
-            let element: &'a Dyn = unsafe { transmute(self.slice.first_unchecked()) };
-
-            // SAFETY:
-            // As the slice is not empty, incrementing the pointer by one unit of the underlying type will
-            // yield either a valid pointer of the next element, or will yield a pointer one byte after the
-            // last element, which is valid as per [`core::ptr::const_ptr::add`]'s safety section.
-            self.slice.data = unsafe { self.slice.data.byte_add(metadata(element).size_of()) };
-            self.slice.len -= 1;
-
-            Some(element)
+            // The slice is not empty.
+            Some(unsafe { self.next_unchecked() })
         }
     }
 
@@ -59,15 +139,14 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for I
             return None;
         }
 
-        // SAFETY:
-        // The above conditional guarantees that the slice is not empty and therefore has a valid vtable
-        // pointer, which can be transmuted to a `DynMetadata<Dyn>`.
-        let metadata: DynMetadata<Dyn> = unsafe { transmute(self.slice.vtable_ptr) };
-
         // SAFETY:
         // As `n < slice.len()`, adding `n` units of the underlying type to the pointer will yield a valid
-        // pointer in the slice.
-        self.slice.data = unsafe { self.slice.data.byte_add(metadata.size_of() * n) };
+        // pointer in the slice, and the offset is at most the size of the slice.
+        self.slice.data = unsafe {
+            self.slice
+                .data
+                .byte_add(byte_offset_unchecked(self.size.get(&self.slice), n))
+        };
         self.slice.len -= n;
 
         self.next()
@@ -88,17 +167,9 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
         if self.slice.is_empty() {
             None
         } else {
-            let element: &'a Dyn =
-                // SAFETY:
-                // As the slice is not empty, it must have a last element (at `slice.len() - 1`) and a valid
-                // vtable pointer, which can be transmuted to `DynMetadata<Dyn>`.
-                // The data is guaranteed to live for at least 'a, and not have a mutable reference to it
-                // in that time, so the lifetime can be extended.
-                unsafe { transmute(self.slice.get_unchecked(self.slice.len - 1)) };
-
-            self.slice.len -= 1;
-
-            Some(element)
+            // SAFETY:
+            // The slice is not empty.
+            Some(unsafe { self.next_back_unchecked() })
         }
     }
 
@@ -285,4 +356,42 @@ mod test {
         );
         assert_eq!(iter.size_hint().0, 0, "expected 0 elements left");
     }
+
+    #[test]
+    fn test_cached_size() {
+        let slice = partial_eq::new::<u8, u8>(&[]);
+        let mut iter = slice.iter();
+        assert_eq!(iter.size.get(&iter.slice), 0);
+        assert!(slice.iter().next_back().is_none());
+
+        let array = [(); 3];
+        let slice = partial_eq::new::<(), _>(&array);
+        let mut iter = slice.iter();
+        assert_eq!(iter.size.get(&iter.slice), 0);
+        assert!(iter.next_back().unwrap() == &());
+        assert_eq!(iter.count(), 2);
+
+        let array = [2_u32, 3, 5];
+        let slice = partial_eq::new::<u32, _>(&array);
+        let mut iter = slice.iter();
+        assert_eq!(iter.size.get(&iter.slice), 4);
+        assert!(iter.next_back().unwrap() == &5);
+        assert!(iter.next().unwrap() == &2);
+        assert!(iter.next_back().unwrap() == &3);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let array = [2, 3, 5, 7, 11];
+        let slice = partial_eq::new::<u8, _>(&array);
+
+        let mut iter = slice.iter();
+        assert!(iter.as_slice() == array[..]);
+        iter.nth(1);
+        iter.nth_back(1);
+        assert!(iter.as_slice() == [5][..]);
+        iter.next();
+        assert!(iter.as_slice().is_empty());
+    }
 }
@@ -1,14 +1,134 @@
 use core::{
     iter::FusedIterator,
     mem::transmute,
-    ptr::{metadata, DynMetadata, Pointee},
+    ptr::{self, DynMetadata, Pointee},
 };
 
-use crate::DynSliceMut;
+use crate::{utils::byte_offset_unchecked, DynSlice, DynSliceMut};
 
 /// Mutable dyn slice iterator
 pub struct IterMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
     pub(crate) slice: DynSliceMut<'a, Dyn>,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> IterMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create a mutable iterator over `slice`.
+    pub(crate) fn new(slice: DynSliceMut<'a, Dyn>) -> Self {
+        Self {
+            size: slice.element_size(),
+            slice,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been yielded yet, like
+    /// [`slice::IterMut::as_slice`](core::slice::IterMut::as_slice).
+    pub const fn as_slice(&self) -> DynSlice<'_, Dyn> {
+        self.slice.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the elements that have not been yielded yet as a mutable slice.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// let mut iter = slice.iter_mut();
+    /// *iter.next().unwrap() += 10;
+    /// iter.as_mut_slice().iter_mut().for_each(|x| *x += 20);
+    /// *iter.next().unwrap() += 30;
+    /// assert_eq!(array, [11, 52, 23, 24]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> DynSliceMut<'_, Dyn> {
+        // SAFETY:
+        // The created slice is from index 0 and has the same length as the
+        // original slice, so must be valid.
+        unsafe { self.slice.slice_unchecked_mut(0, self.slice.len) }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the iterator into a mutable slice of the elements that have not been yielded
+    /// yet, like [`slice::IterMut::into_slice`](core::slice::IterMut::into_slice).
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::add_assign;
+    ///
+    /// let mut array = [1, 2, 3, 4];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// let mut iter = slice.iter_mut();
+    /// iter.next_back();
+    /// let mut rest = iter.into_slice();
+    /// *rest.first_mut().unwrap() += 10;
+    /// assert_eq!(rest.len(), 3);
+    /// # drop(rest);
+    /// # assert_eq!(array, [11, 2, 3, 4]);
+    /// ```
+    pub const fn into_slice(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
+
+    /// Returns the next element, without checking that there is one.
+    ///
+    /// # Safety
+    /// The caller must ensure that the iterator is not empty.
+    pub(crate) unsafe fn next_unchecked(&mut self) -> &'a mut Dyn {
+        debug_assert!(!self.slice.is_empty(), "[dyn-slice] iterator is empty!");
+
+        // SAFETY:
+        // As the slice is not empty, it must have a first element and a valid vtable pointer, which
+        // can be transmuted to `DynMetadata<Dyn>`.
+        // The data is guaranteed to live for at least 'a, and not have another mutable reference to it
+        // in that time, so the lifetime can be extended.
+        let element: &'a mut Dyn = transmute(self.slice.first_unchecked_mut());
+
+        // SAFETY:
+        // As the slice is not empty, incrementing the pointer by one unit of the underlying type will
+        // yield either a valid pointer of the next element, or will yield a pointer one byte after the
+        // last element, which is valid as per [`core::ptr::const_ptr::add`]'s safety section.
+        self.slice.0.data = self.slice.data.byte_add(self.size);
+        self.slice.0.len -= 1;
+
+        element
+    }
+
+    /// Returns the next element from the back, without checking that there is one.
+    ///
+    /// # Safety
+    /// The caller must ensure that the iterator is not empty.
+    pub(crate) unsafe fn next_back_unchecked(&mut self) -> &'a mut Dyn {
+        debug_assert!(!self.slice.is_empty(), "[dyn-slice] iterator is empty!");
+
+        self.slice.0.len -= 1;
+
+        // SAFETY:
+        // As the slice was not empty, it has a last element (now at `slice.len()`) and a valid
+        // vtable pointer, which can be transmuted to `DynMetadata<Dyn>`.
+        // The data is guaranteed to live for at least 'a, and not have another mutable reference to it
+        // in that time, so the lifetime can be extended.
+        let element: &'a mut Dyn = &mut *ptr::from_raw_parts_mut(
+            self.slice
+                .0
+                .data
+                .cast_mut()
+                .byte_add(byte_offset_unchecked(self.size, self.slice.len)),
+            transmute(self.slice.vtable_ptr),
+        );
+
+        element
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for IterMut<'a, Dyn> {
@@ -19,20 +139,8 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for I
             None
         } else {
             // SAFETY:
-            // As the slice is not empty, it must have a first element and a valid vtable pointer, which
-            // can be transmuted to `DynMetadata<Dyn>`.
-            // The data is guaranteed to live for at least 'a, and not have a mutable reference to it
-            // in that time, so the lifetime can be extended.
-            let element: &'a mut Dyn = unsafe { transmute(self.slice.first_unchecked_mut()) };
-
-            // SAFETY:
-            // As the slice is not empty, incrementing the pointer by one unit of the underlying type will
-            // yield either a valid pointer of the next element, or will yield a pointer one byte after the
-            // last element, which is valid as per [`core::ptr::const_ptr::add`]'s safety section.
-            self.slice.0.data = unsafe { self.slice.data.byte_add(metadata(element).size_of()) };
-            self.slice.0.len -= 1;
-
-            Some(element)
+            // The slice is not empty.
+            Some(unsafe { self.next_unchecked() })
         }
     }
 
@@ -53,15 +161,14 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for I
             return None;
         }
 
-        // SAFETY:
-        // The above conditional guarantees that the slice is not empty and therefore has a valid vtable
-        // pointer, which can be transmuted to a `DynMetadata<Dyn>`.
-        let metadata: DynMetadata<Dyn> = unsafe { transmute(self.slice.vtable_ptr) };
-
         // SAFETY:
         // As `n < slice.len()`, adding `n` units of the underlying type to the pointer will yield a valid
-        // pointer in the slice.
-        self.slice.0.data = unsafe { self.slice.data.byte_add(metadata.size_of() * n) };
+        // pointer in the slice, and the offset is at most the size of the slice.
+        self.slice.0.data = unsafe {
+            self.slice
+                .data
+                .byte_add(byte_offset_unchecked(self.size, n))
+        };
         self.slice.0.len -= n;
 
         self.next()
@@ -82,17 +189,9 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
         if self.slice.is_empty() {
             None
         } else {
-            let element: &'a mut Dyn =
-                // SAFETY:
-                // As the slice is not empty, it must have a last element (at `slice.len() - 1`) and a valid
-                // vtable pointer, which can be transmuted to `DynMetadata<Dyn>`.
-                // The data is guaranteed to live for at least 'a, and not have another mutable reference to it
-                // in that time, so the lifetime can be extended.
-                unsafe { transmute(self.slice.get_unchecked_mut(self.slice.len - 1)) };
-
-            self.slice.0.len -= 1;
-
-            Some(element)
+            // SAFETY:
+            // The slice is not empty.
+            Some(unsafe { self.next_back_unchecked() })
         }
     }
 
@@ -124,7 +223,10 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> FusedIterator
 
 #[cfg(test)]
 mod test {
-    use crate::standard::partial_eq;
+    use crate::{
+        standard::partial_eq,
+        test::{ped, test_iter},
+    };
 
     #[test]
     fn test_next() {
@@ -288,4 +390,109 @@ mod test {
         );
         assert_eq!(iter.size_hint().0, 0, "expected 0 elements left");
     }
+
+    #[test]
+    fn oracle() {
+        test_iter! {
+            mut [0_u8; 0],
+            ds => ds.iter_mut(),
+            s => s.iter(),
+        }
+        test_iter! {
+            mut [1],
+            ds => ds.iter_mut(),
+            s => s.iter(),
+        }
+        test_iter! {
+            mut [2, 3, 5, 7, 11],
+            ds => ds.iter_mut(),
+            s => s.iter(),
+        }
+    }
+
+    #[test]
+    fn oracle_back() {
+        test_iter! {
+            mut [0_u8; 0],
+            ds => ds.iter_mut().rev(),
+            s => s.iter().rev(),
+        }
+        test_iter! {
+            mut [1],
+            ds => ds.iter_mut().rev(),
+            s => s.iter().rev(),
+        }
+        test_iter! {
+            mut [2, 3, 5, 7, 11],
+            ds => ds.iter_mut().rev(),
+            s => s.iter().rev(),
+        }
+    }
+
+    #[test]
+    fn oracle_nth() {
+        test_iter! {@nth
+            mut [0_u8; 0],
+            ds => ds.iter_mut(),
+            s => s.iter(),
+        }
+        test_iter! {@nth
+            mut [1],
+            ds => ds.iter_mut(),
+            s => s.iter(),
+        }
+        test_iter! {@nth
+            mut [2, 3, 5, 7, 11],
+            ds => ds.iter_mut(),
+            s => s.iter(),
+        }
+    }
+
+    #[test]
+    fn oracle_nth_back() {
+        test_iter! {@nth
+            mut [0_u8; 0],
+            ds => ds.iter_mut().rev(),
+            s => s.iter().rev(),
+        }
+        test_iter! {@nth
+            mut [1],
+            ds => ds.iter_mut().rev(),
+            s => s.iter().rev(),
+        }
+        test_iter! {@nth
+            mut [2, 3, 5, 7, 11],
+            ds => ds.iter_mut().rev(),
+            s => s.iter().rev(),
+        }
+    }
+
+    #[test]
+    fn oracle_last() {
+        let mut array = [0_u8; 0];
+        assert!(ped::new_mut::<u8, u8>(&mut array)
+            .iter_mut()
+            .last()
+            .is_none());
+
+        let mut array = [2, 3, 5, 7, 11];
+        let mut slice = ped::new_mut::<u8, u8>(&mut array);
+        let mut iter = slice.iter_mut();
+        iter.next_back();
+        assert_eq!(iter.last().unwrap(), &7);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut array = [2, 3, 5, 7, 11];
+        let mut slice = partial_eq::new_mut::<u8, _>(&mut array);
+
+        let mut iter = slice.iter_mut();
+        iter.nth(1);
+        iter.nth_back(1);
+        assert!(iter.as_slice() == [5][..]);
+        assert!(iter.as_mut_slice() == [5][..]);
+        assert!(iter.next().unwrap() == &5);
+        assert!(iter.into_slice().is_empty());
+    }
 }
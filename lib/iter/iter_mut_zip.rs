@@ -0,0 +1,191 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{DynSliceMut, IterMut};
+
+/// Iterator over pairs of mutable references to the elements of two dyn slices of equal length,
+/// in lockstep.
+///
+/// This is created by [`DynSliceMut::iter_mut_zip`].
+pub struct IterMutZip<
+    'a,
+    'b,
+    Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+    Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>>,
+> {
+    a: IterMut<'a, Dyn>,
+    b: IterMut<'b, Dyn2>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    /// Returns an iterator over pairs of mutable references to the elements of this slice and
+    /// `other`, in lockstep.
+    ///
+    /// Unlike zipping two iterators, this only does one bounds check per step.
+    ///
+    /// # Panics
+    /// Panics if the slices have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::{add_assign, to};
+    ///
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [10_u8, 20, 30];
+    /// let mut sa = add_assign::new_mut::<u32, _>(&mut a);
+    /// let mut sb = to::new_mut::<u32, _>(&mut b);
+    ///
+    /// for (x, y) in sa.iter_mut_zip(&mut sb) {
+    ///     *x += y.to();
+    /// }
+    /// assert_eq!(a, [11, 22, 33]);
+    /// ```
+    pub fn iter_mut_zip<'s, 'b, Dyn2>(
+        &'s mut self,
+        other: &'b mut DynSliceMut<Dyn2>,
+    ) -> IterMutZip<'s, 'b, Dyn, Dyn2>
+    where
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>>,
+    {
+        assert!(self.len() == other.len(), "slices have different lengths");
+
+        IterMutZip {
+            a: self.iter_mut(),
+            b: other.iter_mut(),
+        }
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+    > Iterator for IterMutZip<'a, 'b, Dyn, Dyn2>
+{
+    type Item = (&'a mut Dyn, &'b mut Dyn2);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        debug_assert_eq!(
+            self.a.len(),
+            self.b.len(),
+            "[dyn-slice] zipped slices have different lengths!"
+        );
+
+        if self.a.slice.is_empty() {
+            None
+        } else {
+            // SAFETY:
+            // Both iterators have the same length, so if one is not empty,
+            // neither is the other.
+            unsafe {
+                Some((
+                    self.a.next().unwrap_unchecked(),
+                    self.b.next().unwrap_unchecked(),
+                ))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.a.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.a.count()
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+    > DoubleEndedIterator for IterMutZip<'a, 'b, Dyn, Dyn2>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.a.slice.is_empty() {
+            None
+        } else {
+            // SAFETY:
+            // Both iterators have the same length, so if one is not empty,
+            // neither is the other.
+            unsafe {
+                Some((
+                    self.a.next_back().unwrap_unchecked(),
+                    self.b.next_back().unwrap_unchecked(),
+                ))
+            }
+        }
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+    > ExactSizeIterator for IterMutZip<'a, 'b, Dyn, Dyn2>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len()
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+    > FusedIterator for IterMutZip<'a, 'b, Dyn, Dyn2>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::{add_assign, to};
+
+    #[test]
+    fn basic() {
+        let mut a: [u16; 4] = [1, 2, 3, 4];
+        let mut b: [u8; 4] = [10, 20, 30, 40];
+        let mut sa = add_assign::new_mut::<u16, _>(&mut a);
+        let mut sb = to::new_mut::<u16, _>(&mut b);
+
+        let mut iter = sa.iter_mut_zip(&mut sb);
+        assert_eq!(iter.len(), 4);
+        let (x, y) = iter.next().unwrap();
+        *x += y.to();
+        let (x, y) = iter.next_back().unwrap();
+        *x += y.to() * 2;
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.count(), 2);
+
+        assert_eq!(a, [11, 2, 3, 84]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut sa = add_assign::new_mut::<u8, u8>(&mut []);
+        let mut sb = to::new_mut::<u8, u8>(&mut []);
+
+        assert!(sa.iter_mut_zip(&mut sb).next().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "slices have different lengths")]
+    fn different_lengths() {
+        let mut a = [1_u8, 2, 3];
+        let mut b = [1_u8, 2];
+        let mut sa = add_assign::new_mut::<u8, _>(&mut a);
+        let mut sb = to::new_mut::<u8, _>(&mut b);
+
+        let _ = sa.iter_mut_zip(&mut sb);
+    }
+}
@@ -1,17 +1,50 @@
+mod array_chunks;
+mod array_chunks_mut;
+mod chain;
+#[cfg(all(feature = "rand", feature = "alloc"))]
+mod choose_multiple;
 mod chunks;
 mod chunks_mut;
+mod concat;
+mod extension;
+mod gather;
 #[allow(clippy::module_inception)]
 mod iter;
 mod iter_mut;
+mod iter_mut_zip;
 mod rchunks;
 mod rchunks_mut;
+mod split;
+mod split_inclusive;
+mod split_inclusive_mut;
+mod split_mut;
 mod windows;
+mod with_metadata;
+mod zip_with;
 
+pub use array_chunks::ArrayChunks;
+pub use array_chunks_mut::ArrayChunksMut;
+pub use chain::{ChainChunks, ChainWindows};
+#[cfg(all(feature = "rand", feature = "alloc"))]
+#[cfg_attr(doc, doc(cfg(all(feature = "rand", feature = "alloc"))))]
+pub use choose_multiple::ChooseMultiple;
 pub use chunks::Chunks;
 pub use chunks_mut::ChunksMut;
+#[allow(clippy::module_name_repetitions)]
+pub use concat::ConcatIter;
+pub use extension::{DynSliceIterExt, FilterIndex};
+pub use gather::Gather;
 pub use iter::Iter;
 #[allow(clippy::module_name_repetitions)]
 pub use iter_mut::IterMut;
+#[allow(clippy::module_name_repetitions)]
+pub use iter_mut_zip::IterMutZip;
 pub use rchunks::RChunks;
 pub use rchunks_mut::RChunksMut;
+pub use split::{RSplit, RSplitN, Split, SplitN};
+pub use split_inclusive::SplitInclusive;
+pub use split_inclusive_mut::SplitInclusiveMut;
+pub use split_mut::{RSplitMut, RSplitNMut, SplitMut, SplitNMut};
 pub use windows::Windows;
+pub use with_metadata::WithMetadata;
+pub use zip_with::ZipWith;
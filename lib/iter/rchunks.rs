@@ -4,12 +4,26 @@ use core::{
     ptr::{DynMetadata, Pointee},
 };
 
-use crate::{utils::extend_lifetime, DynSlice};
+use crate::{
+    utils::{checked_chunk_offset, extend_lifetime, LazySize},
+    DynSlice,
+};
 
 /// Iterator over non-overlapping chunks of a [`DynSlice`] from right to left.
 pub struct RChunks<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
     pub(crate) slice: DynSlice<'a, Dyn>,
     pub(crate) chunk_size: NonZeroUsize,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: LazySize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> RChunks<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the elements of the chunks that have not been yielded yet.
+    pub const fn as_slice(&self) -> DynSlice<'a, Dyn> {
+        self.slice
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for RChunks<'a, Dyn> {
@@ -24,10 +38,11 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for R
             // len <= slice length, so this cannot underflow
             let mid = self.slice.len() - len;
 
-            // SAFETY:
-            // `len` is upper bounded by the slice length, so splitting
-            // here is valid.
-            let (remaining, chunk) = unsafe { self.slice.split_at_unchecked(mid) };
+            let (remaining, chunk) =
+                // SAFETY:
+                // `len` is upper bounded by the slice length, so splitting
+                // here is valid.
+                unsafe { self.slice.split_at_with_size_unchecked(mid, self.size.get(&self.slice)) };
             let (remaining, chunk) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -56,7 +71,9 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for R
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         // Get the number of elements that should be skipped
-        let Some(skip_len) = self.chunk_size.get().checked_mul(n) else {
+        let Some(skip_len) =
+            checked_chunk_offset(self.chunk_size.get(), n, self.size.get(&self.slice))
+        else {
             self.slice.len = 0;
             return None;
         };
@@ -88,9 +105,10 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
                 len = self.chunk_size.get();
             }
 
-            // SAFETY:
-            // `len` is upperbounded by the slice length.
-            let (chunk, remaining) = unsafe { self.slice.split_at_unchecked(len) };
+            let (chunk, remaining) =
+                // SAFETY:
+                // `len` is upperbounded by the slice length.
+                unsafe { self.slice.split_at_with_size_unchecked(len, self.size.get(&self.slice)) };
             let (chunk, remaining) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -109,7 +127,9 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
 
         if let Some(m) = n.checked_sub(1) {
             // Get the length of all but the last chunk
-            let Some(mut skipped) = m.checked_mul(self.chunk_size.get()) else {
+            let Some(mut skipped) =
+                checked_chunk_offset(self.chunk_size.get(), m, self.size.get(&self.slice))
+            else {
                 self.slice.len = 0;
                 return None;
             };
@@ -124,15 +144,21 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
             // Add the last chunk
             skipped = skipped.saturating_add(last);
 
-            let Some(remaining) = self.slice.slice(skipped..) else {
+            if skipped > self.slice.len() {
                 self.slice.len = 0;
                 return None;
-            };
+            }
             // SAFETY:
-            // The original slice is immediately replaced with the slice,
+            // `skipped <= slice.len()`, so splitting here is valid.
+            // The original slice is immediately replaced with the second part,
             // so the lifetime can be extended to match it.
-            let remaining = unsafe { extend_lifetime(remaining) };
-            self.slice = remaining;
+            self.slice = unsafe {
+                extend_lifetime(
+                    self.slice
+                        .split_at_with_size_unchecked(skipped, self.size.get(&self.slice))
+                        .1,
+                )
+            };
         }
 
         self.next_back()
@@ -213,4 +239,16 @@ mod test {
             s => s.rchunks(3).rev(),
         }
     }
+
+    #[test]
+    fn as_slice() {
+        let array = [1, 2, 3, 4, 5];
+        let slice = ped::new::<u8, u8>(&array);
+
+        let mut iter = slice.rchunks(2).unwrap();
+        iter.next();
+        assert!(iter.as_slice() == [1, 2, 3][..]);
+        iter.next_back();
+        assert!(iter.as_slice() == [2, 3][..]);
+    }
 }
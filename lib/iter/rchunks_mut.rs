@@ -4,12 +4,34 @@ use core::{
     ptr::{DynMetadata, Pointee},
 };
 
-use crate::{utils::extend_lifetime_mut, DynSliceMut};
+use crate::{
+    utils::{checked_chunk_offset, extend_lifetime_mut},
+    DynSlice, DynSliceMut,
+};
 
 /// Iterator over non-overlapping chunks of a [`DynSliceMut`] from right to left.
 pub struct RChunksMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
     pub(crate) slice: DynSliceMut<'a, Dyn>,
     pub(crate) chunk_size: NonZeroUsize,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> RChunksMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the elements of the chunks that have not been yielded yet.
+    pub const fn as_slice(&self) -> DynSlice<'_, Dyn> {
+        self.slice.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the iterator into a mutable slice of the elements of the chunks that have not
+    /// been yielded yet, so that they can be used after stopping early.
+    pub const fn into_remainder(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for RChunksMut<'a, Dyn> {
@@ -24,10 +46,11 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for R
             // len <= slice length, so this cannot underflow
             let mid = self.slice.len() - len;
 
-            // SAFETY:
-            // `len` is upper bounded by the slice length, so splitting
-            // here is valid.
-            let (remaining, chunk) = unsafe { self.slice.split_at_unchecked_mut(mid) };
+            let (remaining, chunk) =
+                // SAFETY:
+                // `len` is upper bounded by the slice length, so splitting
+                // here is valid.
+                unsafe { self.slice.split_at_with_size_unchecked_mut(mid, self.size) };
             let (remaining, chunk) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -56,7 +79,7 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for R
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         // Get the number of elements that should be skipped
-        let Some(skip_len) = self.chunk_size.get().checked_mul(n) else {
+        let Some(skip_len) = checked_chunk_offset(self.chunk_size.get(), n, self.size) else {
             self.slice.0.len = 0;
             return None;
         };
@@ -88,9 +111,10 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
                 len = self.chunk_size.get();
             }
 
-            // SAFETY:
-            // `len` is upperbounded by the slice length.
-            let (chunk, remaining) = unsafe { self.slice.split_at_unchecked_mut(len) };
+            let (chunk, remaining) =
+                // SAFETY:
+                // `len` is upperbounded by the slice length.
+                unsafe { self.slice.split_at_with_size_unchecked_mut(len, self.size) };
             let (chunk, remaining) =
                 // SAFETY:
                 // The original slice is immediately replaced with one part,
@@ -109,7 +133,8 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
 
         if let Some(m) = n.checked_sub(1) {
             // Get the length of all but the last chunk
-            let Some(mut skipped) = m.checked_mul(self.chunk_size.get()) else {
+            let Some(mut skipped) = checked_chunk_offset(self.chunk_size.get(), m, self.size)
+            else {
                 self.slice.0.len = 0;
                 return None;
             };
@@ -124,15 +149,21 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIte
             // Add the last chunk
             skipped = skipped.saturating_add(last);
 
-            let Some(remaining) = self.slice.slice_mut(skipped..) else {
+            if skipped > self.slice.len() {
                 self.slice.0.len = 0;
                 return None;
-            };
+            }
             // SAFETY:
-            // The original slice is immediately replaced with the slice,
+            // `skipped <= slice.len()`, so splitting here is valid.
+            // The original slice is immediately replaced with the second part,
             // so the lifetime can be extended to match it.
-            let remaining = unsafe { extend_lifetime_mut(remaining) };
-            self.slice = remaining;
+            self.slice = unsafe {
+                extend_lifetime_mut(
+                    self.slice
+                        .split_at_with_size_unchecked_mut(skipped, self.size)
+                        .1,
+                )
+            };
         }
 
         self.next_back()
@@ -213,4 +244,17 @@ mod test {
             s => s.rchunks(3).rev(),
         }
     }
+
+    #[test]
+    fn into_remainder() {
+        let mut array = [1, 2, 3, 4, 5];
+        let mut slice = ped::new_mut::<u8, u8>(&mut array);
+
+        let mut iter = slice.rchunks_mut(2).unwrap();
+        iter.next();
+        assert!(iter.as_slice() == [1, 2, 3][..]);
+        iter.next_back();
+        let remainder = iter.into_remainder();
+        assert!(remainder == [2, 3][..]);
+    }
 }
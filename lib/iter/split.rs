@@ -0,0 +1,312 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime, DynSlice};
+
+/// Iterator over subslices of a [`DynSlice`] separated by elements that match a predicate.
+///
+/// This is created by [`DynSlice::split`].
+pub struct Split<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) slice: DynSlice<'a, Dyn>,
+    pub(crate) pred: P,
+    pub(crate) finished: bool,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> Split<'a, Dyn, P> {
+    pub(crate) fn new(slice: DynSlice<'a, Dyn>, pred: P) -> Self {
+        Self {
+            slice,
+            pred,
+            finished: false,
+            size: slice.element_size(),
+        }
+    }
+
+    /// Yields the rest of the slice, and ends the iterator.
+    fn finish(&mut self) -> Option<DynSlice<'a, Dyn>> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.slice)
+        }
+    }
+
+    /// Splits the slice around the separator at `index`, returning the parts before and after
+    /// it.
+    ///
+    /// # Safety
+    /// The caller must ensure that `index < self.slice.len()`.
+    unsafe fn split_around(&self, index: usize) -> (DynSlice<'a, Dyn>, DynSlice<'a, Dyn>) {
+        let (before, after) = self.slice.split_at_with_size_unchecked(index, self.size);
+        // `after` starts with the separator, so is not empty
+        let (_, after) = after.split_at_with_size_unchecked(1, self.size);
+        // The parts are subslices of `self.slice`, so they can have its lifetime
+        (extend_lifetime(before), extend_lifetime(after))
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for Split<'a, Dyn, P>
+{
+    type Item = DynSlice<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.slice.iter().position(&mut self.pred) {
+            None => self.finish(),
+            Some(index) => {
+                // SAFETY:
+                // `index` comes from `position`, so is less than the slice length.
+                let (split, remaining) = unsafe { self.split_around(index) };
+                self.slice = remaining;
+                Some(split)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // If the predicate does not match, the whole slice is yielded,
+            // otherwise every element could be a separator
+            (1, Some(self.slice.len().saturating_add(1)))
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    DoubleEndedIterator for Split<'a, Dyn, P>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.slice.iter().rposition(&mut self.pred) {
+            None => self.finish(),
+            Some(index) => {
+                // SAFETY:
+                // `index` comes from `rposition`, so is less than the slice length.
+                let (remaining, split) = unsafe { self.split_around(index) };
+                self.slice = remaining;
+                Some(split)
+            }
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for Split<'a, Dyn, P>
+{
+}
+
+/// Iterator over subslices of a [`DynSlice`] separated by elements that match a predicate,
+/// starting from the end of the slice.
+///
+/// This is created by [`DynSlice::rsplit`].
+#[allow(clippy::module_name_repetitions)]
+pub struct RSplit<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) inner: Split<'a, Dyn, P>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for RSplit<'a, Dyn, P>
+{
+    type Item = DynSlice<'a, Dyn>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    DoubleEndedIterator for RSplit<'a, Dyn, P>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for RSplit<'a, Dyn, P>
+{
+}
+
+/// Iterator over at most `n` subslices of a [`DynSlice`] separated by elements that match a
+/// predicate, where the last subslice is the rest of the slice.
+///
+/// This is created by [`DynSlice::splitn`].
+#[allow(clippy::module_name_repetitions)]
+pub struct SplitN<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) inner: Split<'a, Dyn, P>,
+    pub(crate) count: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for SplitN<'a, Dyn, P>
+{
+    type Item = DynSlice<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let count = self.count;
+        (
+            lower.min(count),
+            Some(upper.map_or(count, |upper| upper.min(count))),
+        )
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for SplitN<'a, Dyn, P>
+{
+}
+
+/// Iterator over at most `n` subslices of a [`DynSlice`] separated by elements that match a
+/// predicate, starting from the end of the slice, where the last subslice is the rest of the
+/// slice.
+///
+/// This is created by [`DynSlice::rsplitn`].
+pub struct RSplitN<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) inner: Split<'a, Dyn, P>,
+    pub(crate) count: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for RSplitN<'a, Dyn, P>
+{
+    type Item = DynSlice<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next_back()
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let count = self.count;
+        (
+            lower.min(count),
+            Some(upper.map_or(count, |upper| upper.min(count))),
+        )
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for RSplitN<'a, Dyn, P>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{standard::partial_eq, DynSlice};
+
+    const ARRAYS: [&[u8]; 7] = [
+        &[],
+        &[0],
+        &[1, 2],
+        &[1, 0, 2, 0, 0, 3],
+        &[0, 1, 0],
+        &[1, 0, 2, 0],
+        &[0, 0],
+    ];
+
+    fn is_zero(x: &(dyn PartialEq<u8> + 'static)) -> bool {
+        *x == 0
+    }
+
+    fn assert_splits<'a, 'b>(
+        actual: impl Iterator<Item = DynSlice<'a, dyn PartialEq<u8>>>,
+        expected: impl Iterator<Item = &'b [u8]>,
+    ) {
+        let actual: Vec<_> = actual.collect();
+        let expected: Vec<_> = expected.collect();
+        assert_eq!(actual.len(), expected.len());
+        assert!(actual.iter().zip(&expected).all(|(a, e)| a == *e));
+    }
+
+    #[test]
+    fn split() {
+        for array in ARRAYS {
+            let slice = partial_eq::new(array);
+            assert_splits(slice.split(is_zero), array.split(|&x| x == 0));
+            assert_splits(slice.split(is_zero).rev(), array.split(|&x| x == 0).rev());
+        }
+    }
+
+    #[test]
+    fn rsplit() {
+        for array in ARRAYS {
+            let slice = partial_eq::new(array);
+            assert_splits(slice.rsplit(is_zero), array.rsplit(|&x| x == 0));
+            assert_splits(slice.rsplit(is_zero).rev(), array.rsplit(|&x| x == 0).rev());
+        }
+    }
+
+    #[test]
+    fn splitn() {
+        for array in ARRAYS {
+            let slice = partial_eq::new(array);
+            for n in 0..5 {
+                assert_splits(slice.splitn(n, is_zero), array.splitn(n, |&x| x == 0));
+                assert_splits(slice.rsplitn(n, is_zero), array.rsplitn(n, |&x| x == 0));
+            }
+        }
+    }
+
+    #[test]
+    fn both_ends() {
+        let array = [1, 0, 2, 0, 3, 0, 4];
+        let slice = partial_eq::new(&array);
+        let mut iter = slice.split(is_zero);
+
+        assert!(iter.next().unwrap() == [1][..]);
+        assert!(iter.next_back().unwrap() == [4][..]);
+        assert!(iter.next_back().unwrap() == [3][..]);
+        assert!(iter.next().unwrap() == [2][..]);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+}
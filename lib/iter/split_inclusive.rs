@@ -0,0 +1,161 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime, DynSlice};
+
+/// Iterator over subslices of a [`DynSlice`] separated by elements that match a predicate,
+/// where each subslice ends with its separator.
+///
+/// This is created by [`DynSlice::split_inclusive`].
+pub struct SplitInclusive<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) slice: DynSlice<'a, Dyn>,
+    pub(crate) pred: P,
+    pub(crate) finished: bool,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> SplitInclusive<'a, Dyn, P> {
+    pub(crate) fn new(slice: DynSlice<'a, Dyn>, pred: P) -> Self {
+        Self {
+            slice,
+            pred,
+            finished: slice.is_empty(),
+            size: slice.element_size(),
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for SplitInclusive<'a, Dyn, P>
+{
+    type Item = DynSlice<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mid = self
+            .slice
+            .iter()
+            .position(&mut self.pred)
+            .map_or(self.slice.len(), |index| index + 1);
+        if mid == self.slice.len() {
+            self.finished = true;
+        }
+
+        let (split, remaining) =
+            // SAFETY:
+            // `mid` is at most the slice length, so splitting here is valid.
+            unsafe { self.slice.split_at_with_size_unchecked(mid, self.size) };
+        let (split, remaining) =
+            // SAFETY:
+            // The original slice is immediately replaced with one part,
+            // so the lifetimes can be extended to match it.
+            unsafe { (extend_lifetime(split), extend_lifetime(remaining)) };
+        self.slice = remaining;
+
+        Some(split)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // If the predicate does not match, the whole slice is yielded,
+            // otherwise every element could be a separator
+            (1, Some(self.slice.len()))
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    DoubleEndedIterator for SplitInclusive<'a, Dyn, P>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // The last element is always part of the last subslice, so it is not checked
+        let mut iter = self.slice.iter();
+        iter.next_back();
+        let mid = iter.rposition(&mut self.pred).map_or(0, |index| index + 1);
+        if mid == 0 {
+            self.finished = true;
+        }
+
+        let (remaining, split) =
+            // SAFETY:
+            // `mid` is at most the slice length, so splitting here is valid.
+            unsafe { self.slice.split_at_with_size_unchecked(mid, self.size) };
+        let (remaining, split) =
+            // SAFETY:
+            // The original slice is immediately replaced with one part,
+            // so the lifetimes can be extended to match it.
+            unsafe { (extend_lifetime(remaining), extend_lifetime(split)) };
+        self.slice = remaining;
+
+        Some(split)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for SplitInclusive<'a, Dyn, P>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{standard::partial_eq, DynSlice};
+
+    fn is_zero(x: &(dyn PartialEq<u8> + 'static)) -> bool {
+        x == &0
+    }
+
+    #[test]
+    fn matches_slice() {
+        let arrays: [&[u8]; 6] = [
+            &[],
+            &[0],
+            &[1, 2],
+            &[1, 0, 2, 0, 0, 3],
+            &[0, 1, 0],
+            &[1, 0, 2, 0],
+        ];
+
+        for array in arrays {
+            let slice = partial_eq::new(array);
+            let expected: Vec<_> = array.split_inclusive(|&x| x == 0).collect();
+
+            let actual: Vec<DynSlice<_>> = slice.split_inclusive(is_zero).collect();
+            assert_eq!(actual.len(), expected.len(), "{array:?}");
+            assert!(actual.iter().zip(&expected).all(|(a, e)| a == *e));
+
+            let actual: Vec<DynSlice<_>> = slice.split_inclusive(is_zero).rev().collect();
+            assert_eq!(actual.len(), expected.len(), "{array:?}");
+            assert!(actual
+                .iter()
+                .zip(expected.iter().rev())
+                .all(|(a, e)| a == *e));
+        }
+    }
+
+    #[test]
+    fn both_ends() {
+        let array = [1, 0, 2, 0, 3, 0, 4];
+        let slice = partial_eq::new(&array);
+        let mut iter = slice.split_inclusive(is_zero);
+
+        assert!(iter.next().unwrap() == [1, 0][..]);
+        assert!(iter.next_back().unwrap() == [4][..]);
+        assert!(iter.next_back().unwrap() == [3, 0][..]);
+        assert!(iter.next().unwrap() == [2, 0][..]);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+}
@@ -0,0 +1,135 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime_mut, DynSliceMut};
+
+/// Iterator over mutable subslices of a [`DynSliceMut`] separated by elements that match a predicate,
+/// where each subslice ends with its separator.
+///
+/// This is created by [`DynSliceMut::split_inclusive_mut`].
+pub struct SplitInclusiveMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) slice: DynSliceMut<'a, Dyn>,
+    pub(crate) pred: P,
+    pub(crate) finished: bool,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> SplitInclusiveMut<'a, Dyn, P> {
+    pub(crate) fn new(slice: DynSliceMut<'a, Dyn>, pred: P) -> Self {
+        Self {
+            finished: slice.is_empty(),
+            size: slice.element_size(),
+            slice,
+            pred,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for SplitInclusiveMut<'a, Dyn, P>
+{
+    type Item = DynSliceMut<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mid = self
+            .slice
+            .iter()
+            .position(&mut self.pred)
+            .map_or(self.slice.len(), |index| index + 1);
+        if mid == self.slice.len() {
+            self.finished = true;
+        }
+
+        let (split, remaining) =
+            // SAFETY:
+            // `mid` is at most the slice length, so splitting here is valid.
+            unsafe { self.slice.split_at_with_size_unchecked_mut(mid, self.size) };
+        let (split, remaining) =
+            // SAFETY:
+            // The original slice is immediately replaced with one part,
+            // so the lifetimes can be extended to match it.
+            unsafe { (extend_lifetime_mut(split), extend_lifetime_mut(remaining)) };
+        self.slice = remaining;
+
+        Some(split)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // If the predicate does not match, the whole slice is yielded,
+            // otherwise every element could be a separator
+            (1, Some(self.slice.len()))
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    DoubleEndedIterator for SplitInclusiveMut<'a, Dyn, P>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // The last element is always part of the last subslice, so it is not checked
+        let mut iter = self.slice.iter();
+        iter.next_back();
+        let mid = iter.rposition(&mut self.pred).map_or(0, |index| index + 1);
+        if mid == 0 {
+            self.finished = true;
+        }
+
+        let (remaining, split) =
+            // SAFETY:
+            // `mid` is at most the slice length, so splitting here is valid.
+            unsafe { self.slice.split_at_with_size_unchecked_mut(mid, self.size) };
+        let (remaining, split) =
+            // SAFETY:
+            // The original slice is immediately replaced with one part,
+            // so the lifetimes can be extended to match it.
+            unsafe { (extend_lifetime_mut(remaining), extend_lifetime_mut(split)) };
+        self.slice = remaining;
+
+        Some(split)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for SplitInclusiveMut<'a, Dyn, P>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::any;
+
+    #[test]
+    fn split_inclusive_mut() {
+        let mut array = [1_u8, 0, 2, 0, 0, 3];
+        let mut slice = any::new_mut(&mut array);
+
+        let mut iter = slice.split_inclusive_mut(|x| x.downcast_ref() == Some(&0_u8));
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        let middle: Vec<_> = iter.collect();
+        assert_eq!((first.len(), last.len(), middle.len()), (2, 1, 2));
+
+        let splits = [first, last].into_iter().chain(middle);
+        for (mut split, add) in splits.zip([10, 20, 30, 40]) {
+            for x in &mut split {
+                *x.downcast_mut::<u8>().unwrap() += add;
+            }
+        }
+        assert_eq!(array, [11, 10, 32, 30, 40, 23]);
+    }
+}
@@ -0,0 +1,310 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::extend_lifetime_mut, DynSliceMut};
+
+/// Iterator over mutable subslices of a [`DynSliceMut`] separated by elements that match a predicate.
+///
+/// This is created by [`DynSliceMut::split_mut`].
+pub struct SplitMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) slice: DynSliceMut<'a, Dyn>,
+    pub(crate) pred: P,
+    pub(crate) finished: bool,
+    /// The size of the underlying type, cached so that it is not read from the vtable on every step
+    pub(crate) size: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> SplitMut<'a, Dyn, P> {
+    pub(crate) fn new(slice: DynSliceMut<'a, Dyn>, pred: P) -> Self {
+        Self {
+            size: slice.element_size(),
+            slice,
+            pred,
+            finished: false,
+        }
+    }
+
+    /// Yields the rest of the slice, and ends the iterator.
+    fn finish(&mut self) -> Option<DynSliceMut<'a, Dyn>> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            // SAFETY:
+            // Splitting at the length is valid, and leaves an empty slice behind.
+            // The original slice is immediately replaced with the empty part,
+            // so the lifetimes can be extended to match it.
+            let (rest, empty) = unsafe {
+                let (rest, empty) = self
+                    .slice
+                    .split_at_with_size_unchecked_mut(self.slice.len(), self.size);
+                (extend_lifetime_mut(rest), extend_lifetime_mut(empty))
+            };
+            self.slice = empty;
+            Some(rest)
+        }
+    }
+
+    /// Splits the slice around the separator at `index`, returning the parts before and after
+    /// it.
+    ///
+    /// The slice is left in an unspecified state, so must be replaced by the caller.
+    ///
+    /// # Safety
+    /// The caller must ensure that `index < self.slice.len()`.
+    unsafe fn split_around(
+        &mut self,
+        index: usize,
+    ) -> (DynSliceMut<'a, Dyn>, DynSliceMut<'a, Dyn>) {
+        let (before, after) = self
+            .slice
+            .split_at_with_size_unchecked_mut(index, self.size);
+        let mut after = extend_lifetime_mut(after);
+        // `after` starts with the separator, so is not empty
+        let (_, after) = after.split_at_with_size_unchecked_mut(1, self.size);
+        // The parts do not overlap, and are subslices of `self.slice`, which is replaced by the
+        // caller, so they can have its lifetime
+        (extend_lifetime_mut(before), extend_lifetime_mut(after))
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for SplitMut<'a, Dyn, P>
+{
+    type Item = DynSliceMut<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.slice.iter().position(&mut self.pred) {
+            None => self.finish(),
+            Some(index) => {
+                // SAFETY:
+                // `index` comes from `position`, so is less than the slice length.
+                let (split, remaining) = unsafe { self.split_around(index) };
+                self.slice = remaining;
+                Some(split)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // If the predicate does not match, the whole slice is yielded,
+            // otherwise every element could be a separator
+            (1, Some(self.slice.len().saturating_add(1)))
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    DoubleEndedIterator for SplitMut<'a, Dyn, P>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.slice.iter().rposition(&mut self.pred) {
+            None => self.finish(),
+            Some(index) => {
+                // SAFETY:
+                // `index` comes from `rposition`, so is less than the slice length.
+                let (remaining, split) = unsafe { self.split_around(index) };
+                self.slice = remaining;
+                Some(split)
+            }
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for SplitMut<'a, Dyn, P>
+{
+}
+
+/// Iterator over mutable subslices of a [`DynSliceMut`] separated by elements that match a predicate,
+/// starting from the end of the slice.
+///
+/// This is created by [`DynSliceMut::rsplit_mut`].
+#[allow(clippy::module_name_repetitions)]
+pub struct RSplitMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) inner: SplitMut<'a, Dyn, P>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for RSplitMut<'a, Dyn, P>
+{
+    type Item = DynSliceMut<'a, Dyn>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    DoubleEndedIterator for RSplitMut<'a, Dyn, P>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for RSplitMut<'a, Dyn, P>
+{
+}
+
+/// Iterator over at most `n` mutable subslices of a [`DynSliceMut`] separated by elements that match a
+/// predicate, where the last subslice is the rest of the slice.
+///
+/// This is created by [`DynSliceMut::splitn_mut`].
+pub struct SplitNMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) inner: SplitMut<'a, Dyn, P>,
+    pub(crate) count: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for SplitNMut<'a, Dyn, P>
+{
+    type Item = DynSliceMut<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let count = self.count;
+        (
+            lower.min(count),
+            Some(upper.map_or(count, |upper| upper.min(count))),
+        )
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for SplitNMut<'a, Dyn, P>
+{
+}
+
+/// Iterator over at most `n` mutable subslices of a [`DynSliceMut`] separated by elements that match a
+/// predicate, starting from the end of the slice, where the last subslice is the rest of the
+/// slice.
+///
+/// This is created by [`DynSliceMut::rsplitn_mut`].
+pub struct RSplitNMut<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>, P> {
+    pub(crate) inner: SplitMut<'a, Dyn, P>,
+    pub(crate) count: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool> Iterator
+    for RSplitNMut<'a, Dyn, P>
+{
+    type Item = DynSliceMut<'a, Dyn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.finish()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next_back()
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let count = self.count;
+        (
+            lower.min(count),
+            Some(upper.map_or(count, |upper| upper.min(count))),
+        )
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a, P: FnMut(&Dyn) -> bool>
+    FusedIterator for RSplitNMut<'a, Dyn, P>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::any;
+
+    fn is_zero(x: &dyn core::any::Any) -> bool {
+        x.downcast_ref() == Some(&0_u8)
+    }
+
+    fn lens<'a>(
+        splits: impl Iterator<Item = crate::DynSliceMut<'a, dyn core::any::Any>>,
+    ) -> Vec<usize> {
+        splits
+            .map(|mut split| {
+                for x in &mut split {
+                    *x.downcast_mut::<u8>().unwrap() += 1;
+                }
+                split.len()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_mut() {
+        let mut array = [1_u8, 0, 2, 3, 0, 0, 4];
+        let mut slice = any::new_mut(&mut array);
+
+        assert_eq!(lens(slice.split_mut(is_zero)), [1, 2, 0, 1]);
+        assert_eq!(lens(slice.split_mut(is_zero).rev()), [1, 0, 2, 1]);
+        assert_eq!(lens(slice.rsplit_mut(is_zero)), [1, 0, 2, 1]);
+        assert_eq!(array, [4, 0, 5, 6, 0, 0, 7]);
+
+        let mut slice = any::new_mut(&mut array);
+        assert!(lens(slice.splitn_mut(0, is_zero)).is_empty());
+        assert_eq!(lens(slice.splitn_mut(2, is_zero)), [1, 5]);
+        assert_eq!(array, [5, 0, 6, 7, 1, 1, 8]);
+
+        let mut array = [1_u8, 0, 2, 0, 3];
+        let mut slice = any::new_mut(&mut array);
+        assert_eq!(lens(slice.rsplitn_mut(2, is_zero)), [1, 3]);
+        assert_eq!(array, [2, 1, 3, 0, 4]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut array: [u8; 0] = [];
+        let mut slice = any::new_mut(&mut array);
+        assert_eq!(lens(slice.split_mut(is_zero)), [0]);
+        assert_eq!(lens(slice.rsplitn_mut(3, is_zero)), [0]);
+    }
+}
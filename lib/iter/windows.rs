@@ -12,6 +12,24 @@ pub struct Windows<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a>
     pub(crate) window_size: NonZeroUsize,
 }
 
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Windows<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Create an iterator over the windows of `slice` of length `window_size`.
+    pub(crate) const fn new(slice: DynSlice<'a, Dyn>, window_size: NonZeroUsize) -> Self {
+        Self { slice, window_size }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Clone for Windows<'a, Dyn> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            window_size: self.window_size,
+        }
+    }
+}
+
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for Windows<'a, Dyn> {
     type Item = DynSlice<'a, Dyn>;
 
@@ -52,7 +70,10 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for W
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let remaining = self.slice.slice(n..)?;
+        let Some(remaining) = self.slice.slice(n..) else {
+            self.slice.len = 0;
+            return None;
+        };
         // SAFETY:
         // The original slice is immediately replaced with the new subslice.
         let remaining = unsafe { extend_lifetime(remaining) };
@@ -60,6 +81,14 @@ impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator for W
 
         self.next()
     }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        self.next_back()
+    }
 }
 
 impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIterator
@@ -136,6 +165,39 @@ mod test {
         }
     }
 
+    #[test]
+    fn clamped() {
+        test_iter! {
+            [1, 2, 3, 4, 5],
+            ds => ds.windows_clamped(3).unwrap(),
+            s => s.windows(3),
+        }
+    }
+
+    #[test]
+    fn clamped_short() {
+        test_iter! {
+            [1, 2],
+            ds => ds.windows_clamped(3).unwrap(),
+            s => s.windows(2),
+        }
+
+        test_iter! {
+            [1, 2],
+            ds => ds.windows_clamped(3).unwrap().rev(),
+            s => s.windows(2).rev(),
+        }
+
+        test_iter! {
+            [],
+            ds => ds.windows_clamped(3).unwrap(),
+            s => s.windows(3),
+        }
+
+        let array = [1_u8, 2];
+        assert!(ped::new::<u8, u8>(&array).windows_clamped(0).is_none());
+    }
+
     #[test]
     fn nth() {
         test_iter! {@nth
@@ -165,4 +227,42 @@ mod test {
             s => s.windows(3).rev(),
         }
     }
+
+    #[test]
+    fn matches_slice_windows() {
+        let array: [u8; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let slice = ped::new::<u8, u8>(&array);
+
+        for len in 0..=array.len() {
+            let slice = slice.slice(..len).unwrap();
+            let array = &array[..len];
+
+            for size in 1..=len + 1 {
+                let windows = slice.windows(size).unwrap();
+                assert_eq!(windows.len(), array.windows(size).len());
+                assert!(windows.clone().eq(array.windows(size)));
+                assert!(windows.rev().eq(array.windows(size).rev()));
+
+                for n in 0..=len {
+                    let mut windows = slice.windows(size).unwrap();
+                    let mut expected = array.windows(size);
+                    assert!(windows.nth(n).into_iter().eq(expected.nth(n)));
+                    assert!(windows.nth_back(1).into_iter().eq(expected.nth_back(1)));
+                    assert!(windows.next().into_iter().eq(expected.next()));
+                    assert_eq!(windows.len(), expected.len());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_sized() {
+        let array = [(); 4];
+        let slice = ped::new::<(), ()>(&array);
+
+        let mut windows = slice.windows(2).unwrap();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows.next_back().unwrap().len(), 2);
+        assert_eq!(windows.last().unwrap().len(), 2);
+    }
 }
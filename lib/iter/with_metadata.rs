@@ -0,0 +1,165 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{self, DynMetadata, Pointee},
+};
+
+use super::Iter;
+
+/// Iterator over the elements of a dyn slice, with their metadata.
+///
+/// The metadata is the same for every element, but having it with each element avoids
+/// transmuting the slice's vtable pointer when reconstructing raw pointers to the elements.
+///
+/// This is created by [`Iter::with_metadata`].
+pub struct WithMetadata<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    iter: Iter<'a, Dyn>,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iter<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns an iterator that yields the metadata of each element with the element.
+    ///
+    /// # Example
+    /// ```
+    /// #![feature(ptr_metadata)]
+    /// use core::{fmt::Debug, ptr};
+    ///
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1_u8, 2, 3];
+    /// let slice = debug::new(&array);
+    ///
+    /// let pointers: Vec<*const dyn Debug> = slice
+    ///     .iter()
+    ///     .with_metadata()
+    ///     .map(|(metadata, element)| {
+    ///         ptr::from_raw_parts((element as *const dyn Debug).cast(), metadata)
+    ///     })
+    ///     .collect();
+    /// # assert_eq!(pointers.len(), 3);
+    /// // SAFETY: the pointers were created from references to `array`, which is still borrowed
+    /// let second = unsafe { &*pointers[1] };
+    /// # assert_eq!(format!("{second:?}"), "2");
+    /// println!("{second:?}"); // 2
+    /// ```
+    pub const fn with_metadata(self) -> WithMetadata<'a, Dyn> {
+        WithMetadata { iter: self }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> WithMetadata<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the iterator over the remaining elements, without their metadata.
+    pub const fn into_inner(self) -> Iter<'a, Dyn> {
+        self.iter
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Clone for WithMetadata<'a, Dyn> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> Iterator
+    for WithMetadata<'a, Dyn>
+{
+    type Item = (DynMetadata<Dyn>, &'a Dyn);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|element| (ptr::metadata(element), element))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|element| (ptr::metadata(element), element))
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.iter
+            .last()
+            .map(|element| (ptr::metadata(element), element))
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> DoubleEndedIterator
+    for WithMetadata<'a, Dyn>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|element| (ptr::metadata(element), element))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth_back(n)
+            .map(|element| (ptr::metadata(element), element))
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> ExactSizeIterator
+    for WithMetadata<'a, Dyn>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a> FusedIterator
+    for WithMetadata<'a, Dyn>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::partial_eq;
+
+    #[test]
+    fn with_metadata() {
+        let array = [2_u16, 3, 5, 7];
+        let slice = partial_eq::new::<u16, _>(&array);
+        let metadata = slice.metadata().unwrap();
+
+        let mut iter = slice.iter().with_metadata();
+        assert_eq!(iter.len(), 4);
+        let (first_metadata, first) = iter.next().unwrap();
+        assert_eq!(first_metadata, metadata);
+        assert!(first == &2);
+
+        let (last_metadata, last) = iter.next_back().unwrap();
+        assert_eq!(last_metadata, metadata);
+        assert!(last == &7);
+
+        let (_, third) = iter.nth(1).unwrap();
+        assert!(third == &5);
+        assert!(iter.next().is_none());
+        assert!(iter.into_inner().as_slice().is_empty());
+
+        let empty = partial_eq::new::<u16, u16>(&[]);
+        assert_eq!(empty.iter().with_metadata().count(), 0);
+    }
+}
@@ -0,0 +1,167 @@
+use core::{
+    iter::FusedIterator,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::Iter;
+
+/// Iterator that combines the elements of two dyn slices of equal length in lockstep.
+///
+/// This is created by [`DynSlice::zip_with`](crate::DynSlice::zip_with).
+pub struct ZipWith<
+    'a,
+    'b,
+    Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+    Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>>,
+    F,
+> {
+    pub(crate) a: Iter<'a, Dyn>,
+    pub(crate) b: Iter<'b, Dyn2>,
+    pub(crate) f: F,
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+        F: Clone,
+    > Clone for ZipWith<'a, 'b, Dyn, Dyn2, F>
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+        R,
+        F: FnMut(&'a Dyn, &'b Dyn2) -> R,
+    > Iterator for ZipWith<'a, 'b, Dyn, Dyn2, F>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        debug_assert_eq!(
+            self.a.len(),
+            self.b.len(),
+            "[dyn-slice] zipped slices have different lengths!"
+        );
+
+        if self.a.slice.is_empty() {
+            None
+        } else {
+            // SAFETY:
+            // Both iterators have the same length, so if one is not empty,
+            // neither is the other.
+            let (a, b) = unsafe { (self.a.next_unchecked(), self.b.next_unchecked()) };
+            Some((self.f)(a, b))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.a.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.a.count()
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+        R,
+        F: FnMut(&'a Dyn, &'b Dyn2) -> R,
+    > DoubleEndedIterator for ZipWith<'a, 'b, Dyn, Dyn2, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.a.slice.is_empty() {
+            None
+        } else {
+            // SAFETY:
+            // Both iterators have the same length, so if one is not empty,
+            // neither is the other.
+            let (a, b) = unsafe { (self.a.next_back_unchecked(), self.b.next_back_unchecked()) };
+            Some((self.f)(a, b))
+        }
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+        R,
+        F: FnMut(&'a Dyn, &'b Dyn2) -> R,
+    > ExactSizeIterator for ZipWith<'a, 'b, Dyn, Dyn2, F>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len()
+    }
+}
+
+impl<
+        'a,
+        'b,
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + 'a,
+        Dyn2: ?Sized + Pointee<Metadata = DynMetadata<Dyn2>> + 'b,
+        R,
+        F: FnMut(&'a Dyn, &'b Dyn2) -> R,
+    > FusedIterator for ZipWith<'a, 'b, Dyn, Dyn2, F>
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::standard::{partial_eq, to};
+
+    #[test]
+    fn basic() {
+        let a: [u8; 4] = [1, 2, 3, 4];
+        let b: [u16; 4] = [10, 20, 30, 40];
+        let sa = to::new::<u16, _>(&a);
+        let sb = to::new::<u16, _>(&b);
+
+        let mut iter = sa.zip_with(&sb, |x, y| x.to() + y.to());
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(11));
+        assert_eq!(iter.next_back(), Some(44));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(22));
+        assert_eq!(iter.next(), Some(33));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn empty() {
+        let sa = partial_eq::new::<u8, u8>(&[]);
+        let sb = partial_eq::new::<u8, u8>(&[]);
+
+        assert_eq!(sa.zip_with(&sb, |_, _| ()).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "slices have different lengths")]
+    fn different_lengths() {
+        let sa = partial_eq::new::<u8, u8>(&[1, 2, 3]);
+        let sb = partial_eq::new::<u8, u8>(&[1, 2]);
+
+        let _ = sa.zip_with(&sb, |_, _| ());
+    }
+}
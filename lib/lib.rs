@@ -14,7 +14,7 @@
 //!
 //! There are some pre-made new functions for common traits in [`standard`].
 
-#![feature(ptr_metadata, pointer_byte_offsets)]
+#![feature(ptr_metadata, pointer_byte_offsets, unsize)]
 #![cfg_attr(doc, feature(doc_cfg))]
 #![warn(
     clippy::all,
@@ -34,21 +34,95 @@
 )]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "any_vec")]
+mod any_vec_interop;
+mod as_chunks;
+#[cfg(feature = "std")]
+mod bytes_reader;
+#[cfg(feature = "std")]
+mod callbacks;
+#[cfg(feature = "capi")]
+#[cfg_attr(doc, doc(cfg(feature = "capi")))]
+pub mod capi;
+#[cfg(feature = "cast")]
+mod cast;
 #[cfg(test)]
 mod compile_tests;
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+/// Slice diffing.
+pub mod diff;
+mod dyn_array_buf;
+mod dyn_cursor;
+#[cfg(feature = "alloc")]
+mod dyn_pool;
+mod dyn_ring_slice;
 mod dyn_slice;
+mod dyn_slice_builder;
+mod dyn_slice_cells;
+mod dyn_slice_chain;
+mod dyn_slice_index;
 mod dyn_slice_mut;
+mod dyn_slice_uninit;
+#[cfg(feature = "alloc")]
+mod dyn_snapshot;
+mod element_guard;
+/// Formatting adapters.
+pub mod fmt;
 /// Iterator types.
 pub mod iter;
+#[cfg(feature = "rand")]
+mod random;
+mod raw_dyn_slice;
+#[cfg(feature = "futures-core")]
+mod select_all;
+mod send_sync;
 /// Dyn slice `new` and `new_mut` definitions for some common traits.
 ///
 /// If you want a dyn slice for a trait that is not here, use the [`declare_new_fns`] macro.
 pub mod standard;
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "test-util")]
+#[cfg_attr(doc, doc(cfg(feature = "test-util")))]
+/// Property testing strategies, for testing code built on dyn slices against plain slices.
+pub mod test_util;
 mod utils;
-
+mod validate;
+
+pub use as_chunks::*;
+#[cfg(feature = "std")]
+pub use bytes_reader::*;
+#[cfg(feature = "std")]
+pub use callbacks::*;
+#[cfg(feature = "cast")]
+pub use cast::*;
+pub use dyn_array_buf::*;
+pub use dyn_cursor::*;
+#[cfg(feature = "alloc")]
+pub use dyn_pool::*;
+pub use dyn_ring_slice::*;
 pub use dyn_slice::*;
+pub use dyn_slice_builder::*;
+pub use dyn_slice_cells::*;
+pub use dyn_slice_chain::*;
+pub use dyn_slice_index::*;
 pub use dyn_slice_mut::*;
+pub use dyn_slice_uninit::*;
+#[cfg(feature = "alloc")]
+pub use dyn_snapshot::*;
+pub use element_guard::*;
 pub use iter::{Iter, IterMut};
+pub use raw_dyn_slice::*;
+#[cfg(feature = "futures-core")]
+pub use select_all::*;
+pub use send_sync::*;
+#[cfg(feature = "stats")]
+pub use stats::*;
+pub use validate::*;
 
 /// Declare `new` and `new_mut` functions for dyn slices of a trait.
 ///
@@ -65,6 +139,20 @@ pub use iter::{Iter, IterMut};
 /// The [`ptr_metadata`](https://doc.rust-lang.org/beta/unstable-book/library-features/ptr-metadata.html)
 /// feature must be enabled to use this macro!
 ///
+/// The items are declared in a new module, which imports everything in scope at the call site,
+/// so paths in the declaration resolve the same way that they would outside of the macro.
+/// The module's items (`Dyn`, `Slice`, `SliceMut`, `new`, `new_mut`, `metadata`, `empty` and
+/// `empty_untyped`) shadow any items with the same names.
+///
+/// The `metadata` function is a `const fn` that returns the metadata for a type as `Dyn`, so it
+/// can be stored in a `const` or `static` for use with
+/// [`DynSlice::from_parts_with_metadata`](crate::DynSlice::from_parts_with_metadata). The
+/// `empty` function creates an empty dyn slice with the metadata for a type, and `empty_untyped`
+/// creates one without metadata.
+///
+/// The trait must be object safe. If it is not, the error is reported on the trait in the
+/// declaration, with the method that makes it not object safe.
+///
 /// ## Example: Display
 /// ```
 /// #![feature(ptr_metadata)]
@@ -77,6 +165,49 @@ pub use iter::{Iter, IterMut};
 /// ## Other examples
 #[doc = concat!("There are more examples of how to use [`declare_new_fns`] in the [examples directory](https://docs.rs/crate/dyn-slice/", env!("CARGO_PKG_VERSION"), "/source/examples/).")]
 ///
+/// # Bounds on the new functions
+/// Extra bounds can be added to only the `new`, `new_mut`, `metadata` and `empty` functions with a
+/// `new_where` attribute.
+/// Unlike the where clause, these do not affect the `Dyn`, `Slice` and `SliceMut` aliases:
+/// ```
+/// #![feature(ptr_metadata)]
+/// # use dyn_slice::declare_new_fns;
+/// declare_new_fns!(
+///     #[new_where(Rhs: Copy)]
+///     pub partial_eq_copy<Rhs> PartialEq<Rhs>
+/// );
+///
+/// # fn main() {
+/// // The alias can still be used with a type that is not `Copy`
+/// fn first_eq(slice: partial_eq_copy::Slice<String>, rhs: &String) -> bool {
+///     slice.first().is_some_and(|first| first == rhs)
+/// }
+///
+/// let array = [1, 2, 3];
+/// let slice = partial_eq_copy::new::<u8, _>(&array);
+/// # assert!(slice[0] == 1);
+/// # }
+/// ```
+///
+/// # Function names
+/// The names of the new functions can be changed with a `fn_names` attribute:
+/// ```
+/// #![feature(ptr_metadata)]
+/// # use dyn_slice::declare_new_fns;
+/// declare_new_fns!(
+///     #[fn_names(new = make, new_mut = make_mut)]
+///     pub display_slice std::fmt::Display
+/// );
+///
+/// # fn main() {
+/// let array = [1, 2, 3];
+/// let slice = display_slice::make(&array);
+/// # assert_eq!(slice.len(), 3);
+/// # }
+/// ```
+///
+/// `#[doc(hidden)]` and `#[deprecated]` attributes are applied to the generated items, as well as the module.
+///
 /// # Use from other crates
 /// When using `dyn_slice` from crates that re-export it, you may need to add a `crate` attribute, for example:
 /// ```text
@@ -110,6 +241,93 @@ macro_rules! declare_new_fn {
     };
 }
 
+#[macro_export]
+/// Create a dyn slice from a list of values, for quick literals in tests and examples.
+///
+/// The values must all be the same type, and there must be at least one.
+///
+/// # Syntax
+/// ```text
+/// dyn_slice![Trait: values...]
+/// dyn_slice![mut Trait: values...]
+/// dyn_slice!(let name: Trait = [values...]);
+/// dyn_slice!(let mut name: Trait = [values...]);
+/// ```
+///
+/// The first two forms are expressions that borrow a temporary array, so the dyn slice can only
+/// be used within the enclosing statement. The `let` forms store the array in a hidden binding, so
+/// `name` can be used until the end of the enclosing block. The `mut` forms create a
+/// [`DynSliceMut`].
+///
+/// # Example
+/// ```
+/// use std::{any::Any, fmt::Display};
+///
+/// use dyn_slice::dyn_slice;
+///
+/// # assert_eq!(format!("{}", dyn_slice![Display: 1_u8, 2, 3].display_list()), "1, 2, 3");
+/// println!("{}", dyn_slice![Display: 1_u8, 2, 3].display_list()); // 1, 2, 3
+///
+/// dyn_slice!(let mut slice: Any = [String::from("a"), String::from("b")]);
+/// slice[0].downcast_mut::<String>().unwrap().push('c');
+/// assert_eq!(slice.downcast::<String>().unwrap(), ["ac", "b"]);
+/// ```
+macro_rules! dyn_slice {
+    (let mut $name:ident : $tr:path = [ $( $value:expr ),+ $(,)? ] $(;)?) => {
+        let mut array = [ $( $value ),+ ];
+        let mut $name = $crate::__private::new_mut::<dyn $tr, _>(&mut array);
+    };
+    (let $name:ident : $tr:path = [ $( $value:expr ),+ $(,)? ] $(;)?) => {
+        let array = [ $( $value ),+ ];
+        let $name = $crate::__private::new::<dyn $tr, _>(&array);
+    };
+    (mut $tr:path : $( $value:expr ),+ $(,)?) => {
+        $crate::__private::new_mut::<dyn $tr, _>(&mut [ $( $value ),+ ])
+    };
+    ($tr:path : $( $value:expr ),+ $(,)?) => {
+        $crate::__private::new::<dyn $tr, _>(&[ $( $value ),+ ])
+    };
+}
+
+#[doc(hidden)]
+/// Items used by macros, which are not part of the public API.
+pub mod __private {
+    use core::{
+        marker::Unsize,
+        ptr::{DynMetadata, Pointee},
+    };
+
+    use crate::{utils::metadata_of, DynSlice, DynSliceMut};
+
+    #[inline]
+    #[must_use]
+    pub fn new<Dyn, T>(value: &[T]) -> DynSlice<Dyn>
+    where
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+        T: Unsize<Dyn>,
+    {
+        let metadata = metadata_of::<T, Dyn>();
+
+        // SAFETY:
+        // The metadata is for `T` as `Dyn`, which is the type of the elements of `value`.
+        unsafe { DynSlice::with_metadata(value, metadata) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn new_mut<Dyn, T>(value: &mut [T]) -> DynSliceMut<Dyn>
+    where
+        Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+        T: Unsize<Dyn>,
+    {
+        let metadata = metadata_of::<T, Dyn>();
+
+        // SAFETY:
+        // The metadata is for `T` as `Dyn`, which is the type of the elements of `value`.
+        unsafe { DynSliceMut::with_metadata(value, metadata) }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use core::fmt;
@@ -124,6 +342,122 @@ mod test {
         pub ped<Rhs> Ped<Rhs>
     }
 
+    declare_new_fns! {
+        #[crate = crate]
+        #[fn_names(new = make, new_mut = make_mut)]
+        #[doc(hidden)]
+        #[deprecated]
+        pub renamed fmt::Debug
+    }
+
+    declare_new_fns! {
+        #[crate = crate]
+        pub iter_vec Iterator<Item = Vec<Option<u8>>>
+    }
+
+    declare_new_fns! {
+        #[crate = crate]
+        pub debug_send<'a> fmt::Debug + 'a + Send
+    }
+
+    declare_new_fns! {
+        #[crate = crate]
+        pub lifetime_first<'a> 'a + fmt::Debug
+    }
+
+    mod nested {
+        use dyn_slice_macros::declare_new_fns;
+
+        declare_new_fns! {
+            #[crate = crate]
+            pub super_ped super::Ped<u8>
+        }
+    }
+
+    #[test]
+    fn declaration_paths() {
+        let mut iters = [vec![vec![Some(1)], vec![None]].into_iter()];
+        let mut slice = iter_vec::new_mut(&mut iters);
+        let iter = slice.get_mut(0).unwrap();
+        assert_eq!(iter.next(), Some(vec![Some(1)]));
+        assert_eq!(iter.next(), Some(vec![None]));
+
+        let array = [1_u8, 2];
+        let slice = nested::super_ped::new(&array);
+        assert!(slice == [1, 2][..]);
+    }
+
+    #[test]
+    fn lifetime_bounds() {
+        let array = [1_u8, 2];
+        assert_eq!(format!("{:?}", debug_send::new(&array)), "[1, 2]");
+        assert_eq!(format!("{:?}", lifetime_first::new(&array)), "[1, 2]");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn fn_names() {
+        let mut array = [1_u8, 2];
+        assert_eq!(format!("{:?}", renamed::make(&array)), "[1, 2]");
+
+        let slice: renamed::SliceMut = renamed::make_mut(&mut array);
+        assert_eq!(slice.len(), 2);
+    }
+
+    #[test]
+    fn metadata_fn() {
+        const METADATA: core::ptr::DynMetadata<ped::Dyn<u8>> = ped::metadata::<u8, u8>();
+
+        let array = [1_u8, 2];
+        // SAFETY:
+        // The metadata is for `u8`, the type of the elements of `array`.
+        let slice = unsafe {
+            crate::DynSlice::from_parts_with_metadata(METADATA, array.len(), array.as_ptr().cast())
+        };
+        assert!(slice == [1, 2][..]);
+        assert_eq!(slice.metadata(), Some(METADATA));
+        assert_eq!(METADATA.size_of(), 1);
+    }
+
+    #[test]
+    fn empty_fns() {
+        let slice = ped::empty::<u8, u8>();
+        assert!(slice.is_empty());
+        assert_eq!(slice.metadata(), Some(ped::metadata::<u8, u8>()));
+        slice.assert_invariants();
+        assert!(slice == [0_u8; 0][..]);
+
+        let slice = crate::standard::any::empty::<u16>();
+        assert_eq!(slice.downcast::<u16>(), Some(&[][..]));
+
+        let slice = ped::empty_untyped::<u8>();
+        assert!(slice.is_empty());
+        assert!(slice.metadata().is_none());
+        slice.assert_invariants();
+
+        let slice = crate::DynSliceMut::<dyn fmt::Debug>::empty();
+        assert_eq!(format!("{slice:?}"), "[]");
+    }
+
+    #[test]
+    fn dyn_slice_macro() {
+        assert!(dyn_slice![Ped<u8>: 1_u8, 2, 3] == [1, 2, 3][..]);
+        assert_eq!(dyn_slice![fmt::Debug: "a",].len(), 1);
+        assert_eq!(dyn_slice![mut fmt::Debug: String::new()].len(), 1);
+
+        // The array is constant, so it is promoted and lives long enough to be bound
+        let slice = dyn_slice![fmt::Debug: 1_u8, 2];
+        assert_eq!(format!("{slice:?}"), "[1, 2]");
+
+        dyn_slice!(let strings: fmt::Debug = [String::from("a"), String::from("b")]);
+        assert_eq!(format!("{strings:?}"), r#"["a", "b"]"#);
+
+        dyn_slice!(let mut values: Ped<u8> = [1_u8, 2, 3]);
+        let (first, rest) = values.split_first_mut().unwrap();
+        assert!(*first == 1);
+        assert!(rest == [2, 3][..]);
+    }
+
     macro_rules! test_iter {
         (
             $a:expr,
@@ -274,4 +608,20 @@ mod test {
         };
     }
     pub(crate) use test_iter;
+
+    #[cfg(any(feature = "futures-core", feature = "futures-io"))]
+    /// Returns a waker that does nothing when woken.
+    pub fn noop_waker() -> core::task::Waker {
+        use core::task::{RawWaker, RawWakerVTable, Waker};
+
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(
+            |_| RawWaker::new(core::ptr::null(), &VTABLE),
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        // SAFETY:
+        // The waker functions do nothing.
+        unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) }
+    }
 }
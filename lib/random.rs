@@ -0,0 +1,179 @@
+use core::ptr::{DynMetadata, Pointee};
+
+use rand::Rng;
+
+#[cfg(feature = "alloc")]
+use crate::iter::ChooseMultiple;
+use crate::{DynSlice, DynSliceMut};
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "rand")))]
+    /// Returns a reference to a random element, or `None` if the slice is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1, 2, 3, 4, 5];
+    /// let slice = debug::new(&array);
+    ///
+    /// let element = slice.choose(&mut rand::thread_rng());
+    /// # assert!(element.is_some());
+    /// println!("{element:?}"); // e.g. Some(3)
+    /// ```
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&Dyn> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let index = rng.gen_range(0..self.len);
+        // SAFETY:
+        // `index` is sampled from `0..self.len`.
+        Some(unsafe { self.get_unchecked(index) })
+    }
+
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(all(feature = "rand", feature = "alloc"))))]
+    /// Returns an iterator over `amount` distinct, randomly chosen elements, in a random order.
+    ///
+    /// If `amount` is greater than the length of the slice, every element is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let array = [1, 2, 3, 4, 5];
+    /// let slice = debug::new(&array);
+    ///
+    /// let chosen = slice.choose_multiple(&mut rand::thread_rng(), 2);
+    /// # assert_eq!(chosen.len(), 2);
+    /// for element in chosen {
+    ///     println!("{element:?}");
+    /// }
+    /// ```
+    pub fn choose_multiple<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> ChooseMultiple<'_, Dyn> {
+        ChooseMultiple {
+            slice: *self,
+            indices: rand::seq::index::sample(rng, self.len, amount.min(self.len)).into_iter(),
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "rand")))]
+    /// Returns a mutable reference to a random element, or `None` if the slice is empty.
+    pub fn choose_mut<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&mut Dyn> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let index = rng.gen_range(0..self.len);
+        // SAFETY:
+        // `index` is sampled from `0..self.len`.
+        Some(unsafe { self.get_unchecked_mut(index) })
+    }
+
+    #[cfg_attr(doc, doc(cfg(feature = "rand")))]
+    /// Shuffles the elements of the slice in place, using the Fisher-Yates shuffle.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let mut slice = debug::new_mut(&mut array);
+    ///
+    /// slice.shuffle(&mut rand::thread_rng());
+    /// # array.sort_unstable();
+    /// # assert_eq!(array, [1, 2, 3, 4, 5]);
+    /// println!("{array:?}"); // e.g. [3, 1, 5, 4, 2]
+    /// ```
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.len).rev() {
+            let j = rng.gen_range(0..=i);
+            // SAFETY:
+            // `i < self.len` and `j <= i`.
+            unsafe { self.swap_unchecked(i, j) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::standard::{debug, partial_eq};
+
+    fn rng() -> SmallRng {
+        SmallRng::seed_from_u64(0x00d1_5eed)
+    }
+
+    #[test]
+    fn choose() {
+        let mut rng = rng();
+
+        let array = [1, 2, 3, 4, 5];
+        let slice = partial_eq::new::<u8, _>(&array);
+        for _ in 0..32 {
+            let element = slice.choose(&mut rng).unwrap();
+            assert!(array.iter().any(|x| element == x));
+        }
+
+        let slice = partial_eq::new::<u8, u8>(&[]);
+        assert!(slice.choose(&mut rng).is_none());
+    }
+
+    #[test]
+    fn choose_mut() {
+        let mut rng = rng();
+
+        let mut array = [String::from("a"), String::from("b")];
+        let mut slice = crate::standard::dyn_clone::new_mut(&mut array);
+        let element = slice.choose_mut(&mut rng).unwrap();
+        assert!(element.clone_from_any(&String::from("c")));
+        assert!(array.iter().any(|x| x == "c"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn choose_multiple() {
+        let mut rng = rng();
+
+        let array: Vec<u8> = (0..16).collect();
+        let slice = debug::new(&array);
+
+        let chosen = slice.choose_multiple(&mut rng, 5);
+        assert_eq!(chosen.len(), 5);
+        let mut chosen: Vec<String> = chosen.map(|x| format!("{x:?}")).collect();
+        chosen.sort_unstable();
+        chosen.dedup();
+        assert_eq!(chosen.len(), 5);
+
+        assert_eq!(slice.choose_multiple(&mut rng, 100).len(), 16);
+        assert_eq!(slice.choose_multiple(&mut rng, 0).next().map(|_| ()), None);
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = rng();
+
+        let sorted: Vec<u32> = (0..64).collect();
+        let mut array = sorted.clone();
+        let mut slice = debug::new_mut(&mut array);
+        slice.shuffle(&mut rng);
+        assert_ne!(array, sorted);
+
+        array.sort_unstable();
+        assert_eq!(array, sorted);
+
+        let mut array: [String; 0] = [];
+        debug::new_mut(&mut array).shuffle(&mut rng);
+    }
+}
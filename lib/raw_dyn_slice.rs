@@ -0,0 +1,142 @@
+use core::ptr::{DynMetadata, Pointee};
+
+use crate::{DynSlice, DynSliceMut};
+
+/// The raw parts of a dyn slice, with a stable `#[repr(C)]` layout.
+///
+/// This can be used to pass a [`DynSlice`] or [`DynSliceMut`] across an `extern "C"` boundary,
+/// for example, to a separately compiled plugin that returns it back.
+///
+/// Note that the vtable pointer is only valid for the program (or library) that created it,
+/// so the slice must only be reconstructed with [`DynSlice::from_raw`] or [`DynSliceMut::from_raw`]
+/// on the same side of the boundary.
+///
+/// # Example
+/// ```
+/// use dyn_slice::{standard::debug, DynSlice, RawDynSlice};
+///
+/// extern "C" fn round_trip(raw: RawDynSlice) -> RawDynSlice {
+///     raw
+/// }
+///
+/// let array = [1, 2, 3, 4, 5];
+/// let slice = debug::new(&array);
+///
+/// let raw = round_trip(slice.into_raw());
+/// // SAFETY: `raw` was created from a `debug::Slice` of `array`, which is still borrowed
+/// let slice: debug::Slice = unsafe { DynSlice::from_raw(raw) };
+/// # assert_eq!(format!("{slice:?}"), "[1, 2, 3, 4, 5]");
+/// println!("{slice:?}"); // [1, 2, 3, 4, 5]
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RawDynSlice {
+    /// A pointer to the underlying slice.
+    pub data: *const (),
+    /// The number of elements in the slice.
+    pub len: usize,
+    /// A pointer to the vtable of the elements, which may be null if `len == 0`.
+    pub vtable: *const (),
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Decompose the dyn slice into its raw parts.
+    pub const fn into_raw(self) -> RawDynSlice {
+        RawDynSlice {
+            data: self.as_ptr(),
+            len: self.len(),
+            vtable: self.vtable_ptr(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct a dyn slice from its raw parts.
+    ///
+    /// # Safety
+    /// Caller must ensure that the parts satisfy the safety requirements of [`DynSlice::from_parts`].
+    /// This is always the case if `raw` was created with [`DynSlice::into_raw`] from a slice of the
+    /// same `Dyn` that lives for at least `'a`.
+    pub const unsafe fn from_raw(raw: RawDynSlice) -> Self {
+        Self::from_parts(raw.vtable, raw.len, raw.data)
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Decompose the mutable dyn slice into its raw parts.
+    pub const fn into_raw(self) -> RawDynSlice {
+        self.0.into_raw()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Construct a mutable dyn slice from its raw parts.
+    ///
+    /// # Safety
+    /// Caller must ensure that the parts satisfy the safety requirements of [`DynSliceMut::from_parts`].
+    /// This is always the case if `raw` was created with [`DynSliceMut::into_raw`] from a slice of the
+    /// same `Dyn` that is mutably borrowed for at least `'a`.
+    pub const unsafe fn from_raw(raw: RawDynSlice) -> Self {
+        Self::from_parts(raw.vtable, raw.len, raw.data.cast_mut())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, size_of};
+
+    use crate::{
+        standard::{add_assign, partial_eq},
+        DynSlice, DynSliceMut, RawDynSlice,
+    };
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<RawDynSlice>(), 3 * size_of::<usize>());
+        assert_eq!(align_of::<RawDynSlice>(), align_of::<usize>());
+    }
+
+    #[test]
+    fn round_trip() {
+        const extern "C" fn pass(raw: RawDynSlice) -> RawDynSlice {
+            raw
+        }
+
+        let array = [1, 2, 3, 4, 5];
+        let slice = partial_eq::new::<u8, u8>(&array);
+
+        let raw = pass(slice.into_raw());
+        assert_eq!(raw.data, slice.as_ptr());
+        assert_eq!(raw.len, slice.len());
+        assert_eq!(raw.vtable, slice.vtable_ptr());
+
+        // SAFETY:
+        // `raw` was created from a slice of the same type that is still borrowed.
+        let slice: partial_eq::Slice<u8> = unsafe { DynSlice::from_raw(raw) };
+        assert!(slice == array.as_slice());
+
+        let empty = partial_eq::new::<u8, u8>(&[]);
+        // SAFETY:
+        // `raw` was created from a slice of the same type that is still borrowed.
+        let empty: partial_eq::Slice<u8> = unsafe { DynSlice::from_raw(empty.into_raw()) };
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn round_trip_mut() {
+        let mut array = [1, 2, 3, 4, 5];
+        let slice = add_assign::new_mut::<u8, u8>(&mut array);
+
+        let raw = slice.into_raw();
+        // SAFETY:
+        // `raw` was created from a mutable slice of the same type that is still borrowed.
+        let mut slice: add_assign::SliceMut<u8> = unsafe { DynSliceMut::from_raw(raw) };
+        slice.iter_mut().for_each(|x| *x += 10);
+
+        assert_eq!(array, [11, 12, 13, 14, 15]);
+    }
+}
@@ -0,0 +1,255 @@
+use core::{
+    pin::Pin,
+    ptr::{DynMetadata, Pointee},
+    task::{Context, Poll},
+};
+
+use futures_core::{FusedStream, Stream};
+
+use crate::DynSliceMut;
+
+/// A stream that polls every stream in a mutable dyn slice, and yields their items as they
+/// arrive, like [`futures::stream::SelectAll`](https://docs.rs/futures/latest/futures/stream/struct.SelectAll.html).
+///
+/// The streams are stored in the slice, so no allocation is needed. When a stream finishes, it is
+/// swapped to the end of the slice, so the order of the streams is not kept. The stream ends when
+/// every stream in the slice has finished.
+///
+/// This is created by [`DynSliceMut::select_all`].
+///
+/// # Example
+/// ```
+/// # use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+/// # const VTABLE: RawWakerVTable =
+/// #     RawWakerVTable::new(|_| RawWaker::new(std::ptr::null(), &VTABLE), |_| {}, |_| {}, |_| {});
+/// # // SAFETY: the waker functions do nothing
+/// # let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+/// use std::pin::Pin;
+///
+/// use dyn_slice::standard::stream;
+/// use futures_core::Stream;
+///
+/// struct Countdown(u8);
+/// impl Stream for Countdown {
+///     type Item = u8;
+///
+///     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u8>> {
+///         Poll::Ready(self.0.checked_sub(1).map(|n| {
+///             self.0 = n;
+///             n
+///         }))
+///     }
+/// }
+///
+/// let mut streams = [Countdown(1), Countdown(3)];
+/// let mut select = stream::new_mut(&mut streams).select_all();
+///
+/// let mut cx = Context::from_waker(&waker);
+/// let mut items = Vec::new();
+/// while let Poll::Ready(Some(item)) = Pin::new(&mut select).poll_next(&mut cx) {
+///     items.push(item);
+/// }
+/// assert_eq!(items, [0, 2, 1, 0]);
+/// ```
+#[cfg_attr(doc, doc(cfg(feature = "futures-core")))]
+pub struct SelectAll<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> {
+    slice: DynSliceMut<'a, Dyn>,
+    /// The number of streams at the start of the slice that have not finished
+    active: usize,
+    /// The index of the stream to poll first, so that every stream gets a turn
+    next: usize,
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSliceMut<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    #[cfg_attr(doc, doc(cfg(feature = "futures-core")))]
+    /// Converts the slice into a [`SelectAll`] stream, which yields the items of every stream in
+    /// the slice as they arrive.
+    pub const fn select_all(self) -> SelectAll<'a, Dyn> {
+        SelectAll {
+            active: self.0.len,
+            slice: self,
+            next: 0,
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> SelectAll<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Returns the number of streams that have not finished.
+    pub const fn len(&self) -> usize {
+        self.active
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if every stream has finished.
+    pub const fn is_empty(&self) -> bool {
+        self.active == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Converts the stream back into the underlying slice.
+    ///
+    /// The streams that have not finished are at the start of the slice, followed by the
+    /// streams that have finished.
+    pub const fn into_inner(self) -> DynSliceMut<'a, Dyn> {
+        self.slice
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Stream + Unpin> Stream
+    for SelectAll<'a, Dyn>
+{
+    type Item = Dyn::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        // Each stream is polled at most once per call, apart from a stream that is swapped into
+        // the place of a finished stream after it has already been polled
+        for _ in 0..this.active {
+            if this.active == 0 {
+                break;
+            }
+            if this.next >= this.active {
+                this.next = 0;
+            }
+
+            let index = this.next;
+            // SAFETY:
+            // `index < this.active <= this.slice.len()`.
+            let stream = unsafe { this.slice.get_unchecked_mut(index) };
+            match Pin::new(stream).poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    this.next = index + 1;
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {
+                    this.active -= 1;
+                    // SAFETY:
+                    // `index <= this.active`, which was less than `this.slice.len()` before it
+                    // was decremented.
+                    unsafe { this.slice.swap_unchecked(index, this.active) };
+                }
+                Poll::Pending => this.next = index + 1,
+            }
+        }
+
+        if this.active == 0 {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.active == 0 {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>> + Stream + Unpin> FusedStream
+    for SelectAll<'a, Dyn>
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.active == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use futures_core::{FusedStream, Stream};
+
+    use crate::{standard::stream, test::noop_waker};
+
+    /// Yields the items, returning `Pending` before each one
+    struct Delayed<'a> {
+        items: &'a [u8],
+        ready: bool,
+    }
+
+    impl Stream for Delayed<'_> {
+        type Item = u8;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<u8>> {
+            let Some((&first, rest)) = self.items.split_first() else {
+                return Poll::Ready(None);
+            };
+            if !self.ready {
+                self.ready = true;
+                return Poll::Pending;
+            }
+
+            self.items = rest;
+            self.ready = false;
+            Poll::Ready(Some(first))
+        }
+    }
+
+    #[test]
+    fn select_all() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut streams = [
+            Delayed {
+                items: &[1, 2],
+                ready: false,
+            },
+            Delayed {
+                items: &[],
+                ready: false,
+            },
+            Delayed {
+                items: &[3],
+                ready: true,
+            },
+        ];
+        let mut select = stream::new_mut(&mut streams).select_all();
+        assert_eq!(select.len(), 3);
+
+        let mut items = Vec::new();
+        let mut pending = 0;
+        loop {
+            match Pin::new(&mut select).poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => items.push(item),
+                Poll::Ready(None) => break,
+                Poll::Pending => pending += 1,
+            }
+        }
+        items.sort_unstable();
+        assert_eq!(items, [1, 2, 3]);
+        assert!(pending > 0);
+
+        assert!(select.is_empty());
+        assert!(select.is_terminated());
+        assert_eq!(Pin::new(&mut select).poll_next(&mut cx), Poll::Ready(None));
+        assert_eq!(select.size_hint(), (0, Some(0)));
+        assert_eq!(select.into_inner().len(), 3);
+    }
+
+    #[test]
+    fn empty() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut streams: [Delayed; 0] = [];
+        let mut select = stream::new_mut(&mut streams).select_all();
+        assert!(select.is_terminated());
+        assert_eq!(Pin::new(&mut select).poll_next(&mut cx), Poll::Ready(None));
+    }
+}
@@ -0,0 +1,138 @@
+use core::{
+    ops::Deref,
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::DynSlice;
+
+/// A [`DynSlice`] that implements [`Send`], even if `Dyn` does not include `+ Send`.
+///
+/// This is created by [`DynSlice::assert_send`].
+///
+/// # Example
+/// ```
+/// use dyn_slice::standard::debug;
+///
+/// let array = [1, 2, 3];
+/// let slice = debug::new(&array);
+/// // SAFETY: the underlying type, `i32`, is `Sync`
+/// let slice = unsafe { slice.assert_send() };
+///
+/// std::thread::scope(|s| {
+///     s.spawn(move || println!("{:?}", *slice)); // [1, 2, 3]
+/// });
+/// ```
+pub struct SendDynSlice<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>>(DynSlice<'a, Dyn>);
+
+/// A [`DynSlice`] that implements [`Sync`], even if `Dyn` does not include `+ Sync`.
+///
+/// This is created by [`DynSlice::assert_sync`].
+pub struct SyncDynSlice<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>>(DynSlice<'a, Dyn>);
+
+// SAFETY:
+// The constructor requires the caller to ensure that the underlying type is `Sync`, so shared
+// references to the elements can be sent to other threads.
+unsafe impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Send for SendDynSlice<'a, Dyn> {}
+// SAFETY:
+// The constructor requires the caller to ensure that the underlying type is `Sync`, so shared
+// references to the elements can be shared between threads.
+unsafe impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Sync for SyncDynSlice<'a, Dyn> {}
+
+macro_rules! impl_wrapper {
+    ( $wrapper:ident ) => {
+        impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> $wrapper<'a, Dyn> {
+            #[inline]
+            #[must_use]
+            /// Returns the inner dyn slice.
+            pub const fn into_inner(self) -> DynSlice<'a, Dyn> {
+                self.0
+            }
+        }
+
+        impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Clone for $wrapper<'a, Dyn> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+        impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Copy for $wrapper<'a, Dyn> {}
+
+        impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> Deref for $wrapper<'a, Dyn> {
+            type Target = DynSlice<'a, Dyn>;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+}
+impl_wrapper!(SendDynSlice);
+impl_wrapper!(SyncDynSlice);
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    #[inline]
+    #[must_use]
+    /// Wraps the slice in a [`SendDynSlice`], which implements [`Send`].
+    ///
+    /// This is useful when `Dyn` does not include `+ Send`, but the underlying type is known.
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type is [`Sync`], as is required to send a
+    /// `&[T]` to another thread.
+    pub const unsafe fn assert_send(self) -> SendDynSlice<'a, Dyn> {
+        SendDynSlice(self)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Wraps the slice in a [`SyncDynSlice`], which implements [`Sync`].
+    ///
+    /// This is useful when `Dyn` does not include `+ Sync`, but the underlying type is known.
+    ///
+    /// # Safety
+    /// The caller must ensure that the underlying type is [`Sync`].
+    pub const unsafe fn assert_sync(self) -> SyncDynSlice<'a, Dyn> {
+        SyncDynSlice(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use crate::standard::debug;
+
+    const fn is_send<T: Send>(_: &T) {}
+    const fn is_sync<T: Sync>(_: &T) {}
+
+    #[test]
+    fn send() {
+        let array = [1_u8, 2, 3];
+        // SAFETY:
+        // The underlying type is `u8`, which is `Sync`.
+        let slice = unsafe { debug::new(&array).assert_send() };
+        is_send(&slice);
+
+        let debug = thread::scope(|s| s.spawn(move || format!("{:?}", *slice)).join().unwrap());
+        assert_eq!(debug, "[1, 2, 3]");
+        assert_eq!(slice.into_inner().len(), 3);
+    }
+
+    #[test]
+    fn sync() {
+        let array = [1_u8, 2, 3];
+        // SAFETY:
+        // The underlying type is `u8`, which is `Sync`.
+        let slice = unsafe { debug::new(&array).assert_sync() };
+        is_sync(&slice);
+
+        let slice = &slice;
+        let debug = thread::scope(|s| {
+            let a = s.spawn(move || format!("{:?}", &slice[0]));
+            let b = s.spawn(move || format!("{:?}", &slice[2]));
+            (a.join().unwrap(), b.join().unwrap())
+        });
+        assert_eq!(debug, (String::from("1"), String::from("3")));
+    }
+}
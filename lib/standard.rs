@@ -1,5 +1,5 @@
 use core::{
-    any::Any,
+    any::{Any, TypeId},
     borrow::{Borrow, BorrowMut},
     cmp::{Ordering, PartialEq, PartialOrd},
     convert::{AsMut, AsRef},
@@ -9,15 +9,18 @@ use core::{
     future::Future,
     hash::{self, BuildHasher, Hasher},
     iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator},
-    marker::{Send, Sized, Sync},
+    marker::{Send, Sized, Sync, Unsize},
+    mem::size_of,
     ops::{
         AddAssign, BitAndAssign, BitOrAssign, BitXorAssign, Deref, DerefMut, DivAssign, Index,
         IndexMut, MulAssign, RemAssign, ShlAssign, ShrAssign, SubAssign,
     },
+    panic::{RefUnwindSafe, UnwindSafe},
     ptr::{DynMetadata, Pointee},
+    slice,
 };
 
-use crate::DynSliceMut;
+use crate::{utils::metadata_of, DynRingSlice, DynSliceMut};
 
 use super::{declare_new_fns, DynSlice};
 
@@ -37,7 +40,7 @@ macro_rules! feature_availability {
 declare_new_fns!(
     #[crate = crate]
     ///
-    /// `DynSlice(Mut)<dyn Any>`, `DynSlice(Mut)<dyn Any + Send>` and `DynSlice(Mut)<dyn Any + Send + Sync>` have a few extra methods:
+    /// `DynSlice(Mut)<dyn Any>`, and slices of the other [`AnyLike`](super::AnyLike) trait objects, have a few extra methods:
     /// - [`DynSlice::is`]
     /// - [`DynSlice::downcast`]
     /// - [`DynSliceMut::downcast_mut`]
@@ -69,7 +72,7 @@ declare_new_fns!(
 declare_new_fns!(
     #[crate = crate]
     ///
-    /// `DynSlice(Mut)<dyn Any>`, `DynSlice(Mut)<dyn Any + Send>` and `DynSlice(Mut)<dyn Any + Send + Sync>` have a few extra methods:
+    /// `DynSlice(Mut)<dyn Any>`, and slices of the other [`AnyLike`](super::AnyLike) trait objects, have a few extra methods:
     /// - [`DynSlice::is`]
     /// - [`DynSlice::downcast`]
     /// - [`DynSliceMut::downcast_mut`]
@@ -101,7 +104,7 @@ declare_new_fns!(
 declare_new_fns!(
     #[crate = crate]
     ///
-    /// `DynSlice(Mut)<dyn Any>`, `DynSlice(Mut)<dyn Any + Send>` and `DynSlice(Mut)<dyn Any + Send + Sync>` have a few extra methods:
+    /// `DynSlice(Mut)<dyn Any>`, and slices of the other [`AnyLike`](super::AnyLike) trait objects, have a few extra methods:
     /// - [`DynSlice::is`]
     /// - [`DynSlice::downcast`]
     /// - [`DynSliceMut::downcast_mut`]
@@ -130,44 +133,199 @@ declare_new_fns!(
     /// ```
     pub any_sync_send Any + Sync + Send
 );
-macro_rules! impl_any_methods {
-    ( $( $t:ty ),* ) => {
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A `dyn Any` trait object, with any combination of the [`Send`], [`Sync`], [`UnwindSafe`] and
+/// [`RefUnwindSafe`] auto traits.
+///
+/// Dyn slices of these types have a few extra methods:
+/// - [`DynSlice::is`]
+/// - [`DynSlice::downcast`]
+/// - [`DynSlice::downcast_to_trait`]
+/// - [`DynSlice::to_vec_of`]
+/// - [`DynSlice::into_iter_downcast`]
+/// - [`DynSliceMut::downcast_mut`]
+/// - [`DynSliceMut::downcast_to_trait_mut`]
+///
+/// This trait is sealed, so cannot be implemented outside of this crate.
+pub trait AnyLike: Any + sealed::Sealed + Pointee<Metadata = DynMetadata<Self>> {}
+
+macro_rules! impl_any_like {
+    ( $( $t:ty ),* $(,)? ) => {
         $(
-            impl<'a> DynSlice<'a, $t> {
-                /// Returns `true` if the underlying slice is of type `T`.
-                #[must_use]
-                pub fn is<T: 'static>(&self) -> bool {
-                    self.get(0).map_or(true, <$t>::is::<T>)
-                }
+            impl sealed::Sealed for $t {}
+            impl AnyLike for $t {}
+        )*
+    };
+}
+impl_any_like!(
+    dyn Any,
+    dyn Any + Send,
+    dyn Any + Sync,
+    dyn Any + Sync + Send,
+    dyn Any + UnwindSafe,
+    dyn Any + Send + UnwindSafe,
+    dyn Any + Sync + UnwindSafe,
+    dyn Any + Sync + Send + UnwindSafe,
+    dyn Any + RefUnwindSafe,
+    dyn Any + Send + RefUnwindSafe,
+    dyn Any + Sync + RefUnwindSafe,
+    dyn Any + Sync + Send + RefUnwindSafe,
+    dyn Any + UnwindSafe + RefUnwindSafe,
+    dyn Any + Send + UnwindSafe + RefUnwindSafe,
+    dyn Any + Sync + UnwindSafe + RefUnwindSafe,
+    dyn Any + Sync + Send + UnwindSafe + RefUnwindSafe,
+);
 
-                /// Returns the underlying slice as `&[T]`, or `None` if the underlying slice is not of type `T`.
-                #[must_use]
-                pub fn downcast<T: 'static>(&self) -> Option<&[T]> {
-                    self.is::<T>().then(|| {
-                        // SAFETY:
-                        // The above line guarantees that the underlying slice is of type `T`,
-                        // so the downcast is valid.
-                        unsafe { self.downcast_unchecked() }
-                    })
-                }
-            }
+impl<'a, Dyn: AnyLike + ?Sized> DynSlice<'a, Dyn> {
+    /// Returns `true` if the underlying slice is of type `T`.
+    #[must_use]
+    pub fn is<T: 'static>(&self) -> bool {
+        // `Dyn` is a `dyn Any` trait object, so `type_id` is called through the vtable and
+        // returns the type id of the underlying type
+        self.get(0)
+            .map_or(true, |first| first.type_id() == TypeId::of::<T>())
+    }
 
-            impl<'a> DynSliceMut<'a, $t> {
-                /// Returns the underlying slice as `&mut [T]`, or `None` if the underlying slice is not of type `T`.
-                #[must_use]
-                pub fn downcast_mut<T: 'static>(&mut self) -> Option<&mut [T]> {
-                    self.0.is::<T>().then(|| {
-                        // SAFETY:
-                        // The above line guarantees that the underlying slice is of type `T`,
-                        // so the downcast is valid.
-                        unsafe { self.downcast_unchecked_mut() }
-                    })
-                }
+    /// Returns the underlying slice as `&[T]`, or `None` if the underlying slice is not of type `T`.
+    #[must_use]
+    pub fn downcast<T: 'static>(&self) -> Option<&[T]> {
+        if self.is_empty() {
+            // Every empty slice passes `is`, but the data pointer may not be aligned for `T`
+            return Some(&[]);
+        }
+
+        self.is::<T>().then(|| {
+            // SAFETY:
+            // The above line guarantees that the underlying slice is of type `T`,
+            // so the downcast is valid.
+            unsafe { self.downcast_unchecked() }
+        })
+    }
+
+    /// Returns the slice as a dyn slice of another trait, `NewDyn`, or `None` if the underlying
+    /// slice is not of type `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::{any, debug};
+    ///
+    /// let array = [1_u8, 2, 3];
+    /// let slice = any::new(&array);
+    ///
+    /// let debug_slice = slice.downcast_to_trait::<u8, debug::Dyn>().unwrap();
+    /// # assert_eq!(format!("{debug_slice:?}"), "[1, 2, 3]");
+    /// println!("{debug_slice:?}"); // [1, 2, 3]
+    /// assert!(slice.downcast_to_trait::<u16, debug::Dyn>().is_none());
+    /// ```
+    #[must_use]
+    pub fn downcast_to_trait<T, NewDyn>(&self) -> Option<DynSlice<'a, NewDyn>>
+    where
+        T: Unsize<NewDyn> + 'static,
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>>,
+    {
+        self.is::<T>().then(|| {
+            let metadata = metadata_of::<T, NewDyn>();
+
+            // SAFETY:
+            // The above line guarantees that the underlying slice is of type `T`, and the
+            // metadata is for `T` as `NewDyn`, so the new slice is valid for the same data.
+            unsafe { DynSlice::from_parts_with_metadata(metadata, self.len(), self.as_ptr()) }
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Returns a [`Vec`](alloc::vec::Vec) of clones of the elements, or `None` if the underlying
+    /// slice is not of type `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let array = [String::from("a"), String::from("b")];
+    /// let slice = any::new(&array);
+    ///
+    /// assert_eq!(slice.to_vec_of::<String>(), Some(vec![String::from("a"), String::from("b")]));
+    /// assert_eq!(slice.to_vec_of::<u8>(), None);
+    /// ```
+    #[must_use]
+    pub fn to_vec_of<T: Clone + 'static>(&self) -> Option<alloc::vec::Vec<T>> {
+        self.downcast::<T>().map(<[T]>::to_vec)
+    }
+
+    /// Returns an iterator over the elements as `&T`, or `None` if the underlying slice is not of
+    /// type `T`.
+    ///
+    /// Unlike [`DynSlice::downcast`], the references live as long as the underlying slice.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::any;
+    ///
+    /// let array = [1_u8, 2, 3];
+    /// let slice = any::new(&array);
+    ///
+    /// let sum: u8 = slice.into_iter_downcast::<u8>().unwrap().sum();
+    /// assert_eq!(sum, 6);
+    /// assert!(slice.into_iter_downcast::<u16>().is_none());
+    /// ```
+    #[must_use]
+    pub fn into_iter_downcast<T: 'static>(self) -> Option<core::slice::Iter<'a, T>> {
+        if self.is_empty() {
+            // Every empty slice passes `is`, but the data pointer may not be aligned for `T`
+            return Some(<&[T]>::default().iter());
+        }
+
+        self.is::<T>().then(|| {
+            // SAFETY:
+            // The above line guarantees that the underlying slice is of type `T`,
+            // so the downcast is valid. The data is borrowed for 'a.
+            unsafe { core::slice::from_raw_parts(self.as_ptr().cast::<T>(), self.len()) }.iter()
+        })
+    }
+}
+
+impl<'a, Dyn: AnyLike + ?Sized> DynSliceMut<'a, Dyn> {
+    /// Returns the underlying slice as `&mut [T]`, or `None` if the underlying slice is not of type `T`.
+    #[must_use]
+    pub fn downcast_mut<T: 'static>(&mut self) -> Option<&mut [T]> {
+        if self.is_empty() {
+            // Every empty slice passes `is`, but the data pointer may not be aligned for `T`
+            return Some(&mut []);
+        }
+
+        self.0.is::<T>().then(|| {
+            // SAFETY:
+            // The above line guarantees that the underlying slice is of type `T`,
+            // so the downcast is valid.
+            unsafe { self.downcast_unchecked_mut() }
+        })
+    }
+
+    /// Returns the slice as a mutable dyn slice of another trait, `NewDyn`, or `None` if the
+    /// underlying slice is not of type `T`.
+    #[must_use]
+    pub fn downcast_to_trait_mut<T, NewDyn>(&mut self) -> Option<DynSliceMut<'_, NewDyn>>
+    where
+        T: Unsize<NewDyn> + 'static,
+        NewDyn: ?Sized + Pointee<Metadata = DynMetadata<NewDyn>>,
+    {
+        self.0.is::<T>().then(|| {
+            let metadata = metadata_of::<T, NewDyn>();
+
+            // SAFETY:
+            // The above line guarantees that the underlying slice is of type `T`, and the
+            // metadata is for `T` as `NewDyn`, so the new slice is valid for the same data.
+            // The new slice borrows `self` mutably, so it is the only way to access the data.
+            unsafe {
+                DynSliceMut::from_parts_with_metadata(metadata, self.len(), self.as_mut_ptr())
             }
-        )*
-    };
+        })
+    }
 }
-impl_any_methods!(dyn Any, dyn Any + Send, dyn Any + Sync + Send);
 
 declare_new_fns!(
     #[crate = crate]
@@ -181,7 +339,8 @@ declare_new_fns!(
 declare_new_fns!(
     #[crate = crate]
     ///
-    /// `DynSlice(Mut)<dyn PartialEq<Rhs>>` implements `PartialEq<[Rhs]>`
+    /// `DynSlice(Mut)<dyn PartialEq<Rhs>>` implements `PartialEq<[Rhs]>`, `PartialEq<[Rhs; N]>`
+    /// and, with the `alloc` feature, `PartialEq<Vec<Rhs>>`.
     ///
     /// # Example
     /// ```
@@ -190,6 +349,7 @@ declare_new_fns!(
     /// let slice = partial_eq::new(&array);
     ///
     /// assert!(slice == array.as_slice());
+    /// assert!(slice == [1, 2, 4, 8]);
     /// ```
     pub partial_eq<Rhs> PartialEq<Rhs>
 );
@@ -228,6 +388,231 @@ impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized, Rh
         self.0.eq(*other)
     }
 }
+impl<
+        'a,
+        Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized,
+        Rhs,
+        const N: usize,
+    > PartialEq<[Rhs; N]> for DynSlice<'a, Dyn>
+{
+    #[inline]
+    fn eq(&self, other: &[Rhs; N]) -> bool {
+        self.eq(other.as_slice())
+    }
+}
+impl<
+        'a,
+        Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized,
+        Rhs,
+        const N: usize,
+    > PartialEq<[Rhs; N]> for DynSliceMut<'a, Dyn>
+{
+    #[inline]
+    fn eq(&self, other: &[Rhs; N]) -> bool {
+        self.0.eq(other.as_slice())
+    }
+}
+impl<
+        'a,
+        Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized,
+        Rhs,
+        const N: usize,
+    > PartialEq<&[Rhs; N]> for DynSlice<'a, Dyn>
+{
+    #[inline]
+    fn eq(&self, other: &&[Rhs; N]) -> bool {
+        self.eq(other.as_slice())
+    }
+}
+impl<
+        'a,
+        Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized,
+        Rhs,
+        const N: usize,
+    > PartialEq<&[Rhs; N]> for DynSliceMut<'a, Dyn>
+{
+    #[inline]
+    fn eq(&self, other: &&[Rhs; N]) -> bool {
+        self.0.eq(other.as_slice())
+    }
+}
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized, Rhs>
+    PartialEq<alloc::vec::Vec<Rhs>> for DynSlice<'a, Dyn>
+{
+    #[inline]
+    fn eq(&self, other: &alloc::vec::Vec<Rhs>) -> bool {
+        self.eq(other.as_slice())
+    }
+}
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialEq<Rhs> + ?Sized, Rhs>
+    PartialEq<alloc::vec::Vec<Rhs>> for DynSliceMut<'a, Dyn>
+{
+    #[inline]
+    fn eq(&self, other: &alloc::vec::Vec<Rhs>) -> bool {
+        self.0.eq(other.as_slice())
+    }
+}
+
+/// A marker for types whose equality is the same as equality of their bytes.
+///
+/// This allows [`DynSlice::bytes_eq_slice`] to compare slices of the type with `memcmp`.
+///
+/// # Safety
+/// For all values `a` and `b` of the type, `a == b` must be `true` if, and only if, the bytes of
+/// `a` and `b` are equal. This means that the type must not have any padding bytes, and must not
+/// have values that are equal with different bytes (like `0.0` and `-0.0`) or values that are not
+/// equal to themselves (like `NaN`).
+pub unsafe trait BytesEq: Eq {}
+
+macro_rules! impl_bytes_eq {
+    ( $( $ty:ty ),* $(,)? ) => {
+        $(
+            // SAFETY:
+            // The type has no padding, and its values are equal if, and only if, their bytes
+            // are equal.
+            unsafe impl BytesEq for $ty {}
+        )*
+    };
+}
+
+impl_bytes_eq!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+);
+
+// SAFETY:
+// Arrays have no padding between elements, and are equal if, and only if, their elements are
+// equal.
+unsafe impl<T: BytesEq, const N: usize> BytesEq for [T; N] {}
+
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + ?Sized> DynSlice<'a, Dyn> {
+    #[must_use]
+    /// Returns `true` if the slice is equal to `other`, like the `PartialEq<[T]>` implementation.
+    ///
+    /// If the underlying type of the slice is `T`, the slices are compared with a single `memcmp`,
+    /// rather than calling [`PartialEq::eq`] through the vtable for every element. Otherwise, the
+    /// elements are compared one by one.
+    ///
+    /// To compare two dyn slices by their bytes, use [`DynSlice::bytes_eq`].
+    ///
+    /// # Safety
+    /// The caller must ensure that, if the metadata of the slice matches the metadata for `T`, the
+    /// underlying type is `T`, as equal metadata does not prove that the types are the same.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::partial_eq;
+    /// let values: Vec<u32> = (0..1024).collect();
+    /// let slice = partial_eq::new(&values);
+    ///
+    /// // SAFETY: the underlying type of the slice is `u32`
+    /// assert!(unsafe { slice.bytes_eq_slice(&values) });
+    /// assert!(!unsafe { slice.bytes_eq_slice(&values[1..]) });
+    /// ```
+    pub unsafe fn bytes_eq_slice<T: BytesEq + Unsize<Dyn>>(&self, other: &[T]) -> bool
+    where
+        Dyn: PartialEq<T>,
+    {
+        if self.len != other.len() {
+            return false;
+        }
+
+        let metadata = metadata_of::<T, Dyn>();
+        if self.metadata() != Some(metadata) {
+            return self == other;
+        }
+
+        let bytes = self.len * size_of::<T>();
+        // SAFETY:
+        // The metadata is for `T`, so the caller guarantees that the underlying type is `T`. The
+        // slice is therefore a valid `[T]` with `self.len` elements, which is `bytes` bytes long,
+        // as is `other`. `T` has no padding, so all of the bytes are initialised.
+        let (a, b) = unsafe {
+            (
+                slice::from_raw_parts(self.as_ptr().cast::<u8>(), bytes),
+                slice::from_raw_parts(other.as_ptr().cast::<u8>(), bytes),
+            )
+        };
+        a == b
+    }
+}
+
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + ?Sized> DynSlice<'a, Dyn> {
+    #[must_use]
+    /// Returns the sub-slice after `prefix`, or `None` if the slice does not start with `prefix`.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::partial_eq;
+    /// let array: [u8; 4] = [1, 2, 4, 8];
+    /// let slice = partial_eq::new(&array);
+    ///
+    /// assert!(slice.strip_prefix(&[1, 2]).unwrap() == [4, 8][..]);
+    /// assert!(slice.strip_prefix(&[2]).is_none());
+    /// ```
+    pub fn strip_prefix<Rhs>(&self, prefix: &[Rhs]) -> Option<DynSlice<Dyn>>
+    where
+        Dyn: PartialEq<Rhs>,
+    {
+        let rest_len = self.len().checked_sub(prefix.len())?;
+
+        // SAFETY:
+        // `prefix.len() + rest_len == self.len()`, so both sub-slices are within the slice.
+        let (head, rest) = unsafe {
+            (
+                self.slice_unchecked(0, prefix.len()),
+                self.slice_unchecked(prefix.len(), rest_len),
+            )
+        };
+        (head == prefix).then_some(rest)
+    }
+
+    #[must_use]
+    /// Returns the sub-slice before `suffix`, or `None` if the slice does not end with `suffix`.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::partial_eq;
+    /// let array: [u8; 4] = [1, 2, 4, 8];
+    /// let slice = partial_eq::new(&array);
+    ///
+    /// assert!(slice.strip_suffix(&[4, 8]).unwrap() == [1, 2][..]);
+    /// assert!(slice.strip_suffix(&[4]).is_none());
+    /// ```
+    pub fn strip_suffix<Rhs>(&self, suffix: &[Rhs]) -> Option<DynSlice<Dyn>>
+    where
+        Dyn: PartialEq<Rhs>,
+    {
+        let rest_len = self.len().checked_sub(suffix.len())?;
+
+        // SAFETY:
+        // `rest_len + suffix.len() == self.len()`, so both sub-slices are within the slice.
+        let (rest, tail) = unsafe {
+            (
+                self.slice_unchecked(0, rest_len),
+                self.slice_unchecked(rest_len, suffix.len()),
+            )
+        };
+        (tail == suffix).then_some(rest)
+    }
+}
 declare_new_fns!(
     #[crate = crate]
     ///
@@ -297,11 +682,115 @@ impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + PartialOrd<Rhs> + ?Sized, R
 
 declare_new_fns!(
     #[crate = crate]
-    pub as_ref<T> AsRef<T>
+    ///
+    /// `DynSlice(Mut)<dyn AsRef<[u8]>>` has [`DynSlice::total_len`], [`DynSlice::flatten_into`]
+    /// and, with the `std` feature, `bytes_reader` and `to_io_slices`.
+    ///
+    /// `DynSlice(Mut)<dyn AsRef<str>>` ([`StrSlice`]) has [`DynSlice::iter_str`],
+    /// [`DynSlice::total_display_len_hint`] and [`DynSlice::concat_to_string`].
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::as_ref;
+    /// let buffers = [vec![1_u8, 2], vec![3]];
+    /// let slice = as_ref::new::<[u8], _>(&buffers);
+    ///
+    /// assert_eq!(slice.total_len(), 3);
+    /// ```
+    pub as_ref<T: ?Sized> AsRef<T>
 );
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]> + ?Sized> DynSlice<'a, Dyn> {
+    #[must_use]
+    /// Returns the total number of bytes in the elements.
+    pub fn total_len(&self) -> usize {
+        self.iter().map(|element| element.as_ref().len()).sum()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    /// Appends the bytes of each element to `out`, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::as_ref;
+    /// let buffers = [&b"dyn"[..], b"-", b"slice"];
+    /// let slice = as_ref::new::<[u8], _>(&buffers);
+    ///
+    /// let mut out = Vec::new();
+    /// slice.flatten_into(&mut out);
+    /// assert_eq!(out, b"dyn-slice");
+    /// ```
+    pub fn flatten_into(&self, out: &mut alloc::vec::Vec<u8>) {
+        out.reserve(self.total_len());
+        for element in self {
+            out.extend_from_slice(element.as_ref());
+        }
+    }
+}
+/// An alias for a dyn slice of string-like elements, `&dyn [AsRef<str>]`.
+///
+/// # Example
+/// ```
+/// # use dyn_slice::standard::{as_ref, StrSlice};
+/// fn shout(words: StrSlice) -> String {
+///     words.concat_to_string().to_uppercase()
+/// }
+///
+/// let words = [String::from("dyn"), String::from("-slice")];
+/// # assert_eq!(shout(as_ref::new(&words)), "DYN-SLICE");
+/// println!("{}", shout(as_ref::new(&words))); // DYN-SLICE
+/// ```
+pub type StrSlice<'a> = as_ref::Slice<'a, str>;
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + AsRef<str> + ?Sized> DynSlice<'a, Dyn> {
+    #[inline]
+    /// Returns an iterator over the elements as string slices.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::as_ref;
+    /// let words = [String::from("a"), String::from("b")];
+    /// let slice = as_ref::new::<str, _>(&words);
+    ///
+    /// assert!(slice.iter_str().eq(["a", "b"]));
+    /// ```
+    pub fn iter_str(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + FusedIterator + Clone + '_
+    {
+        self.iter().map(AsRef::<str>::as_ref)
+    }
+
+    #[must_use]
+    /// Returns the total length of the elements, in bytes.
+    ///
+    /// This is the length of the string from [`DynSlice::concat_to_string`], so it can be used
+    /// to reserve space before writing the elements somewhere else.
+    pub fn total_display_len_hint(&self) -> usize {
+        self.iter_str().map(str::len).sum()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    /// Returns the elements concatenated into a string.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::as_ref;
+    /// let parts: [&str; 3] = ["dyn", "-", "slice"];
+    /// let slice = as_ref::new::<str, _>(&parts);
+    ///
+    /// assert_eq!(slice.concat_to_string(), "dyn-slice");
+    /// ```
+    pub fn concat_to_string(&self) -> alloc::string::String {
+        let mut out = alloc::string::String::with_capacity(self.total_display_len_hint());
+        out.extend(self.iter_str());
+        out
+    }
+}
 declare_new_fns!(
     #[crate = crate]
-    pub as_mut<T> AsMut<T>
+    pub as_mut<T: ?Sized> AsMut<T>
 );
 
 declare_new_fns!(
@@ -379,8 +868,29 @@ declare_new_fns!(
 );
 declare_new_fns!(
     #[crate = crate]
+    ///
+    /// `DynSliceMut<dyn Write>` has [`DynSliceMut::write_str_all`].
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::write;
+    /// let mut array = [String::from("a"), String::from("b")];
+    /// let mut slice = write::new_mut(&mut array);
+    ///
+    /// slice.write_str_all("!").unwrap();
+    /// assert_eq!(array, ["a!", "b!"]);
+    /// ```
     pub write Write
 );
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + Write + ?Sized> DynSliceMut<'a, Dyn> {
+    /// Writes `s` into every element, stopping at the first error.
+    ///
+    /// # Errors
+    /// Returns the first error returned by an element.
+    pub fn write_str_all(&mut self, s: &str) -> fmt::Result {
+        self.iter_mut().try_for_each(|w| w.write_str(s))
+    }
+}
 
 declare_new_fns!(
     #[crate = crate]
@@ -458,6 +968,19 @@ declare_new_fns!(
 
 declare_new_fns!(
     #[crate = crate]
+    ///
+    /// `DynSliceMut<dyn AddAssign<Rhs>>` has [`DynSliceMut::add_assign_from`], and the other
+    /// `*Assign` modules have the matching `*_assign_from` methods.
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::add_assign;
+    /// let mut array = [1, 2, 3];
+    /// let mut slice = add_assign::new_mut(&mut array);
+    ///
+    /// slice.add_assign_from(&[10, 20, 30]);
+    /// assert_eq!(array, [11, 22, 33]);
+    /// ```
     pub add_assign<Rhs> AddAssign<Rhs>
 );
 declare_new_fns!(
@@ -474,11 +997,46 @@ declare_new_fns!(
 );
 declare_new_fns!(
     #[crate = crate]
-    pub deref<Target> Deref<Target = Target>
+    ///
+    /// `DynSlice(Mut)<dyn Deref<Target = Target>>` has [`DynSlice::iter_deref`] and [`DynSlice::get_deref`].
+    ///
+    /// # Example
+    /// ```
+    /// # use std::rc::Rc;
+    /// # use dyn_slice::standard::deref;
+    /// let array = [Rc::new(1), Rc::new(2)];
+    /// let slice = deref::new(&array);
+    ///
+    /// assert_eq!(slice.get_deref(1), Some(&2));
+    /// assert_eq!(slice.iter_deref().sum::<i32>(), 3);
+    /// ```
+    pub deref<Target: ?Sized> Deref<Target = Target>
 );
+impl<
+        'a,
+        Dyn: Pointee<Metadata = DynMetadata<Dyn>> + Deref<Target = Target> + ?Sized,
+        Target: ?Sized,
+    > DynSlice<'a, Dyn>
+{
+    #[inline]
+    /// Returns an iterator over the dereferenced elements.
+    pub fn iter_deref(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &Target> + ExactSizeIterator + FusedIterator + Clone + '_
+    {
+        self.iter().map(Deref::deref)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns a reference to the dereferenced element at the given `index` or `None` if the `index` is out of bounds.
+    pub fn get_deref(&self, index: usize) -> Option<&Target> {
+        self.get(index).map(Deref::deref)
+    }
+}
 declare_new_fns!(
     #[crate = crate]
-    pub deref_mut<Target> DerefMut<Target = Target>
+    pub deref_mut<Target: ?Sized> DerefMut<Target = Target>
 );
 declare_new_fns!(
     #[crate = crate]
@@ -513,6 +1071,44 @@ declare_new_fns!(
     pub sub_assign<Rhs> SubAssign<Rhs>
 );
 
+macro_rules! impl_assign_from {
+    ( $( $trait:ident :: $op:ident => $fn:ident, $verb:literal );* $(;)? ) => {
+        impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + ?Sized> DynSliceMut<'a, Dyn> {
+            $(
+                #[doc = concat!(
+                    "Applies [`", stringify!($trait), "::", stringify!($op), "`] to each element, ",
+                    "with the corresponding element of `rhs`, ", $verb, "."
+                )]
+                ///
+                /// # Panics
+                /// Panics if the slices have different lengths.
+                pub fn $fn<Rhs: Clone>(&mut self, rhs: &[Rhs])
+                where
+                    Dyn: $trait<Rhs>,
+                {
+                    assert!(self.len() == rhs.len(), "slices have different lengths");
+
+                    for (element, rhs) in self.iter_mut().zip(rhs) {
+                        element.$op(rhs.clone());
+                    }
+                }
+            )*
+        }
+    };
+}
+impl_assign_from!(
+    AddAssign::add_assign => add_assign_from, "like `a[i] += rhs[i]`";
+    BitAndAssign::bitand_assign => bit_and_assign_from, "like `a[i] &= rhs[i]`";
+    BitOrAssign::bitor_assign => bit_or_assign_from, "like `a[i] |= rhs[i]`";
+    BitXorAssign::bitxor_assign => bit_xor_assign_from, "like `a[i] ^= rhs[i]`";
+    DivAssign::div_assign => div_assign_from, "like `a[i] /= rhs[i]`";
+    MulAssign::mul_assign => mul_assign_from, "like `a[i] *= rhs[i]`";
+    RemAssign::rem_assign => rem_assign_from, "like `a[i] %= rhs[i]`";
+    ShlAssign::shl_assign => shl_assign_from, "like `a[i] <<= rhs[i]`";
+    ShrAssign::shr_assign => shr_assign_from, "like `a[i] >>= rhs[i]`";
+    SubAssign::sub_assign => sub_assign_from, "like `a[i] -= rhs[i]`";
+);
+
 /// A reference-to-value conversion.
 pub trait To<T> {
     /// Converts this reference into the (usually inferred) input type.
@@ -532,6 +1128,245 @@ declare_new_fns!(
     pub to<T> To<T>
 );
 
+/// A value-to-place assignment, the object safe counterpart of `*self = value.into()`.
+pub trait Assign<T> {
+    /// Assigns `value` to this place, converting it into the place's type.
+    fn assign(&mut self, value: T);
+}
+
+impl<T, S: From<T>> Assign<T> for S {
+    #[inline]
+    fn assign(&mut self, value: T) {
+        *self = value.into();
+    }
+}
+
+declare_new_fns!(
+    #[crate = crate]
+    ///
+    /// `DynSliceMut<dyn Assign<T>>` has [`DynSliceMut::assign_from_iter`].
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::assign;
+    /// let mut array: [u16; 4] = [1, 2, 4, 8];
+    /// let mut slice = assign::new_mut::<u8, _>(&mut array);
+    ///
+    /// assert_eq!(slice.assign_from_iter([10u8, 20]), 2);
+    /// assert_eq!(array, [10, 20, 4, 8]);
+    /// ```
+    pub assign<T> Assign<T>
+);
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + ?Sized> DynSliceMut<'a, Dyn> {
+    /// Assigns each value from `iter` to the elements of the slice, in order, returning the number of
+    /// elements that were assigned.
+    ///
+    /// This stops when either the slice or `iter` runs out.
+    pub fn assign_from_iter<T, I>(&mut self, iter: I) -> usize
+    where
+        Dyn: Assign<T>,
+        I: IntoIterator<Item = T>,
+    {
+        self.write_each(
+            iter.into_iter()
+                .map(|value| move |element: &mut Dyn| element.assign(value)),
+        )
+    }
+}
+
+/// An object safe alternative to [`Clone`].
+///
+/// This is implemented for all types that implement [`Clone`].
+pub trait DynClone: Any {
+    /// Returns `self` as [`Any`], to compare and downcast the underlying type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Clones `source` into `self`, if they are the same underlying type, dropping the old value of `self`.
+    ///
+    /// Returns `true` if `source` was cloned, or `false` if the types differ.
+    fn clone_from_any(&mut self, source: &dyn Any) -> bool;
+}
+
+impl<T: Clone + Any> DynClone for T {
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_from_any(&mut self, source: &dyn Any) -> bool {
+        source.downcast_ref::<Self>().map_or(false, |source| {
+            self.clone_from(source);
+            true
+        })
+    }
+}
+
+declare_new_fns!(
+    #[crate = crate]
+    ///
+    /// `DynSliceMut<dyn DynClone>` has [`DynSliceMut::clone_from_element`].
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::dyn_clone;
+    /// let mut array = [String::from("a"), String::from("b")];
+    /// let mut slice = dyn_clone::new_mut(&mut array);
+    ///
+    /// assert!(slice.clone_from_element(0, &String::from("c")));
+    /// assert!(!slice.clone_from_element(1, &5_u8));
+    /// assert_eq!(array, ["c", "b"]);
+    /// ```
+    pub dyn_clone DynClone
+);
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + DynClone + ?Sized> DynSliceMut<'a, Dyn> {
+    /// Clones `other` into the element at `index`, dropping the old element.
+    ///
+    /// Returns `false`, without changing the element, if `other` is not the same type as the
+    /// underlying slice.
+    ///
+    /// # Panics
+    /// Panics if `index >= self.len()`.
+    pub fn clone_from_element<Other: DynClone + ?Sized>(
+        &mut self,
+        index: usize,
+        other: &Other,
+    ) -> bool {
+        self[index].clone_from_any(other.as_any())
+    }
+}
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + DynClone + ?Sized> DynRingSlice<'a, Dyn> {
+    /// Clones the elements of the ring buffer, from the oldest to the newest, into the start of
+    /// `dest`, dropping the old elements of `dest`, and returns the number of elements that were
+    /// cloned.
+    ///
+    /// This stops when either the ring buffer or `dest` runs out, or when an element is not the
+    /// same type as the element of `dest` that it would replace.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::dyn_clone, DynRingSlice};
+    ///
+    /// let buffer = [String::from("c"), String::from("a"), String::from("b")];
+    /// let ring = DynRingSlice::from_buffer(dyn_clone::new(&buffer), 1, 3).unwrap();
+    ///
+    /// let mut contiguous = [String::new(), String::new(), String::new()];
+    /// assert_eq!(ring.make_contiguous_into(&mut dyn_clone::new_mut(&mut contiguous)), 3);
+    /// assert_eq!(contiguous, ["a", "b", "c"]);
+    /// ```
+    pub fn make_contiguous_into<Dest: Pointee<Metadata = DynMetadata<Dest>> + DynClone + ?Sized>(
+        &self,
+        dest: &mut DynSliceMut<Dest>,
+    ) -> usize {
+        let mut cloned = 0;
+        for (dest, element) in dest.iter_mut().zip(self.iter()) {
+            if !dest.clone_from_any(element.as_any()) {
+                break;
+            }
+            cloned += 1;
+        }
+        cloned
+    }
+}
+
+/// An object safe alternative to [`Default`].
+///
+/// This is implemented for all types that implement [`Default`].
+pub trait DynDefault {
+    /// Replaces `self` with the default value of its type, dropping the old value.
+    fn reset(&mut self);
+}
+
+impl<T: Default> DynDefault for T {
+    #[inline]
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+declare_new_fns!(
+    #[crate = crate]
+    ///
+    /// `DynSliceMut<dyn DynDefault>` has [`DynSliceMut::reset_all`].
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::dyn_default;
+    /// let mut array = [String::from("a"), String::from("b")];
+    /// let mut slice = dyn_default::new_mut(&mut array);
+    ///
+    /// slice.reset_all();
+    /// assert_eq!(array, ["", ""]);
+    /// ```
+    pub dyn_default DynDefault
+);
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + DynDefault + ?Sized> DynSliceMut<'a, Dyn> {
+    #[inline]
+    /// Replaces every element with the default value of the underlying type.
+    pub fn reset_all(&mut self) {
+        self.iter_mut().for_each(DynDefault::reset);
+    }
+}
+
+/// An object safe alternative to [`Hash`](hash::Hash).
+///
+/// This is implemented for all types that implement [`Hash`](hash::Hash).
+pub trait DynHash {
+    /// Feeds this value into `state`, like [`Hash::hash`](hash::Hash::hash).
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+}
+
+impl<T: hash::Hash> DynHash for T {
+    #[inline]
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        self.hash(&mut state);
+    }
+}
+
+declare_new_fns!(
+    #[crate = crate]
+    ///
+    /// `DynSlice<dyn DynHash>` has [`DynSlice::hash_elements_into`] and [`DynSlice::content_hash`].
+    ///
+    /// # Example
+    /// ```
+    /// # use dyn_slice::standard::dyn_hash;
+    /// use std::collections::hash_map::DefaultHasher;
+    ///
+    /// let strings = [String::from("a"), String::from("b")];
+    /// let a = dyn_hash::new(&["a", "b"]);
+    /// let b = dyn_hash::new(&strings);
+    ///
+    /// assert_eq!(
+    ///     a.content_hash::<DefaultHasher>(),
+    ///     b.content_hash::<DefaultHasher>()
+    /// );
+    /// ```
+    pub dyn_hash DynHash
+);
+impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + DynHash + ?Sized> DynSlice<'a, Dyn> {
+    /// Feeds the length of the slice, followed by each element, into `state`.
+    ///
+    /// This writes the same values as hashing the equivalent `[T]`, apart from for types that hash
+    /// whole slices at once, such as integers, so the result only depends on the contents of the
+    /// slice.
+    pub fn hash_elements_into<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for element in self {
+            element.dyn_hash(state);
+        }
+    }
+
+    #[must_use]
+    /// Returns the hash of the slice's contents, using a new `H`.
+    ///
+    /// See [`DynSlice::hash_elements_into`] for details.
+    pub fn content_hash<H: Hasher + Default>(&self) -> u64 {
+        let mut state = H::default();
+        self.hash_elements_into(&mut state);
+        state.finish()
+    }
+}
+
 #[cfg(feature = "alloc")]
 mod standard_alloc {
     extern crate alloc;
@@ -551,13 +1386,14 @@ pub use standard_alloc::*;
 
 #[cfg(feature = "std")]
 mod standard_std {
+    use core::ptr::{DynMetadata, Pointee};
     use std::{
         error::Error,
-        io::{BufRead, IsTerminal, Read, Seek, Write},
-        net::ToSocketAddrs,
+        io::{self, BufRead, IoSlice, IsTerminal, Read, Seek, Write},
+        net::{SocketAddr, ToSocketAddrs},
     };
 
-    use crate::declare_new_fns;
+    use crate::{declare_new_fns, DynSlice, DynSliceMut};
 
     declare_new_fns!(
         #[crate = crate]
@@ -594,20 +1430,293 @@ mod standard_std {
         #[crate = crate]
         #[cfg_attr(doc, doc(cfg(feature = "std")))]
         #[doc = feature_availability!("std")]
+        ///
+        /// `DynSliceMut<dyn Write>` has [`DynSliceMut::write_vectored_each`].
         pub io_write Write
     );
+    impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + Write + ?Sized> DynSliceMut<'a, Dyn> {
+        #[cfg_attr(doc, doc(cfg(feature = "std")))]
+        /// Writes `bufs` to each element with [`Write::write_vectored`], returning the number of
+        /// bytes written to each element.
+        ///
+        /// Like [`Write::write_vectored`], each element may only write part of the buffers.
+        ///
+        /// # Errors
+        /// Returns the first error returned by an element. The elements before it have already
+        /// been written to.
+        ///
+        /// # Example
+        /// ```
+        /// use std::io::IoSlice;
+        ///
+        /// use dyn_slice::standard::io_write;
+        ///
+        /// let mut outputs = [Vec::new(), Vec::new()];
+        /// let mut slice = io_write::new_mut(&mut outputs);
+        ///
+        /// let written = slice
+        ///     .write_vectored_each(&[IoSlice::new(b"ab"), IoSlice::new(b"c")])
+        ///     .unwrap();
+        /// assert_eq!(written, [3, 3]);
+        /// assert_eq!(outputs, [b"abc", b"abc"]);
+        /// ```
+        pub fn write_vectored_each(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<Vec<usize>> {
+            self.iter_mut()
+                .map(|element| element.write_vectored(bufs))
+                .collect()
+        }
+    }
+
+    impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + AsRef<[u8]> + ?Sized> DynSlice<'a, Dyn> {
+        #[must_use]
+        #[cfg_attr(doc, doc(cfg(feature = "std")))]
+        /// Returns an [`IoSlice`] of the bytes of each element, for use with
+        /// [`Write::write_vectored`].
+        ///
+        /// # Example
+        /// ```
+        /// use std::io::Write;
+        ///
+        /// use dyn_slice::standard::as_ref;
+        ///
+        /// let buffers = [vec![1_u8, 2], vec![3]];
+        /// let slice = as_ref::new::<[u8], _>(&buffers);
+        ///
+        /// let mut output = Vec::new();
+        /// output.write_vectored(&slice.to_io_slices()).unwrap();
+        /// assert_eq!(output, [1, 2, 3]);
+        /// ```
+        pub fn to_io_slices(&self) -> Vec<IoSlice<'_>> {
+            self.iter()
+                .map(|element| IoSlice::new(element.as_ref()))
+                .collect()
+        }
+    }
 
     declare_new_fns!(
         #[crate = crate]
         #[cfg_attr(doc, doc(cfg(feature = "std")))]
         #[doc = feature_availability!("std")]
+        ///
+        /// `DynSlice(Mut)<dyn ToSocketAddrs<Iter = Iter>>` has [`DynSlice::resolve_all`].
+        ///
+        /// # Example
+        /// ```
+        /// # use std::net::SocketAddr;
+        /// # use dyn_slice::standard::to_socket_addrs;
+        /// let array = ["127.0.0.1:80", "[::1]:443"];
+        /// let slice = to_socket_addrs::new(&array);
+        ///
+        /// let addrs = slice.resolve_all().unwrap();
+        /// assert_eq!(
+        ///     addrs,
+        ///     [
+        ///         "127.0.0.1:80".parse::<SocketAddr>().unwrap(),
+        ///         "[::1]:443".parse().unwrap(),
+        ///     ],
+        /// );
+        /// ```
         pub to_socket_addrs<Iter: core::iter::Iterator<Item = std::net::SocketAddr>>
             ToSocketAddrs<Iter = Iter>
     );
+    impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + ToSocketAddrs + ?Sized> DynSlice<'a, Dyn> {
+        #[cfg_attr(doc, doc(cfg(feature = "std")))]
+        /// Resolves every element to socket addresses, returning all of them in order.
+        ///
+        /// # Errors
+        /// Returns the first error encountered while resolving an element.
+        pub fn resolve_all(&self) -> io::Result<Vec<SocketAddr>> {
+            let mut addrs = Vec::new();
+            for element in self {
+                addrs.extend(element.to_socket_addrs()?);
+            }
+
+            Ok(addrs)
+        }
+    }
 }
 #[cfg(feature = "std")]
 pub use standard_std::*;
 
+#[cfg(feature = "futures-core")]
+mod standard_futures_core {
+    use futures_core::Stream;
+
+    use crate::declare_new_fns;
+
+    declare_new_fns!(
+        #[crate = crate]
+        #[cfg_attr(doc, doc(cfg(feature = "futures-core")))]
+        #[doc = feature_availability!("futures-core")]
+        ///
+        /// `DynSliceMut<dyn Stream<Item = Item> + Unpin>` can be polled as one stream with
+        /// [`DynSliceMut::select_all`](crate::DynSliceMut::select_all).
+        pub stream<Item> Stream<Item = Item> + Unpin
+    );
+}
+#[cfg(feature = "futures-core")]
+pub use standard_futures_core::*;
+
+#[cfg(feature = "futures-io")]
+mod standard_futures_io {
+    use core::{
+        pin::Pin,
+        ptr::{DynMetadata, Pointee},
+        task::{Context, Poll},
+    };
+    use std::io;
+
+    use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite};
+
+    use crate::{declare_new_fns, DynSliceMut};
+
+    declare_new_fns!(
+        #[crate = crate]
+        #[cfg_attr(doc, doc(cfg(feature = "futures-io")))]
+        #[doc = feature_availability!("futures-io")]
+        ///
+        /// `DynSliceMut<dyn AsyncRead + Unpin>` has [`DynSliceMut::poll_read_each`].
+        pub async_read AsyncRead + Unpin
+    );
+    declare_new_fns!(
+        #[crate = crate]
+        #[cfg_attr(doc, doc(cfg(feature = "futures-io")))]
+        #[doc = feature_availability!("futures-io")]
+        pub async_buf_read AsyncBufRead + Unpin
+    );
+    declare_new_fns!(
+        #[crate = crate]
+        #[cfg_attr(doc, doc(cfg(feature = "futures-io")))]
+        #[doc = feature_availability!("futures-io")]
+        pub async_write AsyncWrite + Unpin
+    );
+
+    impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + AsyncRead + Unpin + ?Sized>
+        DynSliceMut<'a, Dyn>
+    {
+        #[cfg_attr(doc, doc(cfg(feature = "futures-io")))]
+        /// Polls each reader once to read into the buffer at the same index, returning the result
+        /// of each poll.
+        ///
+        /// # Panics
+        /// Panics if `bufs` does not have the same length as the slice.
+        ///
+        /// # Example
+        /// ```
+        /// # use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+        /// # const VTABLE: RawWakerVTable =
+        /// #     RawWakerVTable::new(|_| RawWaker::new(std::ptr::null(), &VTABLE), |_| {}, |_| {}, |_| {});
+        /// # // SAFETY: the waker functions do nothing
+        /// # let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+        /// use dyn_slice::standard::async_read;
+        ///
+        /// let mut readers: [&[u8]; 2] = [b"abc", b"de"];
+        /// let mut slice = async_read::new_mut(&mut readers);
+        ///
+        /// let (mut a, mut b) = ([0; 4], [0; 4]);
+        /// let mut cx = Context::from_waker(&waker);
+        /// let results = slice.poll_read_each(&mut cx, &mut [&mut a, &mut b]);
+        ///
+        /// assert!(matches!(results[..], [Poll::Ready(Ok(3)), Poll::Ready(Ok(2))]));
+        /// assert_eq!(&a[..3], b"abc");
+        /// assert_eq!(&b[..2], b"de");
+        /// ```
+        pub fn poll_read_each(
+            &mut self,
+            cx: &mut Context<'_>,
+            bufs: &mut [&mut [u8]],
+        ) -> Vec<Poll<io::Result<usize>>> {
+            assert!(
+                self.len() == bufs.len(),
+                "the number of buffers does not match the number of readers"
+            );
+
+            self.iter_mut()
+                .zip(bufs)
+                .map(|(reader, buf)| Pin::new(reader).poll_read(cx, buf))
+                .collect()
+        }
+    }
+}
+#[cfg(feature = "futures-io")]
+pub use standard_futures_io::*;
+
+#[cfg(feature = "defmt")]
+mod standard_defmt {
+    use core::ptr::{DynMetadata, Pointee};
+
+    use crate::{declare_new_fns, DynSlice, DynSliceMut};
+
+    #[cfg_attr(doc, doc(cfg(feature = "defmt")))]
+    /// An object safe alternative to [`defmt::Format`].
+    ///
+    /// This is implemented for all types that implement [`defmt::Format`].
+    pub trait DynFormat {
+        /// Writes the defmt representation of `self` to `fmt`.
+        fn dyn_format(&self, fmt: defmt::Formatter);
+    }
+
+    impl<T: defmt::Format> DynFormat for T {
+        #[inline]
+        fn dyn_format(&self, fmt: defmt::Formatter) {
+            self.format(fmt);
+        }
+    }
+
+    declare_new_fns!(
+        #[crate = crate]
+        #[cfg_attr(doc, doc(cfg(feature = "defmt")))]
+        #[doc = feature_availability!("defmt")]
+        ///
+        /// `DynSlice(Mut)<dyn DynFormat>` implements [`defmt::Format`], formatting the elements
+        /// like a slice.
+        ///
+        /// # Example
+        /// ```
+        /// # #[defmt::global_logger]
+        /// # struct Logger;
+        /// # unsafe impl defmt::Logger for Logger {
+        /// #     fn acquire() {}
+        /// #     unsafe fn flush() {}
+        /// #     unsafe fn release() {}
+        /// #     unsafe fn write(_bytes: &[u8]) {}
+        /// # }
+        /// # use dyn_slice::standard::defmt_format;
+        /// let array = [1_u8, 2, 3];
+        /// let slice = defmt_format::new(&array);
+        ///
+        /// defmt::info!("{}", slice); // [1, 2, 3]
+        /// ```
+        pub defmt_format DynFormat
+    );
+
+    impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + DynFormat + ?Sized> defmt::Format
+        for DynSlice<'a, Dyn>
+    {
+        fn format(&self, fmt: defmt::Formatter) {
+            defmt::write!(fmt, "[");
+            for (i, element) in self.iter().enumerate() {
+                if i != 0 {
+                    defmt::write!(fmt, ", ");
+                }
+                element.dyn_format(fmt);
+            }
+            defmt::write!(fmt, "]");
+        }
+    }
+
+    impl<'a, Dyn: Pointee<Metadata = DynMetadata<Dyn>> + DynFormat + ?Sized> defmt::Format
+        for DynSliceMut<'a, Dyn>
+    {
+        #[inline]
+        fn format(&self, fmt: defmt::Formatter) {
+            self.0.format(fmt);
+        }
+    }
+}
+#[cfg(feature = "defmt")]
+pub use standard_defmt::*;
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -644,6 +1753,89 @@ mod test {
         assert_eq!(slice.downcast::<u8>(), Some(&[][..]));
     }
 
+    #[test]
+    fn test_downcast_empty_untyped() {
+        // Empty slices without metadata have a dangling data pointer that is only aligned for
+        // `u8`, so downcasting them to a type with a greater alignment must not use it
+
+        let slice = DynSlice::<dyn Any>::default();
+        assert_eq!(slice.downcast::<u64>(), Some(&[][..]));
+        assert_eq!(slice.to_vec_of::<u64>(), Some(Vec::new()));
+        assert_eq!(slice.into_iter_downcast::<u64>().unwrap().count(), 0);
+
+        let slice = any::empty_untyped();
+        assert_eq!(slice.downcast::<u64>(), Some(&[][..]));
+
+        let mut slice = DynSliceMut::<dyn Any>::default();
+        assert_eq!(slice.downcast_mut::<u64>(), Some(&mut [][..]));
+    }
+
+    declare_new_fns!(
+        #[crate = crate]
+        any_unwind_safe Any + Send + UnwindSafe + 'static
+    );
+    declare_new_fns!(
+        #[crate = crate]
+        any_sync Any + Sync
+    );
+
+    #[test]
+    fn test_any_like() {
+        let mut array = [1_u16, 2];
+        let mut slice = any_unwind_safe::new_mut(&mut array);
+
+        assert!(slice.is::<u16>());
+        assert!(!slice.is::<u32>());
+        assert!(slice.downcast::<u8>().is_none());
+        slice.downcast_mut::<u16>().unwrap()[0] = 3;
+        assert_eq!(array, [3, 2]);
+
+        let array = [1_u16, 2];
+        let slice = any_sync::new(&array);
+        assert!(slice.is::<u16>());
+        assert!(!slice.is::<u8>());
+    }
+
+    #[test]
+    fn test_downcast_to_trait() {
+        let mut array = [1_u8, 2];
+        let mut slice = any::new_mut(&mut array);
+
+        let mut add_slice = slice
+            .downcast_to_trait_mut::<u8, add_assign::Dyn<u8>>()
+            .unwrap();
+        add_slice.iter_mut().for_each(|x| *x += 1);
+        assert!(slice
+            .downcast_to_trait_mut::<u16, add_assign::Dyn<u16>>()
+            .is_none());
+
+        let debug_slice = slice.downcast_to_trait::<u8, debug::Dyn>().unwrap();
+        assert_eq!(format!("{debug_slice:?}"), "[2, 3]");
+        assert!(slice.downcast_to_trait::<u16, debug::Dyn>().is_none());
+
+        let array: [u8; 0] = [];
+        let slice = any::new(&array);
+        let debug_slice = slice.downcast_to_trait::<u8, debug::Dyn>().unwrap();
+        assert_eq!(format!("{debug_slice:?}"), "[]");
+    }
+
+    #[test]
+    fn test_typed_extraction() {
+        let array = [String::from("a"), String::from("bc")];
+        let iter = {
+            let slice = any::new(&array);
+            assert_eq!(slice.to_vec_of::<String>().unwrap(), array);
+            assert!(slice.to_vec_of::<&str>().is_none());
+            assert!(slice.into_iter_downcast::<u8>().is_none());
+            slice.into_iter_downcast::<String>().unwrap()
+        };
+        assert_eq!(iter.map(String::len).sum::<usize>(), 3);
+
+        let slice = any::new::<u8>(&[]);
+        assert_eq!(slice.to_vec_of::<u16>(), Some(Vec::new()));
+        assert_eq!(slice.into_iter_downcast::<u16>().unwrap().count(), 0);
+    }
+
     #[test]
     fn test_borrow() {
         let a: Box<u8> = Box::new(5);
@@ -693,6 +1885,27 @@ mod test {
         assert!(slice != ne7);
     }
 
+    #[test]
+    fn test_partial_eq_array_vec() {
+        let mut array: [u8; 3] = [10, 11, 12];
+        let slice = partial_eq::new::<u8, _>(&array);
+
+        let (equal, not_equal) = (&[10, 11, 12], &[10, 11, 13]);
+        assert!(slice == [10, 11, 12]);
+        assert!(slice == equal);
+        assert!(slice != [10, 11]);
+        assert!(slice != not_equal);
+        assert!(slice == vec![10, 11, 12]);
+        assert!(slice != vec![10]);
+        assert!(slice != Vec::new());
+
+        let slice = partial_eq::new_mut::<u8, _>(&mut array);
+        assert!(slice == [10, 11, 12]);
+        assert!(slice == equal);
+        assert!(slice != [10, 11, 12, 13]);
+        assert!(slice == vec![10, 11, 12]);
+    }
+
     #[test]
     fn test_partial_ord() {
         let array: [u8; 2] = [5, 7];
@@ -761,6 +1974,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_as_ref_str() {
+        let array = [String::from("ab"), String::new(), String::from("cde")];
+        let slice: StrSlice = as_ref::new(&array);
+
+        assert!(slice.iter_str().eq(["ab", "", "cde"]));
+        assert!(slice.iter_str().rev().eq(["cde", "", "ab"]));
+        assert_eq!(slice.total_display_len_hint(), 5);
+        assert_eq!(slice.concat_to_string(), "abcde");
+
+        let empty = as_ref::new::<str, String>(&[]);
+        assert_eq!(empty.total_display_len_hint(), 0);
+        assert!(empty.concat_to_string().is_empty());
+    }
+
     #[test]
     fn test_debug() {
         #[derive(Debug)]
@@ -865,6 +2093,294 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_assign() {
+        let mut array: [u32; 3] = [1, 2, 3];
+        let mut slice = assign::new_mut::<u16, _>(&mut array);
+
+        slice[1].assign(20);
+        assert_eq!(array, [1, 20, 3]);
+
+        let mut slice = assign::new_mut::<u16, _>(&mut array);
+        assert_eq!(slice.assign_from_iter([7, 8, 9, 10]), 3);
+        assert_eq!(array, [7, 8, 9]);
+
+        let mut slice = assign::new_mut::<u16, _>(&mut array);
+        assert_eq!(slice.assign_from_iter([]), 0);
+        assert_eq!(array, [7, 8, 9]);
+    }
+
+    #[test]
+    fn test_write_str_all() {
+        let mut array = [String::new(), String::from("x")];
+        let mut slice = write::new_mut(&mut array);
+
+        slice.write_str_all("ab").unwrap();
+        slice.write_str_all("c").unwrap();
+        assert_eq!(array, ["abc", "xabc"]);
+
+        let mut array: [String; 0] = [];
+        assert!(write::new_mut(&mut array).write_str_all("a").is_ok());
+    }
+
+    #[test]
+    fn test_deref() {
+        let array: [Box<str>; 2] = ["a".into(), "b".into()];
+        let slice = deref::new(&array);
+
+        assert_eq!(slice.get_deref(0), Some("a"));
+        assert_eq!(slice.get_deref(1), Some("b"));
+        assert_eq!(slice.get_deref(2), None);
+
+        let iter = slice.iter_deref();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.clone().rev().collect::<String>(), "ba");
+        assert_eq!(iter.collect::<String>(), "ab");
+    }
+
+    #[test]
+    fn test_strip_prefix_suffix() {
+        let array: [u8; 4] = [1, 2, 3, 4];
+        let slice = partial_eq::new(&array);
+
+        assert!(slice.strip_prefix(&[]).unwrap() == array[..]);
+        assert!(slice.strip_prefix(&[1]).unwrap() == [2, 3, 4][..]);
+        assert!(slice.strip_prefix(&array).unwrap().is_empty());
+        assert!(slice.strip_prefix(&[1, 2, 3, 4, 5]).is_none());
+        assert!(slice.strip_prefix(&[1, 3]).is_none());
+
+        assert!(slice.strip_suffix(&[]).unwrap() == array[..]);
+        assert!(slice.strip_suffix(&[3, 4]).unwrap() == [1, 2][..]);
+        assert!(slice.strip_suffix(&array).unwrap().is_empty());
+        assert!(slice.strip_suffix(&[0, 1, 2, 3, 4]).is_none());
+        assert!(slice.strip_suffix(&[2, 4]).is_none());
+
+        let empty = partial_eq::new::<u8, u8>(&[]);
+        assert!(empty.strip_prefix::<u8>(&[]).unwrap().is_empty());
+        assert!(empty.strip_suffix(&[1]).is_none());
+    }
+
+    #[test]
+    fn test_dyn_default() {
+        use alloc::rc::Rc;
+
+        let a = Rc::new(1);
+        let mut array = [Some(Rc::clone(&a)), None, Some(Rc::clone(&a))];
+        let mut slice = dyn_default::new_mut(&mut array);
+
+        slice.reset_all();
+        assert_eq!(array, [None, None, None]);
+        assert_eq!(Rc::strong_count(&a), 1);
+
+        let mut array = [1_u8, 2, 3];
+        let mut slice = dyn_default::new_mut(&mut array);
+        slice[1].reset();
+        assert_eq!(array, [1, 0, 3]);
+    }
+
+    #[test]
+    fn test_bytes_eq_slice() {
+        #[derive(Debug)]
+        struct Wrapper(u32);
+        impl PartialEq<u32> for Wrapper {
+            fn eq(&self, other: &u32) -> bool {
+                self.0 == *other
+            }
+        }
+
+        // SAFETY:
+        // Every slice below is either built from the type it is compared against, or from a type
+        // with a different vtable.
+        unsafe {
+            let values = [1_u32, 2, 3, 0x0100_0000];
+            let slice = partial_eq::new(&values);
+            assert!(slice.bytes_eq_slice(&values));
+            assert!(!slice.bytes_eq_slice(&[1, 2, 3, 1]));
+            assert!(!slice.bytes_eq_slice(&values[..3]));
+            assert!(slice.slice(..0).unwrap().bytes_eq_slice::<u32>(&[]));
+
+            // A different underlying type falls back to comparing elements
+            let wrappers = [Wrapper(1), Wrapper(2), Wrapper(3), Wrapper(0x0100_0000)];
+            let slice = partial_eq::new::<u32, _>(&wrappers);
+            assert!(slice.bytes_eq_slice(&values));
+            assert!(!slice.bytes_eq_slice(&[1, 2, 3, 1]));
+
+            let arrays = [[1_u8, 2], [3, 4]];
+            assert!(partial_eq::new(&arrays).bytes_eq_slice(&arrays));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_vectored_io() {
+        use std::io::{self, Cursor, Write};
+
+        /// A writer that only accepts one byte at a time
+        struct OneByte(Vec<u8>);
+        impl Write for OneByte {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.extend(buf.first());
+                Ok(buf.len().min(1))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffers = [&b"ab"[..], b"", b"cd"];
+        let slice = as_ref::new::<[u8], _>(&buffers);
+        let io_slices = slice.to_io_slices();
+        assert_eq!(io_slices.len(), 3);
+        assert_eq!(&*io_slices[2], b"cd");
+
+        let mut writers = [OneByte(Vec::new()), OneByte(Vec::new())];
+        let mut slice = io_write::new_mut(&mut writers);
+        assert_eq!(slice.write_vectored_each(&io_slices).unwrap(), [1, 1]);
+        assert!(writers.iter().all(|writer| writer.0 == b"a"));
+
+        // The second cursor only has space for one byte
+        let mut writers = [Cursor::new([0_u8; 4]), Cursor::new([0; 4])];
+        writers[1].set_position(3);
+        let mut slice = io_write::new_mut(&mut writers);
+        assert_eq!(slice.write_vectored_each(&io_slices).unwrap(), [4, 1]);
+        assert_eq!(writers[0].get_ref(), b"abcd");
+    }
+
+    #[test]
+    fn test_dyn_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let array = [String::from("a"), String::new(), String::from("bc")];
+        let slice = dyn_hash::new(&array);
+
+        let mut reference = DefaultHasher::new();
+        hash::Hash::hash(&array[..], &mut reference);
+        let mut state = DefaultHasher::new();
+        slice.hash_elements_into(&mut state);
+        assert_eq!(state.finish(), reference.finish());
+        assert_eq!(slice.content_hash::<DefaultHasher>(), reference.finish());
+
+        // The length prefix keeps the boundaries between elements
+        let moved = [String::from("a"), String::from("bc"), String::new()];
+        assert_ne!(
+            dyn_hash::new(&moved).content_hash::<DefaultHasher>(),
+            reference.finish()
+        );
+
+        let empty: [u8; 0] = [];
+        assert_ne!(
+            dyn_hash::new(&empty).content_hash::<DefaultHasher>(),
+            dyn_hash::new(&[0_u8]).content_hash::<DefaultHasher>()
+        );
+    }
+
+    #[test]
+    fn test_dyn_clone() {
+        use alloc::rc::Rc;
+
+        let a = Rc::new(1);
+        let b = Rc::new(2);
+
+        let mut array = [Rc::clone(&a), Rc::clone(&a)];
+        let mut slice = dyn_clone::new_mut(&mut array);
+
+        assert!(slice.clone_from_element(1, &b));
+        assert_eq!(Rc::strong_count(&a), 2);
+        assert_eq!(Rc::strong_count(&b), 2);
+
+        assert!(!slice.clone_from_element(0, &2));
+        assert!(!slice.clone_from_element(0, &Rc::new(2_u8)));
+        assert_eq!(Rc::strong_count(&a), 2);
+
+        let other = dyn_clone::new(core::slice::from_ref(&b));
+        assert!(slice.clone_from_element(0, &other[0]));
+        assert_eq!(Rc::strong_count(&a), 1);
+        assert_eq!(Rc::strong_count(&b), 3);
+
+        assert_eq!(array, [Rc::clone(&b), Rc::clone(&b)]);
+    }
+
+    #[test]
+    fn test_make_contiguous_into() {
+        let buffer = [String::from("c"), String::from("a"), String::from("b")];
+        let ring = DynRingSlice::from_buffer(dyn_clone::new(&buffer), 1, 3).unwrap();
+
+        let mut short = [String::new(), String::new()];
+        assert_eq!(
+            ring.make_contiguous_into(&mut dyn_clone::new_mut(&mut short)),
+            2
+        );
+        assert_eq!(short, ["a", "b"]);
+
+        let mut long = [String::new(), String::new(), String::new(), String::new()];
+        assert_eq!(
+            ring.make_contiguous_into(&mut dyn_clone::new_mut(&mut long)),
+            3
+        );
+        assert_eq!(long, ["a", "b", "c", ""]);
+
+        // A destination of a different type is left unchanged
+        let mut numbers = [0_u8];
+        assert_eq!(
+            ring.make_contiguous_into(&mut dyn_clone::new_mut(&mut numbers)),
+            0
+        );
+        assert_eq!(numbers, [0]);
+    }
+
+    #[test]
+    fn test_replace_raw() {
+        use alloc::rc::Rc;
+
+        let a = Rc::new(1);
+        let b = Rc::new(2);
+
+        let mut array = [Rc::clone(&a), Rc::clone(&a)];
+        let mut slice = debug::new_mut(&mut array);
+
+        let c = Rc::clone(&b);
+        // SAFETY:
+        // The underlying type is `Rc<i32>`, and writing cannot unwind.
+        unsafe { slice.replace_raw(0, |ptr| ptr.cast::<Rc<i32>>().write(c)) };
+        assert_eq!(Rc::strong_count(&a), 2);
+        assert_eq!(Rc::strong_count(&b), 2);
+        assert_eq!(array, [Rc::clone(&b), Rc::clone(&a)]);
+    }
+
+    #[test]
+    fn test_assign_from() {
+        let mut array = [10_u32, 20, 30];
+
+        add_assign::new_mut(&mut array).add_assign_from(&[1, 2, 3]);
+        assert_eq!(array, [11, 22, 33]);
+        sub_assign::new_mut(&mut array).sub_assign_from(&[1, 2, 3]);
+        assert_eq!(array, [10, 20, 30]);
+        mul_assign::new_mut(&mut array).mul_assign_from(&[2, 3, 4]);
+        assert_eq!(array, [20, 60, 120]);
+        div_assign::new_mut(&mut array).div_assign_from(&[2, 3, 4]);
+        assert_eq!(array, [10, 20, 30]);
+        rem_assign::new_mut(&mut array).rem_assign_from(&[3, 7, 30]);
+        assert_eq!(array, [1, 6, 0]);
+        shl_assign::new_mut(&mut array).shl_assign_from(&[1_u8, 2, 3]);
+        assert_eq!(array, [2, 24, 0]);
+        shr_assign::new_mut(&mut array).shr_assign_from(&[1_u8, 3, 0]);
+        assert_eq!(array, [1, 3, 0]);
+        bit_or_assign::new_mut(&mut array).bit_or_assign_from(&[2, 4, 8]);
+        assert_eq!(array, [3, 7, 8]);
+        bit_and_assign::new_mut(&mut array).bit_and_assign_from(&[1, 6, 12]);
+        assert_eq!(array, [1, 6, 8]);
+        bit_xor_assign::new_mut(&mut array).bit_xor_assign_from(&[1, 1, 1]);
+        assert_eq!(array, [0, 7, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "slices have different lengths")]
+    fn test_assign_from_different_lengths() {
+        let mut array = [1_u8, 2];
+        add_assign::new_mut::<u8, _>(&mut array).add_assign_from(&[1]);
+    }
+
     #[test]
     fn test_to_string() {
         struct A;
@@ -884,6 +2400,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_resolve_all() {
+        use std::net::{Ipv4Addr, SocketAddr};
+
+        let array: [(Ipv4Addr, u16); 2] =
+            [(Ipv4Addr::LOCALHOST, 80), (Ipv4Addr::UNSPECIFIED, 8080)];
+        let slice = to_socket_addrs::new(&array);
+
+        assert_eq!(
+            slice.resolve_all().expect("failed to resolve addresses"),
+            array.map(SocketAddr::from),
+        );
+
+        let slice = to_socket_addrs::new::<core::option::IntoIter<SocketAddr>, SocketAddr>(&[]);
+        assert!(slice
+            .resolve_all()
+            .expect("failed to resolve addresses")
+            .is_empty());
+
+        let array = ["127.0.0.1:80", "not an address"];
+        let slice = to_socket_addrs::new(&array);
+        assert!(slice.resolve_all().is_err());
+    }
+
     #[test]
     fn test_error() {
         #[derive(Debug)]
@@ -906,4 +2446,43 @@ mod test {
 
         assert_eq!(format!("{slice:?}"), format!("{array:?}"));
     }
+
+    /// A defmt logger that discards everything, as linking defmt requires a global logger
+    #[cfg(feature = "defmt")]
+    #[defmt::global_logger]
+    struct NullLogger;
+    #[cfg(feature = "defmt")]
+    // SAFETY:
+    // The logger does nothing, so there is no state to protect.
+    unsafe impl defmt::Logger for NullLogger {
+        fn acquire() {}
+        unsafe fn flush() {}
+        unsafe fn release() {}
+        unsafe fn write(_bytes: &[u8]) {}
+    }
+
+    #[test]
+    #[cfg(feature = "defmt")]
+    fn test_defmt_format() {
+        const fn is_format<T: defmt::Format + ?Sized>() {}
+        is_format::<crate::DynSlice<defmt_format::Dyn>>();
+        is_format::<crate::DynSliceMut<defmt_format::Dyn>>();
+
+        let array = [1_u8, 2, 3];
+        let slice = defmt_format::new(&array);
+        defmt::info!("{}", slice);
+    }
+
+    #[test]
+    #[cfg(feature = "futures-io")]
+    #[should_panic(expected = "the number of buffers does not match the number of readers")]
+    fn test_poll_read_each_mismatched() {
+        use core::task::Context;
+
+        let waker = crate::test::noop_waker();
+
+        let mut readers: [&[u8]; 2] = [b"a", b"b"];
+        let mut slice = async_read::new_mut(&mut readers);
+        let _ = slice.poll_read_each(&mut Context::from_waker(&waker), &mut [&mut [0; 1]]);
+    }
 }
@@ -0,0 +1,62 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(doc, doc(cfg(feature = "stats")))]
+/// Allocation statistics for an owned container of dyn elements, like [`DynArrayBuf`] or
+/// `DynPool`.
+///
+/// Containers only record statistics with the `stats` feature, and return them from their
+/// `stats` method. An element is in use if it is in a [`DynArrayBuf`], or checked out of a pool.
+///
+/// [`DynArrayBuf`]: crate::DynArrayBuf
+pub struct Stats {
+    /// The number of elements that have been allocated, by pushing them into a buffer or
+    /// acquiring them from a pool.
+    pub allocations: usize,
+    /// The number of elements in use.
+    pub len: usize,
+    /// The largest number of elements that have been in use at once.
+    pub high_water_len: usize,
+    /// The number of bytes used by the elements in use.
+    pub bytes: usize,
+    /// The largest number of bytes that have been used by elements at once.
+    pub high_water_bytes: usize,
+    /// The number of bytes of storage that the container has.
+    pub capacity_bytes: usize,
+}
+
+impl Stats {
+    #[must_use]
+    /// Create statistics with nothing recorded.
+    pub(crate) const fn new() -> Self {
+        Self {
+            allocations: 0,
+            len: 0,
+            high_water_len: 0,
+            bytes: 0,
+            high_water_bytes: 0,
+            capacity_bytes: 0,
+        }
+    }
+
+    /// Records `count` allocations, after which `len` elements of size `element_size` are in use.
+    pub(crate) fn record_allocations(&mut self, count: usize, len: usize, element_size: usize) {
+        self.allocations += count;
+        self.high_water_len = self.high_water_len.max(len);
+        self.high_water_bytes = self.high_water_bytes.max(len * element_size);
+    }
+
+    #[must_use]
+    /// Returns the statistics with the current usage filled in.
+    pub(crate) const fn with_usage(
+        self,
+        len: usize,
+        element_size: usize,
+        capacity_bytes: usize,
+    ) -> Self {
+        Self {
+            len,
+            bytes: len * element_size,
+            capacity_bytes,
+            ..self
+        }
+    }
+}
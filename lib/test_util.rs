@@ -0,0 +1,283 @@
+use core::{fmt::Debug, ops::Range};
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::vec,
+    prop_oneof,
+    strategy::{Just, Strategy},
+};
+
+use crate::{declare_new_fns, DynSlice, Iter};
+
+/// The trait that the generated dyn slices are over.
+///
+/// Each element can be converted to a `u64`, so that elements of different underlying types can
+/// be compared with the oracle slice.
+pub trait TestElement: Debug {
+    /// Returns the element as a `u64`.
+    fn value(&self) -> u64;
+}
+
+macro_rules! impl_test_element {
+    ( $( $ty:ty => |$x:ident| $value:expr ),* $(,)? ) => {
+        $(
+            impl TestElement for $ty {
+                #[inline]
+                // Larger values are truncated
+                #[allow(clippy::cast_possible_truncation)]
+                fn value(&self) -> u64 {
+                    let $x = self;
+                    $value
+                }
+            }
+        )*
+    };
+}
+
+impl_test_element!(
+    () => |_x| 0,
+    u8 => |x| u64::from(*x),
+    u16 => |x| u64::from(*x),
+    u32 => |x| u64::from(*x),
+    u64 => |x| *x,
+    [u8; 3] => |x| u64::from(x[0]) | u64::from(x[1]) << 8 | u64::from(x[2]) << 16,
+    u128 => |x| *x as u64,
+);
+
+declare_new_fns!(
+    #[crate = crate]
+    pub test_element TestElement
+);
+
+/// A generated dyn slice, with a sub-slice and an iterator state, and the oracle slice that it
+/// should behave like.
+///
+/// This is generated by [`slice_case`].
+#[derive(Debug, Clone)]
+pub struct SliceCase<T> {
+    /// The elements of the underlying slice.
+    pub values: Vec<T>,
+    /// The range of `values` that the dyn slice covers.
+    pub range: Range<usize>,
+    /// The number of elements to take from the front of the iterator.
+    pub front: usize,
+    /// The number of elements to take from the back of the iterator.
+    pub back: usize,
+}
+
+impl<T: TestElement + 'static> SliceCase<T> {
+    #[inline]
+    #[must_use]
+    /// Returns the dyn slice over `values[range]`.
+    pub fn slice(&self) -> DynSlice<'_, dyn TestElement> {
+        test_element::new(self.expected())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the oracle slice, `values[range]`.
+    pub fn expected(&self) -> &[T] {
+        &self.values[self.range.clone()]
+    }
+
+    #[must_use]
+    /// Returns an iterator over the dyn slice, with `front` and `back` elements already taken.
+    pub fn iter(&self) -> Iter<'_, dyn TestElement> {
+        let mut iter = self.slice().into_iter();
+        if self.front > 0 {
+            iter.nth(self.front - 1);
+        }
+        if self.back > 0 {
+            iter.nth_back(self.back - 1);
+        }
+        iter
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the oracle for [`SliceCase::iter`], the remaining elements of the oracle slice.
+    pub fn expected_iter(&self) -> &[T] {
+        let expected = self.expected();
+        &expected[self.front..expected.len() - self.back]
+    }
+
+    /// Checks that the dyn slice and iterator hold the same values as their oracles, and that the
+    /// dyn slice passes [`DynSlice::assert_invariants`].
+    ///
+    /// # Panics
+    /// Panics if any of the checks fail.
+    pub fn assert_matches(&self) {
+        let slice = self.slice();
+        slice.assert_invariants();
+        assert_values(slice.iter(), self.expected());
+
+        let iter = self.iter();
+        iter.as_slice().assert_invariants();
+        assert_eq!(iter.len(), self.expected_iter().len());
+        assert_values(iter, self.expected_iter());
+    }
+}
+
+/// Checks that `actual` yields the same values as `expected`.
+fn assert_values<'a, T: TestElement>(
+    actual: impl Iterator<Item = &'a dyn TestElement>,
+    expected: &[T],
+) {
+    let actual: Vec<u64> = actual.map(TestElement::value).collect();
+    let expected: Vec<u64> = expected.iter().map(TestElement::value).collect();
+    assert_eq!(actual, expected, "dyn slice did not match the oracle");
+}
+
+/// Returns a strategy that generates [`SliceCase`]s with up to `max_len` elements of type `T`.
+///
+/// # Example
+/// ```
+/// use dyn_slice::test_util::slice_case;
+/// use proptest::{prop_assert_eq, proptest};
+///
+/// proptest!(|(case in slice_case::<u32>(16))| {
+///     case.assert_matches();
+///     prop_assert_eq!(case.slice().len(), case.expected().len());
+/// });
+/// ```
+pub fn slice_case<T: Arbitrary + TestElement + Clone + 'static>(
+    max_len: usize,
+) -> impl Strategy<Value = SliceCase<T>> {
+    vec(any::<T>(), 0..=max_len)
+        .prop_flat_map(|values| {
+            let len = values.len();
+            (Just(values), 0..=len, 0..=len)
+        })
+        .prop_flat_map(|(values, a, b)| {
+            let range = a.min(b)..a.max(b);
+            let len = range.len();
+            (Just(values), Just(range), 0..=len)
+        })
+        .prop_flat_map(|(values, range, front)| {
+            let remaining = range.len() - front;
+            (Just(values), Just(range), Just(front), 0..=remaining)
+        })
+        .prop_map(|(values, range, front, back)| SliceCase {
+            values,
+            range,
+            front,
+            back,
+        })
+}
+
+#[derive(Debug, Clone)]
+/// A [`SliceCase`] with one of several underlying types, including a zero-sized type, a type
+/// with an odd size and a type with a large alignment.
+///
+/// This is generated by [`mixed_slice_case`].
+pub enum MixedSliceCase {
+    /// Zero-sized elements.
+    Unit(SliceCase<()>),
+    /// `u8` elements.
+    U8(SliceCase<u8>),
+    /// `u16` elements.
+    U16(SliceCase<u16>),
+    /// `u32` elements.
+    U32(SliceCase<u32>),
+    /// `u64` elements.
+    U64(SliceCase<u64>),
+    /// Elements with a size of 3 and an alignment of 1.
+    Bytes3(SliceCase<[u8; 3]>),
+    /// `u128` elements, which have the largest alignment of the primitives.
+    U128(SliceCase<u128>),
+}
+
+macro_rules! for_each_case {
+    ($self:expr, |$case:ident| $body:expr) => {
+        match $self {
+            MixedSliceCase::Unit($case) => $body,
+            MixedSliceCase::U8($case) => $body,
+            MixedSliceCase::U16($case) => $body,
+            MixedSliceCase::U32($case) => $body,
+            MixedSliceCase::U64($case) => $body,
+            MixedSliceCase::Bytes3($case) => $body,
+            MixedSliceCase::U128($case) => $body,
+        }
+    };
+}
+
+impl MixedSliceCase {
+    #[must_use]
+    /// Returns the dyn slice, like [`SliceCase::slice`].
+    pub fn slice(&self) -> DynSlice<'_, dyn TestElement> {
+        for_each_case!(self, |case| case.slice())
+    }
+
+    #[must_use]
+    /// Returns the values of the oracle slice, like [`SliceCase::expected`].
+    pub fn expected_values(&self) -> Vec<u64> {
+        for_each_case!(self, |case| case
+            .expected()
+            .iter()
+            .map(TestElement::value)
+            .collect())
+    }
+
+    #[must_use]
+    /// Returns the iterator, like [`SliceCase::iter`].
+    pub fn iter(&self) -> Iter<'_, dyn TestElement> {
+        for_each_case!(self, |case| case.iter())
+    }
+
+    /// Checks the case, like [`SliceCase::assert_matches`].
+    ///
+    /// # Panics
+    /// Panics if any of the checks fail.
+    pub fn assert_matches(&self) {
+        for_each_case!(self, |case| case.assert_matches());
+    }
+}
+
+/// Returns a strategy that generates [`MixedSliceCase`]s with up to `max_len` elements.
+pub fn mixed_slice_case(max_len: usize) -> impl Strategy<Value = MixedSliceCase> {
+    prop_oneof![
+        slice_case(max_len).prop_map(MixedSliceCase::Unit),
+        slice_case(max_len).prop_map(MixedSliceCase::U8),
+        slice_case(max_len).prop_map(MixedSliceCase::U16),
+        slice_case(max_len).prop_map(MixedSliceCase::U32),
+        slice_case(max_len).prop_map(MixedSliceCase::U64),
+        slice_case(max_len).prop_map(MixedSliceCase::Bytes3),
+        slice_case(max_len).prop_map(MixedSliceCase::U128),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    use super::{mixed_slice_case, TestElement};
+
+    proptest! {
+        #[test]
+        fn mixed(case in mixed_slice_case(12)) {
+            case.assert_matches();
+
+            let values = case.expected_values();
+            prop_assert_eq!(case.slice().len(), values.len());
+            prop_assert_eq!(
+                case.slice().iter().rev().map(TestElement::value).collect::<Vec<_>>(),
+                values.into_iter().rev().collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn split_at(case in mixed_slice_case(12), mid in 0_usize..16) {
+            let slice = case.slice();
+            let values = case.expected_values();
+            match slice.split_at(mid) {
+                Some((left, right)) => {
+                    left.assert_invariants();
+                    right.assert_invariants();
+                    prop_assert_eq!(left.iter().map(TestElement::value).collect::<Vec<_>>(), &values[..mid]);
+                    prop_assert_eq!(right.iter().map(TestElement::value).collect::<Vec<_>>(), &values[mid..]);
+                }
+                None => prop_assert!(mid > values.len()),
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use core::{
+    marker::Unsize,
     mem::transmute,
-    ptr::{DynMetadata, Pointee},
+    ptr::{self, DynMetadata, Pointee},
 };
 
 use crate::{DynSlice, DynSliceMut};
@@ -28,3 +29,114 @@ pub unsafe fn extend_lifetime_mut<'to, Dyn: ?Sized + Pointee<Metadata = DynMetad
 ) -> DynSliceMut<'to, Dyn> {
     transmute(value)
 }
+
+#[must_use]
+#[inline]
+/// Returns the metadata for `T` as `Dyn`.
+///
+/// Unsizing a pointer does not dereference it, so a null pointer can be used to get the metadata,
+/// even when there is no value of `T`, such as for an empty slice.
+pub fn metadata_of<T, Dyn>() -> DynMetadata<Dyn>
+where
+    T: Unsize<Dyn>,
+    Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>,
+{
+    ptr::metadata(ptr::null::<T>() as *const Dyn)
+}
+
+#[derive(Clone, Copy)]
+/// The size of the underlying type of a dyn slice, read from the vtable the first time it is
+/// needed.
+///
+/// This lets iterators cache the size, while still being created in const fns, as reading the
+/// size from the vtable is not const.
+pub struct LazySize(Option<usize>);
+
+impl LazySize {
+    #[inline]
+    #[must_use]
+    /// Create a size that has not been read yet.
+    pub const fn new() -> Self {
+        Self(None)
+    }
+
+    #[inline]
+    /// Returns the size of the underlying type of `slice`, reading it from the vtable the first
+    /// time.
+    ///
+    /// `slice` must always have the same underlying type, or be empty.
+    pub fn get<Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>>(
+        &mut self,
+        slice: &DynSlice<Dyn>,
+    ) -> usize {
+        *self.0.get_or_insert_with(|| slice.element_size())
+    }
+}
+
+#[must_use]
+#[inline]
+/// Returns the number of bytes spanned by `count` elements of `size` bytes, or `None` if it is
+/// greater than `isize::MAX`, which is the largest size an allocation can be.
+pub const fn checked_byte_offset(size: usize, count: usize) -> Option<usize> {
+    match size.checked_mul(count) {
+        Some(offset) if offset <= isize::MAX.unsigned_abs() => Some(offset),
+        _ => None,
+    }
+}
+
+#[must_use]
+#[inline]
+/// Returns the number of elements in `count` chunks of `chunk_size` elements of `size` bytes, or
+/// `None` if they would span more than `isize::MAX` bytes, so cannot be in a valid slice.
+///
+/// Zero-sized elements never span any bytes, so only the number of elements is checked for them.
+pub const fn checked_chunk_offset(chunk_size: usize, count: usize, size: usize) -> Option<usize> {
+    let Some(elements) = chunk_size.checked_mul(count) else {
+        return None;
+    };
+    if checked_byte_offset(size, elements).is_none() {
+        return None;
+    }
+    Some(elements)
+}
+
+#[must_use]
+#[inline]
+/// Returns the number of bytes spanned by `count` elements of `size` bytes.
+///
+/// # Safety
+/// The caller must ensure that the offset is not greater than `isize::MAX`. This is always the
+/// case if `count` is at most the length of a valid dyn slice with elements of `size` bytes.
+pub unsafe fn byte_offset_unchecked(size: usize, count: usize) -> usize {
+    debug_assert!(
+        checked_byte_offset(size, count).is_some(),
+        "[dyn-slice] byte offset is greater than isize::MAX!"
+    );
+
+    size.wrapping_mul(count)
+}
+
+#[inline]
+/// Hint to the CPU that the memory at `ptr` will be read soon.
+///
+/// This is a no-op on targets without a stable prefetch instruction, and under Miri.
+pub fn prefetch_read(ptr: *const ()) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse", not(miri)))]
+    // SAFETY:
+    // Prefetching is only a hint, and never faults, even for invalid addresses.
+    unsafe {
+        core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(ptr.cast());
+    }
+    #[cfg(all(target_arch = "x86", target_feature = "sse", not(miri)))]
+    // SAFETY:
+    // Prefetching is only a hint, and never faults, even for invalid addresses.
+    unsafe {
+        core::arch::x86::_mm_prefetch::<{ core::arch::x86::_MM_HINT_T0 }>(ptr.cast());
+    }
+    #[cfg(not(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse",
+        not(miri)
+    )))]
+    let _ = ptr;
+}
@@ -0,0 +1,260 @@
+use core::{
+    fmt::{self, Display},
+    mem::{align_of, transmute},
+    ptr::{DynMetadata, Pointee},
+};
+
+use crate::{utils::checked_byte_offset, DynSlice};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A reason that the raw parts of a dyn slice are invalid.
+///
+/// This is returned by [`DynSlice::validate`].
+pub enum LayoutError {
+    /// The data pointer is null.
+    NullData,
+    /// The vtable pointer is null, but the slice is not empty.
+    NullVtable,
+    /// The vtable pointer is misaligned, or the vtable has an alignment that is not a power of
+    /// two.
+    InvalidVtable,
+    /// The data pointer is not aligned to the alignment of the underlying type.
+    MisalignedData,
+    /// The slice is larger than `isize::MAX` bytes.
+    TooLarge,
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NullData => "data pointer is null",
+            Self::NullVtable => "vtable pointer is null, but the slice is not empty",
+            Self::InvalidVtable => "vtable pointer does not point to a plausible vtable",
+            Self::MisalignedData => "data pointer is misaligned for the underlying type",
+            Self::TooLarge => "slice is larger than isize::MAX bytes",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+impl std::error::Error for LayoutError {}
+
+impl<'a, Dyn: ?Sized + Pointee<Metadata = DynMetadata<Dyn>>> DynSlice<'a, Dyn> {
+    /// Checks that the raw parts of the slice are plausible.
+    ///
+    /// Dyn slices created by safe code are always valid, so this is useful for catching mistakes
+    /// in slices created from raw parts, for example over FFI. A successful check does not mean
+    /// that the slice is valid, as the vtable and data cannot be fully checked.
+    ///
+    /// This is called by [`DynSlice::from_parts_with_metadata`] and
+    /// [`DynSliceMut::from_parts_with_metadata`](crate::DynSliceMut::from_parts_with_metadata) in
+    /// debug builds.
+    ///
+    /// # Errors
+    /// Returns the first problem that is found.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::{standard::debug, LayoutError};
+    ///
+    /// let slice = debug::new(&[1, 2, 3]);
+    /// assert_eq!(slice.validate(), Ok(()));
+    ///
+    /// // WARNING: this deliberately breaks the safety contract of `from_parts`, which does not
+    /// // allow a null vtable pointer for a non-empty slice, to show what `validate` reports.
+    /// // Invalid raw parts like these usually come from foreign code, and the slice must never be
+    /// // used, other than to validate it.
+    /// let invalid = unsafe { debug::Slice::from_parts(std::ptr::null(), 3, slice.as_ptr()) };
+    /// assert_eq!(invalid.validate(), Err(LayoutError::NullVtable));
+    /// ```
+    pub fn validate(&self) -> Result<(), LayoutError> {
+        if self.data.is_null() {
+            return Err(LayoutError::NullData);
+        }
+        if self.vtable_ptr.is_null() {
+            return if self.len == 0 {
+                Ok(())
+            } else {
+                Err(LayoutError::NullVtable)
+            };
+        }
+        // Vtables start with the drop function pointer, size and alignment, so are aligned to
+        // at least a pointer
+        if self.vtable_ptr.align_offset(align_of::<usize>()) != 0 {
+            return Err(LayoutError::InvalidVtable);
+        }
+
+        // SAFETY:
+        // DynMetadata only contains a single pointer, and has the same layout as *const ().
+        // The pointer is not null and is aligned, so reading the size and alignment from it is
+        // the best that can be checked.
+        let metadata = unsafe { transmute::<*const (), DynMetadata<Dyn>>(self.vtable_ptr) };
+        let align = metadata.align_of();
+        if !align.is_power_of_two() {
+            return Err(LayoutError::InvalidVtable);
+        }
+        if self.data.align_offset(align) != 0 {
+            return Err(LayoutError::MisalignedData);
+        }
+        if checked_byte_offset(metadata.size_of(), self.len).is_none() {
+            return Err(LayoutError::TooLarge);
+        }
+
+        Ok(())
+    }
+
+    #[track_caller]
+    /// Checks that the slice is valid, and that element access agrees with the raw parts, panicking
+    /// if it does not.
+    ///
+    /// As well as [`DynSlice::validate`], this checks that iterating from both ends yields each
+    /// element once, at the address and with the metadata that the raw parts say it should have.
+    /// This is intended for fuzzing and running under Miri, where every element is read, so
+    /// invalid slices are caught.
+    ///
+    /// # Panics
+    /// Panics if any of the checks fail.
+    ///
+    /// # Example
+    /// ```
+    /// use dyn_slice::standard::debug;
+    ///
+    /// let slice = debug::new(&[1, 2, 3]);
+    /// slice.assert_invariants();
+    /// slice.slice(1..).unwrap().assert_invariants();
+    /// ```
+    pub fn assert_invariants(&self) {
+        if let Err(error) = self.validate() {
+            panic!("[dyn-slice] invalid raw parts: {error}!");
+        }
+
+        let size = self.element_size();
+        let mut count = 0;
+        for (index, element) in self.iter().enumerate() {
+            let (data, metadata) = (element as *const Dyn).to_raw_parts();
+            let expected = self.data.wrapping_byte_add(size.wrapping_mul(index));
+            assert_eq!(
+                data, expected,
+                "[dyn-slice] element {index} is at the wrong address!"
+            );
+            assert_eq!(
+                Some(metadata),
+                self.metadata(),
+                "[dyn-slice] element {index} has the wrong metadata!"
+            );
+            count += 1;
+        }
+        assert_eq!(
+            count, self.len,
+            "[dyn-slice] iterator yielded the wrong number of elements!"
+        );
+
+        let mut back = self.iter();
+        for index in (0..self.len).rev() {
+            let element = back.next_back().map(|element| element as *const Dyn);
+            // SAFETY:
+            // `index < self.len`.
+            let expected = unsafe { self.get_ptr_unchecked(index) };
+            assert_eq!(
+                element.map(<*const Dyn>::cast::<()>),
+                Some(expected),
+                "[dyn-slice] element {index} is at the wrong address from the back!"
+            );
+        }
+        assert!(
+            back.next_back().is_none(),
+            "[dyn-slice] iterator yielded too many elements from the back!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{fmt::Debug, ptr};
+
+    use super::LayoutError;
+    use crate::{standard::debug, DynSlice};
+
+    #[test]
+    fn valid() {
+        assert_eq!(debug::new(&[1_u64, 2]).validate(), Ok(()));
+        assert_eq!(debug::new::<u64>(&[]).validate(), Ok(()));
+        assert_eq!(debug::new(&[(); 5]).validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        let array = [1_u32, 2, 3];
+        let slice = debug::new(&array);
+
+        // SAFETY:
+        // The slices are only validated, and never accessed.
+        unsafe {
+            let null_data = DynSlice::<dyn Debug>::from_parts(slice.vtable_ptr(), 1, ptr::null());
+            assert_eq!(null_data.validate(), Err(LayoutError::NullData));
+
+            let null_vtable = DynSlice::<dyn Debug>::from_parts(ptr::null(), 1, slice.as_ptr());
+            assert_eq!(null_vtable.validate(), Err(LayoutError::NullVtable));
+            let empty = DynSlice::<dyn Debug>::from_parts(ptr::null(), 0, slice.as_ptr());
+            assert_eq!(empty.validate(), Ok(()));
+
+            let misaligned_vtable = DynSlice::<dyn Debug>::from_parts(
+                slice.vtable_ptr().wrapping_byte_add(1),
+                1,
+                slice.as_ptr(),
+            );
+            assert_eq!(
+                misaligned_vtable.validate(),
+                Err(LayoutError::InvalidVtable)
+            );
+
+            let misaligned_data = DynSlice::<dyn Debug>::from_parts(
+                slice.vtable_ptr(),
+                1,
+                slice.as_ptr().cast::<u8>().add(1).cast(),
+            );
+            assert_eq!(misaligned_data.validate(), Err(LayoutError::MisalignedData));
+
+            let too_large =
+                DynSlice::<dyn Debug>::from_parts(slice.vtable_ptr(), usize::MAX, slice.as_ptr());
+            assert_eq!(too_large.validate(), Err(LayoutError::TooLarge));
+        }
+    }
+
+    #[test]
+    fn assert_invariants() {
+        let array = [1_u64, 2, 3];
+        let slice = debug::new(&array);
+        slice.assert_invariants();
+        slice.slice(1..).unwrap().assert_invariants();
+        debug::new::<u64>(&[]).assert_invariants();
+        debug::new(&[(); 3]).assert_invariants();
+    }
+
+    #[test]
+    #[should_panic = "[dyn-slice] invalid raw parts: data pointer is misaligned for the underlying type!"]
+    fn assert_invariants_misaligned() {
+        let array = [1_u32, 2];
+        let slice = debug::new(&array);
+
+        // SAFETY:
+        // The slice is misaligned, so `assert_invariants` panics before accessing it.
+        let misaligned = unsafe {
+            DynSlice::<dyn Debug>::from_parts(
+                slice.vtable_ptr(),
+                1,
+                slice.as_ptr().cast::<u8>().add(1).cast(),
+            )
+        };
+        misaligned.assert_invariants();
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            LayoutError::TooLarge.to_string(),
+            "slice is larger than isize::MAX bytes"
+        );
+    }
+}